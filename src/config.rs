@@ -1,347 +1,2310 @@
+use regex::Regex;
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Config {
+    /// Version of the config file format; files without one are version 1
+    #[serde(default = "default_config_version")]
+    pub version: u32,
+
+    /// Marks the project root: configs in parent directories are not inherited
+    #[serde(default)]
+    pub root: bool,
+
     /// Optional preset name to apply
     #[serde(default)]
     pub preset: Option<PresetName>,
 
+    /// Lowest severity that makes the run exit with a non-zero code
+    #[serde(default)]
+    pub fail_on: FailOn,
+
+    /// Structure snapshot (`naechste-structure.lock`) enforcement
+    #[serde(default)]
+    pub snapshot: SnapshotConfig,
+
+    /// Which files are linted
+    #[serde(default)]
+    pub files: FilesConfig,
+
+    /// Import prefixes mapped to directories relative to the project root
+    /// (e.g. `"~/": "src/"`), for projects whose aliases aren't in a tsconfig
+    #[serde(default)]
+    pub import_aliases: BTreeMap<String, String>,
+
     #[serde(default)]
     pub rules: Rules,
+
+    /// Config fragments keyed by environment name, merged over the rest of
+    /// the config when that environment is active (`--env <name>`, or `ci`
+    /// when the `CI` variable is set)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    #[schemars(with = "BTreeMap<String, serde_json::Map<String, Value>>")]
+    pub env_overrides: BTreeMap<String, Value>,
+
+    /// Environment whose `env_overrides` were applied, if any
+    #[serde(skip)]
+    pub env: Option<String>,
+
+    /// Problems found while loading the config that don't prevent linting
+    #[serde(skip)]
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SnapshotConfig {
+    /// Report route tree changes that are not recorded in the lock file
+    #[serde(default)]
+    pub enforce: bool,
+
+    /// Severity of unrecorded route tree changes
+    #[serde(default = "default_snapshot_severity")]
+    pub severity: Severity,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            version: CONFIG_VERSION,
+            root: false,
+            preset: None,
+            fail_on: FailOn::default(),
+            snapshot: SnapshotConfig::default(),
+            files: FilesConfig::default(),
+            import_aliases: BTreeMap::new(),
+            rules: Rules::default(),
+            env_overrides: BTreeMap::new(),
+            env: None,
+            warnings: Vec::new(),
+        }
+    }
+}
+
+impl Default for SnapshotConfig {
+    fn default() -> Self {
+        SnapshotConfig {
+            enforce: false,
+            severity: default_snapshot_severity(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FilesConfig {
+    /// Extensions of lintable source files, without the leading dot; also the
+    /// extensions tried, in order, when resolving extensionless imports
+    #[serde(default = "default_file_extensions")]
+    pub extensions: Vec<String>,
+
+    /// Skip files matched by `.gitignore` (the `--no-gitignore` flag turns this off)
+    #[serde(default = "default_true")]
+    pub gitignore: bool,
+}
+
+impl Default for FilesConfig {
+    fn default() -> Self {
+        FilesConfig {
+            extensions: default_file_extensions(),
+            gitignore: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    /// Only errors fail the run
+    #[default]
+    Error,
+    /// Warnings and errors fail the run
+    Warning,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum PresetName {
     Bassist,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Rules {
-    #[serde(default = "default_rule_config")]
-    pub server_side_exports: RuleConfig,
+    #[serde(default)]
+    pub server_side_exports: RuleConfig<NoOptions>,
+
+    #[serde(default)]
+    pub component_nesting_depth: RuleConfig<NestingDepthOptions>,
+
+    #[serde(default)]
+    pub filename_style: RuleConfig<FilenameStyleOptions>,
+
+    #[serde(default)]
+    pub file_organization: RuleConfig<FileOrganizationOptions>,
+
+    #[serde(default)]
+    pub enforce_location_missing_dir: RuleConfig<NoOptions>,
+
+    #[serde(default)]
+    pub public_asset_naming: RuleConfig<PublicAssetNamingOptions>,
+
+    #[serde(default)]
+    pub no_server_only_next_apis_in_client: RuleConfig<ServerOnlyApisOptions>,
+
+    #[serde(default)]
+    pub router_migration_boundaries: RuleConfig<RouterMigrationOptions>,
+
+    #[serde(default)]
+    pub hook_file_naming: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub component_nesting_depth: RuleConfig,
+    #[serde(default)]
+    pub catch_all_api_routes: RuleConfig<CatchAllApiRoutesOptions>,
+
+    #[serde(default)]
+    pub import_style_consistency: RuleConfig<ImportStyleOptions>,
+
+    #[serde(default)]
+    pub default_export_name_match: RuleConfig<DefaultExportNameOptions>,
+
+    #[serde(default)]
+    pub max_component_lines: RuleConfig<MaxLinesOptions>,
+
+    #[serde(default = "default_error_rule_config::<ClientEnvPrefixOptions>")]
+    pub client_env_prefix: RuleConfig<ClientEnvPrefixOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub filename_style_consistency: RuleConfig,
+    #[serde(default = "default_error_rule_config::<PageDefaultExportOptions>")]
+    pub page_default_export: RuleConfig<PageDefaultExportOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub file_organization: RuleConfig,
+    #[serde(default)]
+    pub no_nested_package_json: RuleConfig<NestedPackageJsonOptions>,
 
     // Bassist preset rules
-    #[serde(default = "default_rule_config")]
-    pub bassist_domain_structure: RuleConfig,
+    #[serde(default)]
+    pub bassist_domain_structure: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_locale_layout: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_locale_nesting: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_route_group_names: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_service_client_restriction: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_supabase_client_imports: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_i18n_hook_usage: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_test_colocation: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_test_naming: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_api_route_structure: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_domain_isolation: RuleConfig<BassistRuleOptions>,
+
+    #[serde(default)]
+    pub bassist_i18n_namespaces: RuleConfig<BassistRuleOptions>,
+
+    // Opt-in rules (disabled unless a severity is configured)
+    #[serde(default = "default_off_rule_config::<ActionsFilenameOptions>")]
+    pub actions_filename: RuleConfig<ActionsFilenameOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub fetch_cache_option: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub legacy_special_files_in_app: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub no_focused_tests: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub single_style_file: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<RequireMemoOptions>")]
+    pub require_memo: RuleConfig<RequireMemoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub no_next_head_in_app: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<FileHeaderOptions>")]
+    pub file_header: RuleConfig<FileHeaderOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub route_explicit_status: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub feature_ownership: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<ExportPlacementOptions>")]
+    pub export_placement: RuleConfig<ExportPlacementOptions>,
+
+    #[serde(default = "default_off_rule_config::<ClientOnlyLibsOptions>")]
+    pub client_only_libs: RuleConfig<ClientOnlyLibsOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub boundary_consistency: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<ExportedTypeDocsOptions>")]
+    pub exported_type_docs: RuleConfig<ExportedTypeDocsOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub dynamic_in_server: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoBarrelFilesOptions>")]
+    pub no_barrel_files: RuleConfig<NoBarrelFilesOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub hydration_risk: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<MaxUrlDepthOptions>")]
+    pub max_url_depth: RuleConfig<MaxUrlDepthOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub barrel_self_cycle: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<ImportQuoteOptions>")]
+    pub import_quote_consistency: RuleConfig<ImportQuoteOptions>,
+
+    #[serde(default = "default_off_rule_config::<PageFileFocusOptions>")]
+    pub page_file_focus: RuleConfig<PageFileFocusOptions>,
+
+    #[serde(default = "default_off_rule_config::<PromiseStyleOptions>")]
+    pub promise_style: RuleConfig<PromiseStyleOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub no_top_level_await_client: RuleConfig<NoOptions>,
+
+    #[serde(default)]
+    pub layout_renders_children: RuleConfig<LayoutChildrenOptions>,
+
+    #[serde(default = "default_off_rule_config::<RequiredDirectoriesOptions>")]
+    pub required_directories: RuleConfig<RequiredDirectoriesOptions>,
+
+    #[serde(default = "default_off_rule_config::<CircularImportsOptions>")]
+    pub no_circular_imports: RuleConfig<CircularImportsOptions>,
+
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub no_redundant_use_strict: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub conflicting_directives: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_off_rule_config::<OutlierFileSizeOptions>")]
+    pub outlier_file_size: RuleConfig<OutlierFileSizeOptions>,
+
+    #[serde(default = "default_off_rule_config::<ClientHooksOptions>")]
+    pub no_client_hooks_in_server: RuleConfig<ClientHooksOptions>,
+
+    #[serde(default = "default_off_rule_config::<RequireMetadataOptions>")]
+    pub require_metadata: RuleConfig<RequireMetadataOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_locale_layout: RuleConfig,
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub unique_page_names: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_locale_nesting: RuleConfig,
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub conflicting_metadata_exports: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_route_group_names: RuleConfig,
+    #[serde(default = "default_off_rule_config::<NoOptions>")]
+    pub unresolved_import: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_service_client_restriction: RuleConfig,
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub no_client_metadata: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_supabase_client_imports: RuleConfig,
+    #[serde(default = "default_rule_config::<DynamicSegmentNamingOptions>")]
+    pub dynamic_segment_naming: RuleConfig<DynamicSegmentNamingOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_i18n_hook_usage: RuleConfig,
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub no_duplicate_routes: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_test_colocation: RuleConfig,
+    #[serde(default = "default_rule_config::<RouteGroupNamingOptions>")]
+    pub route_group_naming: RuleConfig<RouteGroupNamingOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_test_naming: RuleConfig,
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub intercepting_routes: RuleConfig<NoOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_api_route_structure: RuleConfig,
+    #[serde(default = "default_off_rule_config::<NoConsoleOptions>")]
+    pub no_console: RuleConfig<NoConsoleOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_domain_isolation: RuleConfig,
+    #[serde(default = "default_off_rule_config::<ApiLocationOptions>")]
+    pub api_location: RuleConfig<ApiLocationOptions>,
 
-    #[serde(default = "default_rule_config")]
-    pub bassist_i18n_namespaces: RuleConfig,
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub no_conflicting_dynamic_segments: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_error_rule_config::<NoOptions>")]
+    pub middleware_location: RuleConfig<NoOptions>,
+
+    #[serde(default = "default_error_rule_config::<ClientOnlyImportsOptions>")]
+    pub client_only_imports: RuleConfig<ClientOnlyImportsOptions>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RuleConfig {
+/// A rule's severity and options
+///
+/// Configs may also give just the severity as a string (`"error"`), like
+/// ESLint, which keeps the default options.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[schemars(
+    bound = "O: JsonSchema + Default + Serialize",
+    rename = "RuleConfig_for_{O}",
+    transform = allow_severity_shorthand
+)]
+pub struct RuleConfig<O> {
     #[serde(default = "default_severity")]
     pub severity: Severity,
 
     #[serde(default)]
-    pub options: RuleOptions,
+    pub options: O,
+
+    /// Custom diagnostic message with `{file}`, `{rule}`, `{line}`,
+    /// `{message}` (the default message) and rule-specific placeholders
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_template: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RuleOptions {
-    #[serde(default = "default_max_depth")]
-    pub max_nesting_depth: usize,
+impl<O: Default> Default for RuleConfig<O> {
+    fn default() -> Self {
+        default_rule_config()
+    }
+}
+
+/// Object form of a rule's config
+#[derive(Deserialize)]
+struct RuleConfigObject<O> {
+    #[serde(default = "default_severity")]
+    severity: Severity,
 
-    #[serde(default = "default_filename_style")]
-    pub filename_style: FilenameStyle,
-    
-    /// File organization checks
     #[serde(default)]
-    pub file_organization_checks: Vec<OrganizationCheck>,
+    options: O,
 
-    /// Bassist-specific options
     #[serde(default)]
-    pub bassist: BassistOptions,
+    message_template: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct BassistOptions {
-    /// Allowed route group names (e.g., "admin", "auth", etc.)
-    #[serde(default = "default_allowed_route_groups")]
-    pub allowed_route_groups: Vec<String>,
+impl<'de, O: Deserialize<'de> + Default> Deserialize<'de> for RuleConfig<O> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::{self, value::MapAccessDeserializer, value::StrDeserializer};
+        use std::marker::PhantomData;
 
-    /// Paths allowed for cross-domain imports (e.g., "types", "schemas")
-    #[serde(default = "default_cross_domain_allowed_paths")]
-    pub cross_domain_allowed_paths: Vec<String>,
+        struct RuleConfigVisitor<O>(PhantomData<O>);
 
-    /// Whether to enforce strict test naming (errors) or just warn
-    #[serde(default)]
-    pub enforce_test_naming: bool,
+        impl<'de, O: Deserialize<'de> + Default> de::Visitor<'de> for RuleConfigVisitor<O> {
+            type Value = RuleConfig<O>;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a severity (\"off\", \"warn\" or \"error\") or a rule config object")
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                Ok(RuleConfig {
+                    severity: Severity::deserialize(StrDeserializer::new(value))?,
+                    options: O::default(),
+                    message_template: None,
+                })
+            }
+
+            fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+                // Parsed in place, so errors keep their position in the file
+                let object = RuleConfigObject::deserialize(MapAccessDeserializer::new(map))?;
+                Ok(RuleConfig {
+                    severity: object.severity,
+                    options: object.options,
+                    message_template: object.message_template,
+                })
+            }
+        }
+
+        deserializer.deserialize_any(RuleConfigVisitor(PhantomData))
+    }
 }
 
-fn default_allowed_route_groups() -> Vec<String> {
-    vec![
-        "admin".to_string(),
-        "auth".to_string(),
-        "chat".to_string(),
-        "feature-flags".to_string(),
-        "profiles".to_string(),
-        "projects".to_string(),
-        "thoughts".to_string(),
-    ]
+/// Accept a bare severity wherever the schema expects a rule config object
+fn allow_severity_shorthand(schema: &mut schemars::Schema) {
+    let mut object = std::mem::take(schema);
+    let description = object.remove("description");
+    *schema = schemars::json_schema!({
+        "anyOf": [
+            { "$ref": "#/$defs/Severity" },
+            object
+        ]
+    });
+    if let Some(description) = description {
+        schema.insert("description".to_string(), description);
+    }
 }
 
-fn default_cross_domain_allowed_paths() -> Vec<String> {
-    vec![
-        "types".to_string(),
-        "schemas".to_string(),
-    ]
+/// Expand rules given as a bare severity to the object form, so merging
+/// another config's options over them keeps the severity
+fn expand_rule_shorthand(raw: &mut Value) {
+    let rules = match raw.get_mut("rules").and_then(Value::as_object_mut) {
+        Some(rules) => rules,
+        None => return,
+    };
+
+    for value in rules.values_mut() {
+        if let Value::String(severity) = value {
+            *value = serde_json::json!({ "severity": severity });
+        }
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-pub enum Severity {
-    Warn,
-    Error,
+impl<O> RuleConfig<O> {
+    /// Whether the rule should run at all
+    pub fn is_enabled(&self) -> bool {
+        self.severity != Severity::Off
+    }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "kebab-case")]
-pub enum FilenameStyle {
-    KebabCase,
-    CamelCase,
-    PascalCase,
-    SnakeCase,
+/// Options for rules that have none
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct NoOptions {}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NestingDepthOptions {
+    #[serde(default = "default_max_depth")]
+    pub max_nesting_depth: usize,
 }
 
-/// File organization check configuration
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct OrganizationCheck {
-    /// Unique identifier for this check
-    pub id: String,
-    
-    /// Optional description of what this check does
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FilenameStyleOptions {
+    #[serde(default = "default_filename_style")]
+    pub filename_style: FilenameStyle,
+
+    /// Extra file stems to skip, in addition to Next.js special files and
+    /// common config files; simple globs such as `*.config` are supported
     #[serde(default)]
-    pub description: Option<String>,
-    
-    /// Pattern to match files this check applies to
-    pub r#match: MatchPattern,
-    
-    /// Requirements for companion files
+    pub ignored_filenames: Vec<String>,
+
+    /// Use `ignored_filenames` instead of the built-in list
     #[serde(default)]
-    pub require: Vec<RequireKind>,
-    
-    /// When imported by condition
+    pub override_ignored_filenames: bool,
+
+    /// Regexes matched against the file stem; matching files pass regardless
+    /// of style
     #[serde(default)]
-    pub when_imported_by: Option<WhenImportedBy>,
-    
-    /// Location enforcement
+    #[schemars(with = "Vec<String>")]
+    pub allowed_exceptions: RegexList,
+
+    /// Styles for specific paths, evaluated in order; the first entry whose
+    /// glob matches the project-relative path wins over `filename_style`
     #[serde(default)]
-    pub enforce_location: Option<EnforceLocation>,
+    pub by_path: Vec<FilenameStyleByPath>,
 }
 
-/// Pattern for matching files
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct MatchPattern {
-    /// Glob pattern to match files
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FilenameStyleByPath {
+    /// Glob matched against the project-relative path (e.g. `components/**`)
     pub glob: String,
-    
-    /// Optional glob patterns to exclude
-    #[serde(default)]
-    pub exclude_glob: Vec<String>,
+    pub style: FilenameStyle,
 }
 
-/// Kind of companion file requirement
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "kind")]
-pub enum RequireKind {
-    /// Exact sibling file name
-    #[serde(rename = "sibling_exact")]
-    SiblingExact { name: String },
-    
-    /// Sibling file matching glob
-    #[serde(rename = "sibling_glob")]
-    SiblingGlob { glob: String },
-}
+/// Regular expressions compiled when the config is loaded, so an invalid
+/// pattern is reported as a config error
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct RegexList(Vec<Regex>);
 
-/// Condition for when a file is imported by another
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WhenImportedBy {
-    /// Glob pattern for importer files
-    pub importer_glob: String,
-    
-    /// Regex patterns to match import specifiers
-    pub import_path_matches: Vec<String>,
-}
+impl RegexList {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.iter().any(|re| re.is_match(text))
+    }
 
-/// Location enforcement rule
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Regex> {
+        self.0.iter()
+    }
+}
+
+impl TryFrom<Vec<String>> for RegexList {
+    type Error = String;
+
+    fn try_from(patterns: Vec<String>) -> Result<Self, Self::Error> {
+        patterns
+            .iter()
+            .map(|pattern| {
+                Regex::new(pattern).map_err(|e| format!("invalid regex '{}': {}", pattern, e))
+            })
+            .collect::<Result<_, _>>()
+            .map(RegexList)
+    }
+}
+
+impl From<RegexList> for Vec<String> {
+    fn from(list: RegexList) -> Self {
+        list.0.iter().map(|re| re.as_str().to_string()).collect()
+    }
+}
+
+/// A single regular expression compiled when the config is loaded, like
+/// the entries of a `RegexList`
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct EnforceLocation {
-    /// List of allowed directory prefixes
-    pub must_be_under: Vec<String>,
+#[serde(try_from = "String", into = "String")]
+pub struct RegexPattern(Regex);
+
+impl RegexPattern {
+    pub fn is_match(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl TryFrom<String> for RegexPattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Regex::new(&pattern)
+            .map(RegexPattern)
+            .map_err(|e| format!("invalid regex '{}': {}", pattern, e))
+    }
+}
+
+impl From<RegexPattern> for String {
+    fn from(pattern: RegexPattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FileOrganizationOptions {
+    /// File organization checks
+    #[serde(default)]
+    pub file_organization_checks: Vec<OrganizationCheck>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PublicAssetNamingOptions {
+    /// Naming style for files and directories under public/
+    #[serde(default = "default_filename_style")]
+    pub public_asset_style: FilenameStyle,
+
+    /// Glob patterns (relative to public/) exempt from asset naming checks
+    #[serde(default = "default_public_asset_ignore")]
+    pub public_asset_ignore: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ServerOnlyApisOptions {
+    /// Import specifiers client components may not use; `specifier#name`
+    /// forbids only that named import (e.g. `next/navigation#redirect`).
+    /// Other entries also cover subpaths and `node:` builtins (`fs` covers
+    /// `node:fs` and `fs/promises`)
+    #[serde(default = "default_forbidden_client_imports")]
+    pub forbidden_client_imports: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClientHooksOptions {
+    /// React hooks that only work in client components
+    #[serde(default = "default_client_hooks")]
+    pub client_hooks: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClientEnvPrefixOptions {
+    /// Variables client components may read without the `NEXT_PUBLIC_` prefix
+    #[serde(default = "default_allowed_env_vars")]
+    pub allowed_env_vars: Vec<String>,
+
+    /// Severity for `NEXT_PUBLIC_` variables in server-only files (`'use
+    /// server'` or importing `server-only`), as their values are public;
+    /// `off` skips the check
+    #[serde(default = "default_public_in_server_severity")]
+    pub public_in_server_severity: Severity,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClientOnlyLibsOptions {
+    /// Packages that only work in client components; subpath imports
+    /// (e.g. `react-use/lib/useMedia`) are included
+    #[serde(default = "default_client_only_packages")]
+    pub client_only_packages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RouterMigrationOptions {
+    /// Globs of pages/ modules that app/ code may import during a router migration
+    #[serde(default)]
+    pub app_may_import_from_pages: Vec<String>,
+
+    /// Globs of app/ bridge modules that pages/ code may import during a router migration
+    #[serde(default)]
+    pub pages_may_import_from_app: Vec<String>,
+
+    /// Which imports across the boundary are checked
+    #[serde(default)]
+    pub direction: RouterDirection,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum RouterDirection {
+    /// Both app/ importing pages/ and pages/ importing app/
+    #[default]
+    Both,
+    /// Only app/ code importing pages/ modules
+    AppToPages,
+    /// Only pages/ code importing app/ modules
+    PagesToApp,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ActionsFilenameOptions {
+    /// Required filename stem suffix for server action modules
+    #[serde(default = "default_actions_suffix")]
+    pub actions_suffix: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CatchAllApiRoutesOptions {
+    /// Globs of catch-all API routes that are intentionally allowed; the
+    /// default covers NextAuth's `api/auth/[...nextauth]` handler
+    #[serde(default = "default_allowed_catch_all_routes")]
+    pub allowed_catch_all_routes: Vec<String>,
+
+    /// Regexes an allowlisted catch-all API route handler must all match
+    /// (e.g. a reference to an auth helper)
+    #[serde(default)]
+    #[schemars(with = "Vec<String>")]
+    pub required_content_patterns: RegexList,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ImportStyleOptions {
+    /// Preferred style for imports of project files
+    #[serde(default)]
+    pub prefer: ImportStyle,
+
+    /// Most `../` segments a relative import may have before the alias is
+    /// preferred (with `prefer: "alias"`), or the most an alias import may
+    /// save before the relative form is preferred (with `prefer: "relative"`)
+    #[serde(default = "default_max_parent_levels")]
+    pub max_parent_levels: usize,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportStyle {
+    /// `@/lib/x` instead of deep `../../../lib/x` imports
+    #[default]
+    Alias,
+    /// `../lib/x` instead of `@/lib/x` for nearby files
+    Relative,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NestedPackageJsonOptions {
+    /// Globs of source folders that must not contain a package.json
+    #[serde(default = "default_package_json_source_roots")]
+    pub source_roots: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ExportPlacementOptions {
+    /// Where a module's exports are declared
+    #[serde(default)]
+    pub export_style: ExportStyle,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportStyle {
+    /// `export` keywords on the declarations themselves
+    #[default]
+    Inline,
+    /// A single `export { ... }` block at the bottom of the file
+    Bottom,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PromiseStyleOptions {
+    /// Preferred way to consume promises in server components
+    #[serde(default)]
+    pub promise_style: PromiseStyle,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum PromiseStyle {
+    /// `await promise`
+    #[default]
+    AsyncAwait,
+    /// `promise.then(...)`
+    Then,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MaxLinesOptions {
+    /// Most lines a file may have
+    #[serde(default = "default_max_lines")]
+    pub max_lines: usize,
+
+    /// Glob patterns (relative to the project root) of files that are not checked
+    #[serde(default = "default_max_lines_exclude")]
+    pub exclude: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OutlierFileSizeOptions {
+    /// How many times the average line count of the other files in its
+    /// directory a file may have
+    #[serde(default = "default_outlier_size_multiplier")]
+    pub size_multiplier: f64,
+
+    /// Fewest relevant files a directory needs, the outlier included, for
+    /// its average to mean anything
+    #[serde(default = "default_outlier_min_files")]
+    pub min_files: usize,
+
+    /// Files with fewer lines are never flagged
+    #[serde(default = "default_outlier_min_lines")]
+    pub min_lines: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MaxUrlDepthOptions {
+    /// Most URL segments a page may have, not counting route groups, parallel
+    /// route slots and private folders
+    #[serde(default = "default_max_url_depth")]
+    pub max_url_depth: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoBarrelFilesOptions {
+    /// Glob (relative to the project root) of files where `index` barrels are forbidden
+    #[serde(default = "default_barrel_forbidden_glob")]
+    pub forbidden_glob: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DefaultExportNameOptions {
+    /// Message for anonymous default exports; `{name}` is replaced with the file stem
+    #[serde(default = "default_anonymous_export_message")]
+    pub anonymous_export_message: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RequireMetadataOptions {
+    /// Special app router files that must export `metadata` or
+    /// `generateMetadata`, by name without extension
+    #[serde(default = "default_require_metadata_files")]
+    pub apply_to: Vec<String>,
+
+    /// Check only the root layout (`app/layout`) rather than every layout
+    #[serde(default = "default_true")]
+    pub root_layout_only: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DynamicSegmentNamingOptions {
+    /// Naming style for the parameter of `[param]`, `[...param]` and
+    /// `[[...param]]` segments
+    #[serde(default = "default_param_style")]
+    pub param_style: FilenameStyle,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RouteGroupNamingOptions {
+    /// Naming style for the name inside a route group's parentheses
+    #[serde(default = "default_filename_style")]
+    pub group_style: FilenameStyle,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct NoConsoleOptions {
+    /// `console` methods that may not be called, e.g. `log` or `debug`
+    #[serde(default = "default_disallowed_console_methods")]
+    pub disallowed_methods: Vec<String>,
+
+    /// Glob patterns of files allowed to use the console (e.g. `scripts/**`);
+    /// test and story files are always skipped
+    #[serde(default)]
+    pub exclude_glob: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ApiLocationOptions {
+    /// Globs of the directories HTTP method handlers may be exported from
+    #[serde(default = "default_api_allowed_roots")]
+    pub allowed_roots: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct ClientOnlyImportsOptions {
+    /// Modules server components may not import: exact specifiers with their
+    /// subpaths, or globs containing `*` matched against the whole specifier
+    /// (e.g. `*.client`)
+    #[serde(default = "default_client_only_modules")]
+    pub client_only_modules: Vec<String>,
+
+    /// Also check files under `pages/`, where there is no `'use client'`
+    /// boundary and every component may render on the server
+    #[serde(default)]
+    pub include_pages: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct PageDefaultExportOptions {
+    /// Special app router files that must have a default export, by name
+    /// without extension: `page`, `layout`, `template`, `loading`, `error`
+    /// or `not-found`
+    #[serde(default = "default_page_default_export_files")]
+    pub special_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CircularImportsOptions {
+    /// Skip `import type` and `export type` statements, which are erased at
+    /// compile time and can't cause a runtime cycle
+    #[serde(default = "default_true")]
+    pub ignore_type_imports: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RequiredDirectoriesOptions {
+    /// Directories that must exist, relative to the project root (e.g. `app/api`)
+    #[serde(default)]
+    pub required_dirs: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct LayoutChildrenOptions {
+    /// Also require the root layout (`app/layout`) to render `<html>` and
+    /// `<body>`
+    #[serde(default)]
+    pub require_html_body: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct PageFileFocusOptions {
+    /// Most exports a page may have besides the default export, metadata
+    /// and route segment config
+    #[serde(default)]
+    pub max_extra_exports: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ImportQuoteOptions {
+    /// Quote style every import specifier must use; without it a file only
+    /// has to be consistent
+    #[serde(default)]
+    pub prefer: Option<QuoteStyle>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum QuoteStyle {
+    /// `'react'`
+    Single,
+    /// `"react"`
+    Double,
+}
+
+impl QuoteStyle {
+    /// The quote character of the style
+    pub fn quote(self) -> char {
+        match self {
+            QuoteStyle::Single => '\'',
+            QuoteStyle::Double => '"',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct ExportedTypeDocsOptions {
+    /// Glob of files whose exported types and interfaces must have a JSDoc comment
+    #[serde(default)]
+    pub public_api_glob: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct RequireMemoOptions {
+    /// Glob of files whose exported components must be wrapped in `memo`
+    #[serde(default)]
+    pub memo_glob: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct FileHeaderOptions {
+    /// Regex the header must match (takes precedence over `header_template`)
+    #[serde(default)]
+    #[schemars(with = "Option<String>")]
+    pub header_pattern: Option<RegexPattern>,
+
+    /// Literal header text; also inserted by `--fix` when the header is missing
+    #[serde(default)]
+    pub header_template: Option<String>,
+
+    /// Globs of files that must start with the header
+    #[serde(default = "default_header_globs")]
+    pub header_globs: Vec<String>,
+
+    /// Number of lines after the shebang and directives searched for the header
+    #[serde(default = "default_header_search_lines")]
+    pub header_search_lines: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct BassistRuleOptions {
+    /// Bassist-specific options
+    #[serde(default)]
+    pub bassist: BassistOptions,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct BassistOptions {
+    /// Allowed route group names (e.g., "admin", "auth", etc.)
+    #[serde(default = "default_allowed_route_groups")]
+    pub allowed_route_groups: Vec<String>,
+
+    /// Paths allowed for cross-domain imports (e.g., "types", "schemas")
+    #[serde(default = "default_cross_domain_allowed_paths")]
+    pub cross_domain_allowed_paths: Vec<String>,
+
+    /// Whether to enforce strict test naming (errors) or just warn
+    #[serde(default)]
+    pub enforce_test_naming: bool,
+}
+
+fn default_allowed_route_groups() -> Vec<String> {
+    vec![
+        "admin".to_string(),
+        "auth".to_string(),
+        "chat".to_string(),
+        "feature-flags".to_string(),
+        "profiles".to_string(),
+        "projects".to_string(),
+        "thoughts".to_string(),
+    ]
+}
+
+fn default_cross_domain_allowed_paths() -> Vec<String> {
+    vec![
+        "types".to_string(),
+        "schemas".to_string(),
+    ]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Rule is disabled
+    Off,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[allow(clippy::enum_variant_names)]
+pub enum FilenameStyle {
+    KebabCase,
+    CamelCase,
+    PascalCase,
+    SnakeCase,
+    /// `{ "custom": "<regex>" }`: the whole name must match the regex
+    Custom(#[schemars(with = "String")] CustomPattern),
+}
+
+impl FilenameStyle {
+    /// Human-readable expectation for diagnostics
+    pub fn describe(&self) -> String {
+        match self {
+            FilenameStyle::Custom(pattern) => format!("pattern '{}'", pattern.as_str()),
+            style => format!("{:?}", style),
+        }
+    }
+}
+
+/// User-provided filename regex, anchored to the whole name and compiled when
+/// the config is loaded
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct CustomPattern {
+    pattern: String,
+    regex: Regex,
+}
+
+impl CustomPattern {
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.regex.is_match(text)
+    }
+}
+
+impl TryFrom<String> for CustomPattern {
+    type Error = String;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        let regex = Regex::new(&format!("^(?:{})$", pattern))
+            .map_err(|e| format!("invalid regex '{}': {}", pattern, e))?;
+        Ok(CustomPattern { pattern, regex })
+    }
+}
+
+impl From<CustomPattern> for String {
+    fn from(pattern: CustomPattern) -> Self {
+        pattern.pattern
+    }
+}
+
+impl PartialEq for CustomPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.pattern == other.pattern
+    }
+}
+
+impl Eq for CustomPattern {}
+
+/// File organization check configuration
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct OrganizationCheck {
+    /// Unique identifier for this check
+    pub id: String,
     
-    /// Optional custom message
+    /// Optional description of what this check does
     #[serde(default)]
-    pub message: Option<String>,
+    pub description: Option<String>,
+    
+    /// Pattern to match files this check applies to
+    pub r#match: MatchPattern,
+    
+    /// Requirements for companion files
+    #[serde(default)]
+    pub require: Vec<RequireKind>,
+
+    /// Only count `sibling_glob` companions named after the file's stem, so a
+    /// leftover `OldButton.stories.tsx` doesn't satisfy `Button.tsx`
+    #[serde(default)]
+    pub strict_companion_stem: bool,
+    
+    /// When imported by condition
+    #[serde(default)]
+    pub when_imported_by: Option<WhenImportedBy>,
+    
+    /// Location enforcement
+    #[serde(default)]
+    pub enforce_location: Option<EnforceLocation>,
+}
+
+/// Pattern for matching files
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MatchPattern {
+    /// Glob pattern to match files
+    pub glob: String,
+    
+    /// Optional glob patterns to exclude
+    #[serde(default)]
+    pub exclude_glob: Vec<String>,
+}
+
+/// Kind of companion file requirement
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind")]
+pub enum RequireKind {
+    /// Exact sibling file name
+    #[serde(rename = "sibling_exact")]
+    SiblingExact { name: String },
+    
+    /// Sibling file matching glob
+    #[serde(rename = "sibling_glob")]
+    SiblingGlob { glob: String },
+}
+
+/// Condition for when a file is imported by another
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WhenImportedBy {
+    /// Glob pattern for importer files
+    pub importer_glob: String,
+    
+    /// Regex patterns to match import specifiers
+    pub import_path_matches: Vec<String>,
+}
+
+/// Location enforcement rule
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct EnforceLocation {
+    /// List of allowed directory prefixes; entries with a `*` are globs
+    /// matched against the file's parent directories (e.g. `src/**/ui`)
+    pub must_be_under: Vec<String>,
+    
+    /// Optional custom message
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+fn default_rule_config<O: Default>() -> RuleConfig<O> {
+    RuleConfig {
+        severity: Severity::Warn,
+        options: O::default(),
+        message_template: None,
+    }
+}
+
+fn default_error_rule_config<O: Default>() -> RuleConfig<O> {
+    RuleConfig {
+        severity: Severity::Error,
+        options: O::default(),
+        message_template: None,
+    }
+}
+
+fn default_off_rule_config<O: Default>() -> RuleConfig<O> {
+    RuleConfig {
+        severity: Severity::Off,
+        options: O::default(),
+        message_template: None,
+    }
+}
+
+fn default_config_version() -> u32 {
+    CONFIG_VERSION
+}
+
+fn default_snapshot_severity() -> Severity {
+    Severity::Error
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_file_extensions() -> Vec<String> {
+    ["ts", "tsx", "js", "jsx", "mjs", "cjs"]
+        .iter()
+        .map(|ext| ext.to_string())
+        .collect()
+}
+
+fn default_max_lines() -> usize {
+    300
+}
+
+fn default_max_lines_exclude() -> Vec<String> {
+    vec![
+        "**/*.test.*".to_string(),
+        "**/*.spec.*".to_string(),
+        "**/*.stories.*".to_string(),
+        "**/*.generated.*".to_string(),
+        "**/generated/**".to_string(),
+    ]
+}
+
+fn default_client_hooks() -> Vec<String> {
+    [
+        "useState",
+        "useEffect",
+        "useLayoutEffect",
+        "useReducer",
+        "useRef",
+        "useContext",
+        "useTransition",
+        "useOptimistic",
+        "useSyncExternalStore",
+    ]
+    .iter()
+    .map(|hook| hook.to_string())
+    .collect()
+}
+
+fn default_outlier_size_multiplier() -> f64 {
+    3.0
+}
+
+fn default_outlier_min_files() -> usize {
+    3
+}
+
+fn default_outlier_min_lines() -> usize {
+    100
+}
+
+fn default_require_metadata_files() -> Vec<String> {
+    vec!["page".to_string(), "layout".to_string()]
+}
+
+fn default_page_default_export_files() -> Vec<String> {
+    vec!["page".to_string()]
+}
+
+fn default_max_url_depth() -> usize {
+    4
+}
+
+fn default_barrel_forbidden_glob() -> String {
+    "app/**".to_string()
+}
+
+fn default_anonymous_export_message() -> String {
+    "Default export is anonymous; name it '{name}' to match the filename".to_string()
+}
+
+fn default_severity() -> Severity {
+    Severity::Warn
+}
+
+fn default_max_depth() -> usize {
+    3
+}
+
+fn default_filename_style() -> FilenameStyle {
+    FilenameStyle::KebabCase
+}
+
+fn default_param_style() -> FilenameStyle {
+    FilenameStyle::CamelCase
+}
+
+fn default_disallowed_console_methods() -> Vec<String> {
+    vec!["log".to_string(), "debug".to_string()]
+}
+
+fn default_api_allowed_roots() -> Vec<String> {
+    vec!["app/api/**".to_string(), "pages/api/**".to_string()]
+}
+
+fn default_client_only_modules() -> Vec<String> {
+    vec!["client-only".to_string()]
+}
+
+fn default_public_asset_ignore() -> Vec<String> {
+    vec!["google*.html".to_string()]
+}
+
+fn default_forbidden_client_imports() -> Vec<String> {
+    vec![
+        "next/headers".to_string(),
+        "next/server".to_string(),
+        "next/navigation#redirect".to_string(),
+        "next/navigation#permanentRedirect".to_string(),
+        "server-only".to_string(),
+        "fs".to_string(),
+        "path".to_string(),
+        "crypto".to_string(),
+    ]
+}
+
+fn default_public_in_server_severity() -> Severity {
+    Severity::Off
+}
+
+fn default_allowed_env_vars() -> Vec<String> {
+    vec!["NODE_ENV".to_string()]
+}
+
+fn default_client_only_packages() -> Vec<String> {
+    vec![
+        "framer-motion".to_string(),
+        "react-use".to_string(),
+        "react-hot-toast".to_string(),
+        "react-dropzone".to_string(),
+    ]
+}
+
+fn default_allowed_catch_all_routes() -> Vec<String> {
+    // `[[]` escapes the bracket in globs
+    vec![
+        "**/api/auth/[[]...nextauth]/route.*".to_string(),
+        "**/api/auth/[[]...nextauth].*".to_string(),
+    ]
+}
+
+fn default_actions_suffix() -> String {
+    ".actions".to_string()
+}
+
+fn default_max_parent_levels() -> usize {
+    2
+}
+
+fn default_package_json_source_roots() -> Vec<String> {
+    vec![
+        "app/**".to_string(),
+        "components/**".to_string(),
+        "lib/**".to_string(),
+    ]
+}
+
+fn default_header_globs() -> Vec<String> {
+    vec!["app/**".to_string(), "lib/**".to_string()]
+}
+
+fn default_header_search_lines() -> usize {
+    5
+}
+
+impl Rules {
+    /// Configured message templates by rule id
+    pub fn message_templates(&self) -> BTreeMap<String, String> {
+        let rules = serde_json::to_value(self).unwrap_or(Value::Null);
+        let mut templates = BTreeMap::new();
+        for (rule, value) in rules.as_object().into_iter().flatten() {
+            if let Some(template) = value["message_template"].as_str() {
+                templates.insert(rule.replace('_', "-"), template.to_string());
+            }
+        }
+        templates
+    }
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules {
+            server_side_exports: default_rule_config(),
+            component_nesting_depth: default_rule_config(),
+            filename_style: default_rule_config(),
+            file_organization: default_rule_config(),
+            enforce_location_missing_dir: default_rule_config(),
+            public_asset_naming: default_rule_config(),
+            no_server_only_next_apis_in_client: default_rule_config(),
+            router_migration_boundaries: default_rule_config(),
+            hook_file_naming: default_rule_config(),
+            catch_all_api_routes: default_rule_config(),
+            import_style_consistency: default_rule_config(),
+            default_export_name_match: default_rule_config(),
+            max_component_lines: default_rule_config(),
+            client_env_prefix: default_error_rule_config(),
+            page_default_export: default_error_rule_config(),
+            no_nested_package_json: default_rule_config(),
+            bassist_domain_structure: default_rule_config(),
+            bassist_locale_layout: default_rule_config(),
+            bassist_locale_nesting: default_rule_config(),
+            bassist_route_group_names: default_rule_config(),
+            bassist_service_client_restriction: default_rule_config(),
+            bassist_supabase_client_imports: default_rule_config(),
+            bassist_i18n_hook_usage: default_rule_config(),
+            bassist_test_colocation: default_rule_config(),
+            bassist_test_naming: default_rule_config(),
+            bassist_api_route_structure: default_rule_config(),
+            bassist_domain_isolation: default_rule_config(),
+            bassist_i18n_namespaces: default_rule_config(),
+            actions_filename: default_off_rule_config(),
+            fetch_cache_option: default_off_rule_config(),
+            legacy_special_files_in_app: default_off_rule_config(),
+            no_focused_tests: default_off_rule_config(),
+            single_style_file: default_off_rule_config(),
+            require_memo: default_off_rule_config(),
+            file_header: default_off_rule_config(),
+            no_next_head_in_app: default_off_rule_config(),
+            route_explicit_status: default_off_rule_config(),
+            feature_ownership: default_off_rule_config(),
+            export_placement: default_off_rule_config(),
+            client_only_libs: default_off_rule_config(),
+            boundary_consistency: default_off_rule_config(),
+            exported_type_docs: default_off_rule_config(),
+            dynamic_in_server: default_off_rule_config(),
+            no_barrel_files: default_off_rule_config(),
+            hydration_risk: default_off_rule_config(),
+            max_url_depth: default_off_rule_config(),
+            barrel_self_cycle: default_off_rule_config(),
+            import_quote_consistency: default_off_rule_config(),
+            page_file_focus: default_off_rule_config(),
+            promise_style: default_off_rule_config(),
+            no_top_level_await_client: default_off_rule_config(),
+            layout_renders_children: default_rule_config(),
+            required_directories: default_off_rule_config(),
+            no_circular_imports: default_off_rule_config(),
+            no_redundant_use_strict: default_off_rule_config(),
+            conflicting_directives: default_error_rule_config(),
+            outlier_file_size: default_off_rule_config(),
+            no_client_hooks_in_server: default_off_rule_config(),
+            require_metadata: default_off_rule_config(),
+            unique_page_names: default_off_rule_config(),
+            conflicting_metadata_exports: default_error_rule_config(),
+            unresolved_import: default_off_rule_config(),
+            no_client_metadata: default_error_rule_config(),
+            dynamic_segment_naming: default_rule_config(),
+            no_duplicate_routes: default_error_rule_config(),
+            route_group_naming: default_rule_config(),
+            intercepting_routes: default_error_rule_config(),
+            no_console: default_off_rule_config(),
+            api_location: default_off_rule_config(),
+            no_conflicting_dynamic_segments: default_error_rule_config(),
+            middleware_location: default_error_rule_config(),
+            client_only_imports: default_error_rule_config(),
+        }
+    }
+}
+
+impl Default for NestingDepthOptions {
+    fn default() -> Self {
+        NestingDepthOptions {
+            max_nesting_depth: default_max_depth(),
+        }
+    }
+}
+
+impl Default for FilenameStyleOptions {
+    fn default() -> Self {
+        FilenameStyleOptions {
+            filename_style: default_filename_style(),
+            ignored_filenames: Vec::new(),
+            override_ignored_filenames: false,
+            allowed_exceptions: RegexList::default(),
+            by_path: Vec::new(),
+        }
+    }
+}
+
+impl Default for PublicAssetNamingOptions {
+    fn default() -> Self {
+        PublicAssetNamingOptions {
+            public_asset_style: default_filename_style(),
+            public_asset_ignore: default_public_asset_ignore(),
+        }
+    }
+}
+
+impl Default for ClientEnvPrefixOptions {
+    fn default() -> Self {
+        ClientEnvPrefixOptions {
+            allowed_env_vars: default_allowed_env_vars(),
+            public_in_server_severity: default_public_in_server_severity(),
+        }
+    }
+}
+
+impl Default for ClientOnlyLibsOptions {
+    fn default() -> Self {
+        ClientOnlyLibsOptions {
+            client_only_packages: default_client_only_packages(),
+        }
+    }
+}
+
+impl Default for ServerOnlyApisOptions {
+    fn default() -> Self {
+        ServerOnlyApisOptions {
+            forbidden_client_imports: default_forbidden_client_imports(),
+        }
+    }
+}
+
+impl Default for CatchAllApiRoutesOptions {
+    fn default() -> Self {
+        CatchAllApiRoutesOptions {
+            allowed_catch_all_routes: default_allowed_catch_all_routes(),
+            required_content_patterns: RegexList::default(),
+        }
+    }
+}
+
+impl Default for ImportStyleOptions {
+    fn default() -> Self {
+        ImportStyleOptions {
+            prefer: ImportStyle::default(),
+            max_parent_levels: default_max_parent_levels(),
+        }
+    }
+}
+
+impl Default for NestedPackageJsonOptions {
+    fn default() -> Self {
+        NestedPackageJsonOptions {
+            source_roots: default_package_json_source_roots(),
+        }
+    }
+}
+
+impl Default for CircularImportsOptions {
+    fn default() -> Self {
+        CircularImportsOptions {
+            ignore_type_imports: true,
+        }
+    }
+}
+
+impl Default for RequireMetadataOptions {
+    fn default() -> Self {
+        RequireMetadataOptions {
+            apply_to: default_require_metadata_files(),
+            root_layout_only: true,
+        }
+    }
+}
+
+impl Default for DynamicSegmentNamingOptions {
+    fn default() -> Self {
+        DynamicSegmentNamingOptions {
+            param_style: default_param_style(),
+        }
+    }
+}
+
+impl Default for RouteGroupNamingOptions {
+    fn default() -> Self {
+        RouteGroupNamingOptions {
+            group_style: default_filename_style(),
+        }
+    }
+}
+
+impl Default for NoConsoleOptions {
+    fn default() -> Self {
+        NoConsoleOptions {
+            disallowed_methods: default_disallowed_console_methods(),
+            exclude_glob: Vec::new(),
+        }
+    }
+}
+
+impl Default for ApiLocationOptions {
+    fn default() -> Self {
+        ApiLocationOptions {
+            allowed_roots: default_api_allowed_roots(),
+        }
+    }
+}
+
+impl Default for ClientOnlyImportsOptions {
+    fn default() -> Self {
+        ClientOnlyImportsOptions {
+            client_only_modules: default_client_only_modules(),
+            include_pages: false,
+        }
+    }
+}
+
+impl Default for PageDefaultExportOptions {
+    fn default() -> Self {
+        PageDefaultExportOptions {
+            special_files: default_page_default_export_files(),
+        }
+    }
+}
+
+impl Default for MaxUrlDepthOptions {
+    fn default() -> Self {
+        MaxUrlDepthOptions {
+            max_url_depth: default_max_url_depth(),
+        }
+    }
+}
+
+impl Default for NoBarrelFilesOptions {
+    fn default() -> Self {
+        NoBarrelFilesOptions {
+            forbidden_glob: default_barrel_forbidden_glob(),
+        }
+    }
+}
+
+impl Default for ClientHooksOptions {
+    fn default() -> Self {
+        ClientHooksOptions {
+            client_hooks: default_client_hooks(),
+        }
+    }
+}
+
+impl Default for OutlierFileSizeOptions {
+    fn default() -> Self {
+        OutlierFileSizeOptions {
+            size_multiplier: default_outlier_size_multiplier(),
+            min_files: default_outlier_min_files(),
+            min_lines: default_outlier_min_lines(),
+        }
+    }
+}
+
+impl Default for MaxLinesOptions {
+    fn default() -> Self {
+        MaxLinesOptions {
+            max_lines: default_max_lines(),
+            exclude: default_max_lines_exclude(),
+        }
+    }
+}
+
+impl Default for DefaultExportNameOptions {
+    fn default() -> Self {
+        DefaultExportNameOptions {
+            anonymous_export_message: default_anonymous_export_message(),
+        }
+    }
+}
+
+impl Default for FileHeaderOptions {
+    fn default() -> Self {
+        FileHeaderOptions {
+            header_pattern: None,
+            header_template: None,
+            header_globs: default_header_globs(),
+            header_search_lines: default_header_search_lines(),
+        }
+    }
+}
+
+impl Default for ActionsFilenameOptions {
+    fn default() -> Self {
+        ActionsFilenameOptions {
+            actions_suffix: default_actions_suffix(),
+        }
+    }
+}
+
+impl Default for BassistOptions {
+    fn default() -> Self {
+        BassistOptions {
+            allowed_route_groups: default_allowed_route_groups(),
+            cross_domain_allowed_paths: default_cross_domain_allowed_paths(),
+            enforce_test_naming: false,
+        }
+    }
+}
+
+/// File name written by `naechste init`
+pub const STARTER_CONFIG_FILE_NAME: &str = "naechste.json";
+
+/// Version of the config file format written by this build
+///
+/// Version 1 is the format from before the `version` field existed.
+pub const CONFIG_VERSION: u32 = 2;
+
+/// Options of the single options struct every rule shared in version 1
+const V1_SHARED_OPTIONS: [&str; 4] = [
+    "max_nesting_depth",
+    "filename_style",
+    "file_organization_checks",
+    "bassist",
+];
+
+/// Renamed rules: the old config key, the current one and the release
+/// that stops accepting the old key
+pub const DEPRECATED_RULE_NAMES: [(&str, &str, &str); 1] = [
+    ("filename_style_consistency", "filename_style", "0.2.0"),
+];
+
+impl Config {
+    /// Write a starter `naechste.json` into `dir` listing every rule with its
+    /// default severity and options
+    ///
+    /// An existing file is only replaced when `force` is set.
+    pub fn write_starter(dir: &Path, force: bool) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        let path = dir.join(STARTER_CONFIG_FILE_NAME);
+        if path.exists() && !force {
+            return Err(format!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            )
+            .into());
+        }
+
+        let contents = serde_json::to_string_pretty(&Config::default())?;
+        fs::write(&path, contents + "\n")?;
+        Ok(path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let (mut config, _) = Self::read(path)?;
+
+        // Apply preset if specified
+        config.apply_preset();
+
+        Ok(config)
+    }
+
+    /// Load the nearest config merged over the configs it inherits from
+    ///
+    /// `paths` lists the discovered configs from the nearest outwards. Each
+    /// one inherits from the next until a config with `root: true`. Returns
+    /// the config and the files it was built from, nearest first.
+    pub fn load_cascade(
+        paths: &[PathBuf],
+    ) -> Result<(Self, Vec<PathBuf>), Box<dyn std::error::Error>> {
+        let mut layers = Vec::new();
+        let mut used = Vec::new();
+        for path in paths {
+            let layer = Self::read_layer(path)?;
+            let root = layer.0.root;
+            layers.push(layer);
+            used.push(path.clone());
+            if root {
+                break;
+            }
+        }
+
+        layers.reverse();
+        Ok((Self::merge_layers(layers)?, used))
+    }
+
+    /// Load configs given in increasing order of precedence, each merged over
+    /// the ones before it
+    ///
+    /// Objects are merged key by key and lists of entries with an `id`, such
+    /// as `file_organization_checks`, are combined with later entries
+    /// replacing earlier ones with the same `id`; anything else is replaced.
+    pub fn load_layered(paths: &[PathBuf]) -> Result<Self, Box<dyn std::error::Error>> {
+        let layers = paths
+            .iter()
+            .map(|path| Self::read_layer(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::merge_layers(layers)
+    }
+
+    /// Merge parsed config files, lowest precedence first, and apply the preset
+    fn merge_layers(mut layers: Vec<(Self, Value)>) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut config = if layers.len() == 1 {
+            layers.pop().map(|(config, _)| config).unwrap_or_default()
+        } else {
+            let mut merged = Value::Object(serde_json::Map::new());
+            for (_, raw) in &layers {
+                merge_values(&mut merged, raw);
+            }
+            let mut config: Config = serde_json::from_value(merged)?;
+            config.version = CONFIG_VERSION;
+            config.warnings = layers
+                .iter()
+                .flat_map(|(layer, _)| layer.warnings.clone())
+                .collect();
+            config
+        };
+
+        config.apply_preset();
+        Ok(config)
+    }
+
+    /// Read one file of a layered config, naming it in errors
+    fn read_layer(path: &Path) -> Result<(Self, Value), Box<dyn std::error::Error>> {
+        // Every file is parsed on its own first, for errors with line numbers
+        Self::read(path)
+            .map_err(|e| format!("Could not load config file {}: {}", path.display(), e).into())
+    }
+
+    /// Parse and migrate one config file, without applying its preset
+    ///
+    /// Also returns the migrated raw value, for merging.
+    fn read(path: &Path) -> Result<(Self, Value), Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let mut raw: Value =
+            parse(&contents, &extension).map_err(|e| e.into_error(&contents))?;
+        let (renamed, migration) = upgrade(&mut raw)?;
+
+        // Parse the file itself when nothing moved, for errors with line numbers
+        let mut config: Config = if !migration.notes.is_empty() || !renamed.is_empty() {
+            serde_json::from_value(raw.clone())
+                .map_err(|e| ParseFailure::from(e).into_error(&contents))?
+        } else {
+            parse(&contents, &extension).map_err(|e| e.into_error(&contents))?
+        };
+        config.version = CONFIG_VERSION;
+
+        if migration.from < CONFIG_VERSION && (!migration.notes.is_empty() || migration.explicit) {
+            config.warnings.push(format!(
+                "Config uses format version {} and was migrated to version {} in memory; run `naechste migrate-config` to update the file",
+                migration.from, CONFIG_VERSION
+            ));
+            config.warnings.extend(migration.notes);
+        }
+        config.warnings.extend(renamed);
+
+        // Options are typed per rule; report the ones a rule doesn't understand
+        config
+            .warnings
+            .extend(unknown_option_warnings(&raw, &config.rules));
+
+        Ok((config, raw))
+    }
+
+    /// Rewrite a config file in the current format, with renamed rules
+    /// under their current key
+    ///
+    /// Comments at the top of the file are kept; the rest is re-serialized,
+    /// so other comments are lost. Returns the version the file was migrated
+    /// from, or `None` when it already is current.
+    pub fn migrate_file(path: &Path) -> Result<Option<u32>, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        let mut raw: Value =
+            parse(&contents, &extension).map_err(|e| e.into_error(&contents))?;
+        let (renamed, migration) = upgrade(&mut raw)?;
+        if migration.from == CONFIG_VERSION && renamed.is_empty() {
+            return Ok(None);
+        }
+
+        // Make sure the result still loads before replacing the file
+        let _: Config = serde_json::from_value(raw.clone())?;
+
+        let body = match extension.as_str() {
+            "yaml" | "yml" => serde_yaml::to_string(&raw)?,
+            _ => serde_json::to_string_pretty(&raw)? + "\n",
+        };
+        fs::write(path, leading_comments(&contents, &extension) + &body)?;
+        Ok(Some(migration.from))
+    }
+
+    /// Apply preset configuration if a preset is specified
+    pub fn apply_preset(&mut self) {
+        if let Some(preset) = self.preset {
+            match preset {
+                PresetName::Bassist => self.apply_bassist_preset(),
+            }
+        }
+    }
+
+    /// Merge the `env_overrides` entry for `env` over this config, the same
+    /// way a nearer config is merged over the configs it inherits from
+    ///
+    /// Returns whether the config has overrides for `env`.
+    pub fn apply_env(&mut self, env: &str) -> Result<bool, Box<dyn std::error::Error>> {
+        let mut overlay = match self.env_overrides.get(env) {
+            Some(overlay) => overlay.clone(),
+            None => return Ok(false),
+        };
+        expand_rule_shorthand(&mut overlay);
+
+        let mut merged = serde_json::to_value(&*self)?;
+        merge_values(&mut merged, &overlay);
+        let mut config: Config = serde_json::from_value(merged)
+            .map_err(|e| format!("Invalid env_overrides for '{}': {}", env, e))?;
+        config.env = Some(env.to_string());
+        config.warnings = std::mem::take(&mut self.warnings);
+
+        *self = config;
+        Ok(true)
+    }
+
+    /// Apply Bassist preset configuration
+    fn apply_bassist_preset(&mut self) {
+        // Enable Bassist rules with Error severity
+        self.rules.bassist_domain_structure.severity = Severity::Error;
+        self.rules.bassist_locale_layout.severity = Severity::Error;
+        self.rules.bassist_locale_nesting.severity = Severity::Error;
+        self.rules.bassist_route_group_names.severity = Severity::Warn;
+        self.rules.bassist_service_client_restriction.severity = Severity::Error;
+        self.rules.bassist_supabase_client_imports.severity = Severity::Error;
+        self.rules.bassist_i18n_hook_usage.severity = Severity::Error;
+        self.rules.bassist_test_colocation.severity = Severity::Warn;
+        self.rules.bassist_test_naming.severity = Severity::Warn;
+        self.rules.bassist_api_route_structure.severity = Severity::Warn;
+        self.rules.bassist_domain_isolation.severity = Severity::Warn;
+        self.rules.bassist_i18n_namespaces.severity = Severity::Warn;
+    }
+}
+
+fn parse<T: DeserializeOwned>(contents: &str, extension: &str) -> Result<T, ParseFailure> {
+    match extension {
+        "yaml" | "yml" => serde_yaml::from_str(contents).map_err(ParseFailure::from),
+        "jsonc" => json5::from_str(contents).map_err(ParseFailure::from),
+        // Attempt strict JSON first, then fall back to JSON5 to allow comments
+        "json" | "" => serde_json::from_str(contents).or_else(|json_error| {
+            json5::from_str(contents).map_err(|json5_error| {
+                // JSON5 errors point at the real problem in files with comments,
+                // but not every JSON5 error has a position
+                let json5_failure = ParseFailure::from(json5_error);
+                if json5_failure.line.is_some() {
+                    json5_failure
+                } else {
+                    ParseFailure::from(json_error)
+                }
+            })
+        }),
+        // Unknown extension: try JSON, then JSON5 (JSON with comments), then YAML
+        _ => serde_json::from_str(contents)
+            .or_else(|_| json5::from_str(contents))
+            .or_else(|_| serde_yaml::from_str(contents))
+            .map_err(ParseFailure::from),
+    }
+}
+
+static POSITION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r" at line \d+ column \d+").unwrap());
+
+/// Why a config could not be parsed, with the 1-based position when known
+#[derive(Debug)]
+struct ParseFailure {
+    message: String,
+    line: Option<usize>,
+    column: Option<usize>,
+}
+
+impl ParseFailure {
+    /// Attach an excerpt of the config around the error
+    fn into_error(self, contents: &str) -> ConfigParseError {
+        let excerpt = self
+            .line
+            .map(|line| excerpt(contents, line, self.column))
+            .unwrap_or_default();
+        ConfigParseError {
+            message: self.message,
+            line: self.line,
+            column: self.column,
+            excerpt,
+        }
+    }
+
+    /// serde_json and serde_yaml include the position in their messages
+    fn strip_position(message: String) -> String {
+        POSITION_RE.replace_all(&message, "").into_owned()
+    }
+}
+
+impl From<serde_json::Error> for ParseFailure {
+    fn from(error: serde_json::Error) -> Self {
+        // Errors that are not tied to the input (e.g. from `from_value`) report line 0
+        let line = Some(error.line()).filter(|line| *line > 0);
+        ParseFailure {
+            column: line.map(|_| error.column()),
+            line,
+            message: Self::strip_position(error.to_string()),
+        }
+    }
+}
+
+impl From<serde_yaml::Error> for ParseFailure {
+    fn from(error: serde_yaml::Error) -> Self {
+        let location = error.location();
+        ParseFailure {
+            line: location.as_ref().map(|location| location.line()),
+            column: location.as_ref().map(|location| location.column()),
+            message: Self::strip_position(error.to_string()),
+        }
+    }
+}
+
+impl From<json5::Error> for ParseFailure {
+    fn from(error: json5::Error) -> Self {
+        let json5::Error::Message { msg, location } = error;
+        // Syntax errors come with their own drawing of the line; keep the
+        // "= expected ..." part since the excerpt shows the line anyway
+        let message = msg
+            .lines()
+            .find_map(|line| line.trim().strip_prefix("= "))
+            .map(String::from)
+            .unwrap_or(msg);
+        ParseFailure {
+            line: location.as_ref().map(|location| location.line),
+            column: location.as_ref().map(|location| location.column),
+            message,
+        }
+    }
+}
+
+/// The config lines up to `line`, numbered, with a caret under `column`
+fn excerpt(contents: &str, line: usize, column: Option<usize>) -> String {
+    let lines: Vec<&str> = contents.lines().collect();
+    let last = line.min(lines.len());
+    let first = last.saturating_sub(2).max(1);
+
+    let mut excerpt = String::new();
+    for number in first..=last {
+        excerpt.push_str(&format!("{:>4} | {}\n", number, lines[number - 1]));
+    }
+    if let Some(column) = column.filter(|column| *column > 0) {
+        excerpt.push_str(&format!("     | {}^\n", " ".repeat(column - 1)));
+    }
+    excerpt
+}
+
+/// A config file that could not be parsed
+#[derive(Debug)]
+pub struct ConfigParseError {
+    pub message: String,
+    /// 1-based line of the problem, when known
+    pub line: Option<usize>,
+    /// 1-based column of the problem, when known
+    pub column: Option<usize>,
+    /// Numbered config lines leading up to the problem
+    pub excerpt: String,
+}
+
+impl std::fmt::Display for ConfigParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)?
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message)?,
+            _ => write!(f, "{}", self.message)?,
+        }
+        if !self.excerpt.is_empty() {
+            write!(f, "\n\n{}", self.excerpt.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigParseError {}
+
+/// Merge `overlay` into `base`: objects key by key, lists of entries with an
+/// `id` by id, anything else replaced
+///
+/// An empty overlay list replaces the base list, so a list can be cleared.
+fn merge_values(base: &mut Value, overlay: &Value) {
+    match (base, overlay) {
+        (Value::Array(base), Value::Array(overlay))
+            if !overlay.is_empty()
+                && base.iter().chain(overlay).all(|entry| entry_id(entry).is_some()) =>
+        {
+            for entry in overlay {
+                match base.iter_mut().find(|existing| entry_id(existing) == entry_id(entry)) {
+                    Some(existing) => *existing = entry.clone(),
+                    None => base.push(entry.clone()),
+                }
+            }
+        }
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => {
+                        base.insert(key.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay.clone(),
+    }
 }
 
-fn default_rule_config() -> RuleConfig {
-    RuleConfig {
-        severity: Severity::Warn,
-        options: RuleOptions::default(),
-    }
+/// The `id` of a list entry that is an object with a string `id`
+fn entry_id(entry: &Value) -> Option<&str> {
+    entry.get("id").and_then(Value::as_str)
 }
 
-fn default_severity() -> Severity {
-    Severity::Warn
+/// A config written for a newer naechste than this one
+#[derive(Debug)]
+pub struct UnsupportedVersionError {
+    pub version: u32,
 }
 
-fn default_max_depth() -> usize {
-    3
+impl std::fmt::Display for UnsupportedVersionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "config version {} is newer than this naechste supports (version {}); upgrade naechste",
+            self.version, CONFIG_VERSION
+        )
+    }
 }
 
-fn default_filename_style() -> FilenameStyle {
-    FilenameStyle::KebabCase
+impl std::error::Error for UnsupportedVersionError {}
+
+/// Outcome of bringing a raw config up to `CONFIG_VERSION`
+struct Migration {
+    /// Version the file declared, 1 when it has no `version`
+    from: u32,
+    /// Whether the file has a `version` field
+    explicit: bool,
+    /// What the migration steps changed, one entry per change
+    notes: Vec<String>,
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Config {
-            preset: None,
-            rules: Rules::default(),
+/// Bring a raw config into the current format the same way for loading and
+/// for `migrate-config`: expand severity shorthands, move renamed rules to
+/// their current key, then migrate
+///
+/// Returns the deprecation warnings for renamed rules and the migration.
+fn upgrade(raw: &mut Value) -> Result<(Vec<String>, Migration), Box<dyn std::error::Error>> {
+    expand_rule_shorthand(raw);
+    let renamed = resolve_rule_aliases(raw);
+    let migration = migrate(raw)?;
+    Ok((renamed, migration))
+}
+
+/// Apply the migrations from the raw config's version up to `CONFIG_VERSION`
+///
+/// Versions newer than this build understands are an error.
+fn migrate(raw: &mut Value) -> Result<Migration, Box<dyn std::error::Error>> {
+    let (from, explicit) = match raw.get("version") {
+        Some(version) => match version.as_u64() {
+            Some(version) if version >= 1 => (u32::try_from(version).unwrap_or(u32::MAX), true),
+            _ => return Err(format!("Invalid config version {}", version).into()),
+        },
+        None => (1, false),
+    };
+
+    if from > CONFIG_VERSION {
+        return Err(Box::new(UnsupportedVersionError { version: from }));
+    }
+
+    let mut notes = Vec::new();
+    if from < 2 {
+        notes.extend(migrate_v1_shared_options(raw));
+    }
+
+    if from < CONFIG_VERSION {
+        if let Some(object) = raw.as_object_mut() {
+            object.shift_insert(0, "version".to_string(), Value::from(CONFIG_VERSION));
         }
     }
+
+    Ok(Migration {
+        from,
+        explicit,
+        notes,
+    })
 }
 
-impl Default for Rules {
-    fn default() -> Self {
-        Rules {
-            server_side_exports: default_rule_config(),
-            component_nesting_depth: default_rule_config(),
-            filename_style_consistency: default_rule_config(),
-            file_organization: default_rule_config(),
-            bassist_domain_structure: default_rule_config(),
-            bassist_locale_layout: default_rule_config(),
-            bassist_locale_nesting: default_rule_config(),
-            bassist_route_group_names: default_rule_config(),
-            bassist_service_client_restriction: default_rule_config(),
-            bassist_supabase_client_imports: default_rule_config(),
-            bassist_i18n_hook_usage: default_rule_config(),
-            bassist_test_colocation: default_rule_config(),
-            bassist_test_naming: default_rule_config(),
-            bassist_api_route_structure: default_rule_config(),
-            bassist_domain_isolation: default_rule_config(),
-            bassist_i18n_namespaces: default_rule_config(),
+/// Version 1 -> 2: every rule used to accept every option of the shared
+/// options struct; drop those a rule never reads
+fn migrate_v1_shared_options(raw: &mut Value) -> Vec<String> {
+    let known = serde_json::to_value(Rules::default()).unwrap_or(Value::Null);
+    let mut notes = Vec::new();
+
+    let rules = match raw.get_mut("rules").and_then(Value::as_object_mut) {
+        Some(rules) => rules,
+        None => return notes,
+    };
+
+    for (rule, value) in rules.iter_mut() {
+        let known_options = match known[rule.as_str()]["options"].as_object() {
+            Some(known_options) => known_options,
+            None => continue,
+        };
+        let options = match value.get_mut("options").and_then(Value::as_object_mut) {
+            Some(options) => options,
+            None => continue,
+        };
+
+        for option in V1_SHARED_OPTIONS {
+            if !known_options.contains_key(option) && options.shift_remove(option).is_some() {
+                notes.push(format!(
+                    "Removed option '{}' from rule '{}', which doesn't read it",
+                    option, rule
+                ));
+            }
+        }
+
+        if options.is_empty() {
+            if let Some(rule) = value.as_object_mut() {
+                rule.shift_remove("options");
+            }
         }
     }
+
+    notes
 }
 
-impl Default for RuleOptions {
-    fn default() -> Self {
-        RuleOptions {
-            max_nesting_depth: default_max_depth(),
-            filename_style: default_filename_style(),
-            file_organization_checks: Vec::new(),
-            bassist: BassistOptions::default(),
+/// Move rules configured under a deprecated name to their current key,
+/// returning a deprecation warning for each
+fn resolve_rule_aliases(raw: &mut Value) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    let rules = match raw.get_mut("rules").and_then(Value::as_object_mut) {
+        Some(rules) => rules,
+        None => return warnings,
+    };
+
+    for (old, new, removed_in) in DEPRECATED_RULE_NAMES {
+        let value = match rules.shift_remove(old) {
+            Some(value) => value,
+            None => continue,
+        };
+
+        if rules.contains_key(new) {
+            warnings.push(format!(
+                "Rule '{}' is configured under both its old name '{}' and its new name; '{}' is ignored",
+                new, old, old
+            ));
+        } else {
+            rules.insert(new.to_string(), value);
+            warnings.push(format!(
+                "Rule '{}' was renamed to '{}'; the old name stops working in {}",
+                old, new, removed_in
+            ));
         }
     }
+
+    warnings
 }
 
-impl Default for BassistOptions {
-    fn default() -> Self {
-        BassistOptions {
-            allowed_route_groups: default_allowed_route_groups(),
-            cross_domain_allowed_paths: default_cross_domain_allowed_paths(),
-            enforce_test_naming: false,
+/// The comment lines (and blank lines) a config file starts with
+fn leading_comments(contents: &str, extension: &str) -> String {
+    let marker = match extension {
+        "yaml" | "yml" => "#",
+        _ => "//",
+    };
+
+    let mut header = String::new();
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with(marker) && !trimmed.is_empty() {
+            break;
         }
+        header.push_str(line);
+        header.push('\n');
+    }
+
+    if header.trim().is_empty() {
+        return String::new();
     }
+
+    // Keep a single blank line between the header and the body
+    let separated = header.ends_with("\n\n");
+    header.truncate(header.trim_end().len());
+    header.push('\n');
+    if separated {
+        header.push('\n');
+    }
+    header
 }
 
-impl Config {
-    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
-        let contents = fs::read_to_string(path)?;
-        let extension = path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .unwrap_or_default()
-            .to_ascii_lowercase();
+/// List options in the raw config that the rule they are set on doesn't have
+fn unknown_option_warnings(raw: &Value, rules: &Rules) -> Vec<String> {
+    let known = serde_json::to_value(rules).unwrap_or(Value::Null);
+    let mut warnings = Vec::new();
 
-        let mut config: Config = match extension.as_str() {
-            "yaml" | "yml" => serde_yaml::from_str(&contents)?,
-            "jsonc" => json5::from_str(&contents)?,
-            // Attempt strict JSON first, then fall back to JSON5 to allow comments
-            "json" | "" => {
-                serde_json::from_str(&contents).or_else(|_| json5::from_str(&contents))?
-            }
-            // Unknown extension: try JSON, then JSON5 (JSON with comments), then YAML
-            _ => serde_json::from_str(&contents)
-                .or_else(|_| json5::from_str(&contents))
-                .or_else(|_| serde_yaml::from_str(&contents))?,
+    let raw_rules = match raw.get("rules").and_then(Value::as_object) {
+        Some(rules) => rules,
+        None => return warnings,
+    };
+
+    for (rule, value) in raw_rules {
+        let options = match value.get("options").and_then(Value::as_object) {
+            Some(options) => options,
+            None => continue,
+        };
+        let known_options = match known[rule.as_str()]["options"].as_object() {
+            Some(known_options) => known_options,
+            None => continue,
         };
-        
-        // Apply preset if specified
-        config.apply_preset();
-        
-        Ok(config)
-    }
 
-    /// Apply preset configuration if a preset is specified
-    pub fn apply_preset(&mut self) {
-        if let Some(preset) = self.preset {
-            match preset {
-                PresetName::Bassist => self.apply_bassist_preset(),
+        for option in options.keys() {
+            if !known_options.contains_key(option) {
+                warnings.push(format!(
+                    "Unknown option '{}' for rule '{}' is ignored",
+                    option, rule
+                ));
             }
         }
     }
 
-    /// Apply Bassist preset configuration
-    fn apply_bassist_preset(&mut self) {
-        // Enable Bassist rules with Error severity
-        self.rules.bassist_domain_structure.severity = Severity::Error;
-        self.rules.bassist_locale_layout.severity = Severity::Error;
-        self.rules.bassist_locale_nesting.severity = Severity::Error;
-        self.rules.bassist_route_group_names.severity = Severity::Warn;
-        self.rules.bassist_service_client_restriction.severity = Severity::Error;
-        self.rules.bassist_supabase_client_imports.severity = Severity::Error;
-        self.rules.bassist_i18n_hook_usage.severity = Severity::Error;
-        self.rules.bassist_test_colocation.severity = Severity::Warn;
-        self.rules.bassist_test_naming.severity = Severity::Warn;
-        self.rules.bassist_api_route_structure.severity = Severity::Warn;
-        self.rules.bassist_domain_isolation.severity = Severity::Warn;
-        self.rules.bassist_i18n_namespaces.severity = Severity::Warn;
-    }
+    warnings
 }
 
 #[cfg(test)]
@@ -355,15 +2318,18 @@ mod tests {
         let config = Config::default();
         assert!(matches!(config.rules.server_side_exports.severity, Severity::Warn));
         assert!(matches!(config.rules.component_nesting_depth.severity, Severity::Warn));
-        assert!(matches!(config.rules.filename_style_consistency.severity, Severity::Warn));
+        assert!(matches!(config.rules.filename_style.severity, Severity::Warn));
         assert!(matches!(config.rules.file_organization.severity, Severity::Warn));
+        assert!(!config.rules.actions_filename.is_enabled());
     }
 
     #[test]
     fn test_default_rule_options() {
-        let options = RuleOptions::default();
-        assert_eq!(options.max_nesting_depth, 3);
-        assert!(matches!(options.filename_style, FilenameStyle::KebabCase));
+        assert_eq!(NestingDepthOptions::default().max_nesting_depth, 3);
+        assert!(matches!(
+            FilenameStyleOptions::default().filename_style,
+            FilenameStyle::KebabCase
+        ));
     }
 
     #[test]
@@ -373,6 +2339,9 @@ mod tests {
 
         let error = serde_json::to_string(&Severity::Error).unwrap();
         assert_eq!(error, "\"error\"");
+
+        let off = serde_json::to_string(&Severity::Off).unwrap();
+        assert_eq!(off, "\"off\"");
     }
 
     #[test]
@@ -390,6 +2359,56 @@ mod tests {
         assert_eq!(snake, "\"snake-case\"");
     }
 
+    #[test]
+    fn test_custom_filename_style() {
+        // Built-in styles still deserialize from plain strings
+        let style: FilenameStyle = serde_json::from_str("\"pascal-case\"").unwrap();
+        assert_eq!(style, FilenameStyle::PascalCase);
+
+        let style: FilenameStyle =
+            serde_json::from_str(r#"{ "custom": "[a-z]+(\\.[a-z]+)*" }"#).unwrap();
+        match &style {
+            FilenameStyle::Custom(pattern) => {
+                assert!(pattern.is_match("feature.section"));
+                // The pattern is anchored to the whole name
+                assert!(!pattern.is_match("Feature.section"));
+                assert!(!pattern.is_match("feature.section-2"));
+            }
+            other => panic!("expected a custom style, got {:?}", other),
+        }
+        assert_eq!(
+            serde_json::to_string(&style).unwrap(),
+            r#"{"custom":"[a-z]+(\\.[a-z]+)*"}"#
+        );
+
+        let error = serde_json::from_str::<FilenameStyle>(r#"{ "custom": "(" }"#).unwrap_err();
+        assert!(error.to_string().contains("invalid regex '('"));
+    }
+
+    #[test]
+    fn test_fail_on_parsing() {
+        let config: Config = serde_json::from_str(r#"{"fail_on": "warning"}"#).unwrap();
+        assert_eq!(config.fail_on, FailOn::Warning);
+
+        let config: Config = serde_json::from_str("{}").unwrap();
+        assert_eq!(config.fail_on, FailOn::Error);
+        assert!(!config.root);
+    }
+
+    #[test]
+    fn test_omitted_rules_match_defaults() {
+        let parsed: Config = serde_json::from_str(r#"{"rules":{}}"#).unwrap();
+        let parsed = serde_json::to_value(&parsed.rules).unwrap();
+        let defaults = serde_json::to_value(Rules::default()).unwrap();
+        for (name, rule) in defaults.as_object().unwrap() {
+            assert_eq!(parsed[name]["severity"], rule["severity"], "severity of {}", name);
+        }
+        assert_eq!(
+            serde_json::from_str::<Config>(r#"{"rules":{}}"#).unwrap().rules.client_env_prefix.severity,
+            Severity::Error
+        );
+    }
+
     #[test]
     fn test_config_load_from_file() {
         let temp_dir = std::env::temp_dir();
@@ -406,7 +2425,7 @@ mod tests {
                         "max_nesting_depth": 5
                     }
                 },
-                "filename_style_consistency": {
+                "filename_style": {
                     "severity": "error",
                     "options": {
                         "filename_style": "pascal-case"
@@ -422,11 +2441,49 @@ mod tests {
         assert!(matches!(config.rules.server_side_exports.severity, Severity::Error));
         assert!(matches!(config.rules.component_nesting_depth.severity, Severity::Warn));
         assert_eq!(config.rules.component_nesting_depth.options.max_nesting_depth, 5);
-        assert!(matches!(config.rules.filename_style_consistency.severity, Severity::Error));
-        assert!(matches!(config.rules.filename_style_consistency.options.filename_style, FilenameStyle::PascalCase));
+        assert!(matches!(config.rules.filename_style.severity, Severity::Error));
+        assert!(matches!(config.rules.filename_style.options.filename_style, FilenameStyle::PascalCase));
         std::fs::remove_file(config_path).ok();
     }
 
+    #[test]
+    fn test_deprecated_rule_names() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-deprecated-rule-names");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let rule = r#"{ "severity": "error", "options": { "filename_style": "pascal-case" } }"#;
+        let old_path = temp_dir.join("old.json");
+        fs::write(&old_path, format!(r#"{{ "rules": {{ "filename_style_consistency": {} }} }}"#, rule)).unwrap();
+        let new_path = temp_dir.join("new.json");
+        fs::write(&new_path, format!(r#"{{ "rules": {{ "filename_style": {} }} }}"#, rule)).unwrap();
+
+        let old = Config::load(&old_path).unwrap();
+        let new = Config::load(&new_path).unwrap();
+        for config in [&old, &new] {
+            assert!(matches!(config.rules.filename_style.severity, Severity::Error));
+            assert!(matches!(config.rules.filename_style.options.filename_style, FilenameStyle::PascalCase));
+        }
+
+        assert!(new.warnings.is_empty());
+        assert_eq!(
+            old.warnings,
+            vec!["Rule 'filename_style_consistency' was renamed to 'filename_style'; the old name stops working in 0.2.0".to_string()]
+        );
+
+        // The current name wins when both are set
+        let both_path = temp_dir.join("both.json");
+        fs::write(
+            &both_path,
+            r#"{ "rules": { "filename_style": { "severity": "off" }, "filename_style_consistency": { "severity": "error" } } }"#,
+        )
+        .unwrap();
+        let both = Config::load(&both_path).unwrap();
+        assert!(!both.rules.filename_style.is_enabled());
+        assert!(both.warnings[0].contains("'filename_style_consistency' is ignored"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_partial_config_uses_defaults() {
         let temp_dir = std::env::temp_dir();
@@ -457,6 +2514,323 @@ mod tests {
         std::fs::remove_file(config_path).ok();
     }
 
+    #[test]
+    fn test_rule_severity_shorthand() {
+        let shorthand: Config =
+            serde_json::from_str(r#"{"rules":{"server_side_exports":"error","filename_style":"off"}}"#)
+                .unwrap();
+        let verbose: Config = serde_json::from_str(
+            r#"{"rules":{"server_side_exports":{"severity":"error"},"filename_style":{"severity":"off"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            serde_json::to_value(&shorthand).unwrap(),
+            serde_json::to_value(&verbose).unwrap()
+        );
+        assert_eq!(shorthand.rules.server_side_exports.severity, Severity::Error);
+        assert!(matches!(shorthand.rules.filename_style.options.filename_style, FilenameStyle::KebabCase));
+
+        let yaml: Config = serde_yaml::from_str("rules:\n  server_side_exports: error\n").unwrap();
+        assert_eq!(yaml.rules.server_side_exports.severity, Severity::Error);
+
+        let error = serde_json::from_str::<Config>(r#"{"rules":{"server_side_exports":"eror"}}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("unknown variant `eror`"), "{}", error);
+
+        // Options merged over a shorthand keep its severity
+        let temp_dir = std::env::temp_dir().join("naechste-tests-severity-shorthand");
+        fs::create_dir_all(&temp_dir).unwrap();
+        fs::write(temp_dir.join("base.json"), r#"{"rules":{"filename_style":"error"}}"#).unwrap();
+        fs::write(
+            temp_dir.join("local.json"),
+            r#"{"rules":{"filename_style":{"options":{"filename_style":"pascal-case"}}}}"#,
+        )
+        .unwrap();
+        let config =
+            Config::load_layered(&[temp_dir.join("base.json"), temp_dir.join("local.json")]).unwrap();
+        assert_eq!(config.rules.filename_style.severity, Severity::Error);
+        assert!(matches!(config.rules.filename_style.options.filename_style, FilenameStyle::PascalCase));
+
+        // And a shorthand merged over options keeps them
+        let mut config: Config = serde_json::from_str(
+            r#"{
+                "rules": { "filename_style": { "options": { "filename_style": "pascal-case" } } },
+                "env_overrides": { "ci": { "rules": { "filename_style": "error" } } }
+            }"#,
+        )
+        .unwrap();
+        assert!(config.apply_env("ci").unwrap());
+        assert_eq!(config.rules.filename_style.severity, Severity::Error);
+        assert!(matches!(config.rules.filename_style.options.filename_style, FilenameStyle::PascalCase));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_unknown_rule_options_warn() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-unknown-options-config.json");
+
+        let config_json = r#"{
+            "version": 2,
+            "rules": {
+                "component_nesting_depth": {
+                    "options": {
+                        "max_nesting_depth": 4,
+                        "filename_style": "pascal-case"
+                    }
+                },
+                "filename_style": {
+                    "options": {
+                        "filename_style": "pascal-case"
+                    }
+                }
+            }
+        }"#;
+
+        let mut file = File::create(&config_path).unwrap();
+        file.write_all(config_json.as_bytes()).unwrap();
+
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.rules.component_nesting_depth.options.max_nesting_depth, 4);
+        assert!(matches!(
+            config.rules.filename_style.options.filename_style,
+            FilenameStyle::PascalCase
+        ));
+        assert_eq!(
+            config.warnings,
+            vec!["Unknown option 'filename_style' for rule 'component_nesting_depth' is ignored"]
+        );
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_load_migrates_version_1() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-migrate-v1-config.json");
+
+        // Version 1 configs could carry every shared option on every rule
+        let config_json = r#"{
+            "rules": {
+                "component_nesting_depth": {
+                    "options": { "max_nesting_depth": 4, "filename_style": "kebab-case" }
+                },
+                "server_side_exports": {
+                    "severity": "error",
+                    "options": { "max_nesting_depth": 3 }
+                }
+            }
+        }"#;
+        std::fs::write(&config_path, config_json).unwrap();
+
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.rules.component_nesting_depth.options.max_nesting_depth, 4);
+        assert_eq!(config.rules.server_side_exports.severity, Severity::Error);
+        assert_eq!(config.warnings.len(), 3);
+        assert!(config.warnings[0].contains("naechste migrate-config"));
+        assert_eq!(
+            config.warnings[1],
+            "Removed option 'filename_style' from rule 'component_nesting_depth', which doesn't read it"
+        );
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_load_unversioned_config_without_legacy_options() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-unversioned-config.json");
+        std::fs::write(&config_path, r#"{ "fail_on": "warning" }"#).unwrap();
+
+        let config = Config::load(&config_path).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert!(config.warnings.is_empty());
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_load_rejects_newer_version() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-newer-version-config.json");
+        std::fs::write(&config_path, r#"{ "version": 99 }"#).unwrap();
+
+        let err = Config::load(&config_path).unwrap_err();
+        assert!(err.is::<UnsupportedVersionError>());
+        assert!(err.to_string().contains("upgrade naechste"));
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_migrate_file_keeps_header_comments() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-migrate-file-config.yaml");
+        std::fs::write(
+            &config_path,
+            "# Shared lint config\n\nrules:\n  server_side_exports:\n    severity: error\n    options:\n      filename_style: kebab-case\n",
+        )
+        .unwrap();
+
+        assert_eq!(Config::migrate_file(&config_path).unwrap(), Some(1));
+        assert_eq!(
+            std::fs::read_to_string(&config_path).unwrap(),
+            "# Shared lint config\n\nversion: 2\nrules:\n  server_side_exports:\n    severity: error\n"
+        );
+
+        // Already current: left alone
+        assert_eq!(Config::migrate_file(&config_path).unwrap(), None);
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_migrate_file_renames_rules() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-migrate-file-renamed.json");
+        std::fs::write(
+            &config_path,
+            r#"{"rules":{"filename_style_consistency":{"severity":"error","options":{"filename_style":"kebab-case","max_nesting_depth":3}}}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(Config::migrate_file(&config_path).unwrap(), Some(1));
+        let migrated: Value =
+            serde_json::from_str(&std::fs::read_to_string(&config_path).unwrap()).unwrap();
+        assert_eq!(
+            migrated,
+            serde_json::json!({
+                "version": 2,
+                "rules": {
+                    "filename_style": {
+                        "severity": "error",
+                        "options": { "filename_style": "kebab-case" }
+                    }
+                }
+            })
+        );
+
+        // The migrated file loads without warnings and needs no second run
+        let config = Config::load(&config_path).unwrap();
+        assert!(config.warnings.is_empty(), "{:?}", config.warnings);
+        assert_eq!(config.rules.filename_style.severity, Severity::Error);
+        assert_eq!(Config::migrate_file(&config_path).unwrap(), None);
+
+        // A current file still under an old name is renamed too
+        std::fs::write(
+            &config_path,
+            r#"{"version":2,"rules":{"filename_style_consistency":"off"}}"#,
+        )
+        .unwrap();
+        assert_eq!(Config::migrate_file(&config_path).unwrap(), Some(2));
+        let config = Config::load(&config_path).unwrap();
+        assert!(config.warnings.is_empty(), "{:?}", config.warnings);
+        assert_eq!(config.rules.filename_style.severity, Severity::Off);
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_write_starter_loads_as_defaults() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-write-starter");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let path = Config::write_starter(&temp_dir, false).unwrap();
+        assert_eq!(path, temp_dir.join("naechste.json"));
+
+        let config = Config::load(&path).unwrap();
+        assert!(config.warnings.is_empty());
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::to_value(Config::default()).unwrap()
+        );
+
+        // Existing configs are only replaced with force
+        assert!(Config::write_starter(&temp_dir, false).is_err());
+        assert!(Config::write_starter(&temp_dir, true).is_ok());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_allowed_exceptions() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-allowed-exceptions-config.json");
+
+        fs::write(
+            &config_path,
+            r#"{ "rules": { "filename_style": { "options": { "allowed_exceptions": ["^API$", "Types$"] } } } }"#,
+        )
+        .unwrap();
+        let config = Config::load(&config_path).unwrap();
+        let exceptions = &config.rules.filename_style.options.allowed_exceptions;
+        assert!(exceptions.is_match("API"));
+        assert!(exceptions.is_match("GraphQLTypes"));
+        assert!(!exceptions.is_match("APIClient"));
+
+        // Invalid patterns fail loading and name the pattern
+        fs::write(
+            &config_path,
+            r#"{ "rules": { "filename_style": { "options": { "allowed_exceptions": ["(unclosed"] } } } }"#,
+        )
+        .unwrap();
+        let error = Config::load(&config_path).unwrap_err().to_string();
+        assert!(error.contains("invalid regex '(unclosed'"));
+
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_required_content_patterns_are_validated() {
+        let config: Config = serde_json::from_str(
+            r#"{ "rules": { "catch_all_api_routes": { "options": { "required_content_patterns": ["requireSession\\("] } } } }"#,
+        )
+        .unwrap();
+        assert!(config
+            .rules
+            .catch_all_api_routes
+            .options
+            .required_content_patterns
+            .is_match("await requireSession()"));
+
+        let error = serde_json::from_str::<Config>(
+            r#"{ "rules": { "catch_all_api_routes": { "options": { "required_content_patterns": ["requireSession("] } } } }"#,
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(error.contains("invalid regex 'requireSession('"), "{}", error);
+    }
+
+    #[test]
+    fn test_file_header_pattern_is_validated() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-header-pattern-config.json");
+
+        fs::write(
+            &config_path,
+            r#"{ "rules": { "file_header": { "severity": "error", "options": { "header_pattern": "Copyright \\(c\\) \\d{4}" } } } }"#,
+        )
+        .unwrap();
+        let config = Config::load(&config_path).unwrap();
+        let pattern = config.rules.file_header.options.header_pattern.as_ref().unwrap();
+        assert!(pattern.is_match("// Copyright (c) 2026 Acme"));
+        assert_eq!(pattern.as_str(), r"Copyright \(c\) \d{4}");
+
+        fs::write(
+            &config_path,
+            r#"{ "rules": { "file_header": { "options": { "header_pattern": "Copyright (c" } } } }"#,
+        )
+        .unwrap();
+        let error = Config::load(&config_path).unwrap_err().to_string();
+        assert!(error.contains("invalid regex 'Copyright (c'"), "{}", error);
+
+        std::fs::remove_file(config_path).ok();
+    }
+
     #[test]
     fn test_invalid_config_file() {
         let temp_dir = std::env::temp_dir();
@@ -519,6 +2893,156 @@ rules:
         std::fs::remove_file(config_path).ok();
     }
 
+    #[test]
+    fn test_parse_error_position() {
+        let temp_dir = std::env::temp_dir();
+        let config_path = temp_dir.join("test-parse-error-config.jsonc");
+        fs::write(
+            &config_path,
+            "{\n  // severities\n  \"rules\": {\n    \"server_side_exports\": { \"severity\": \"eror\" }\n  }\n}\n",
+        )
+        .unwrap();
+
+        let error = Config::load(&config_path).unwrap_err();
+        let error = error.downcast_ref::<ConfigParseError>().unwrap();
+        assert_eq!(error.line, Some(4));
+        assert!(error.message.contains("unknown variant `eror`"));
+        assert!(error
+            .excerpt
+            .ends_with("   4 |     \"server_side_exports\": { \"severity\": \"eror\" }\n     |                                          ^\n"));
+
+        let config_path = temp_dir.join("test-parse-error-config.yaml");
+        fs::write(&config_path, "rules:\n  server_side_exports:\n    severity: error\n   options: {}\n")
+            .unwrap();
+
+        let error = Config::load(&config_path).unwrap_err();
+        let error = error.downcast_ref::<ConfigParseError>().unwrap();
+        assert_eq!((error.line, error.column), (Some(4), Some(4)));
+        assert!(!error.message.contains("at line"));
+        assert!(error.to_string().starts_with("line 4, column 4: did not find expected key"));
+
+        std::fs::remove_file(temp_dir.join("test-parse-error-config.jsonc")).ok();
+        std::fs::remove_file(config_path).ok();
+    }
+
+    #[test]
+    fn test_load_cascade_merges_parent_configs() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-config-cascade");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(temp_dir.join("packages/web")).unwrap();
+
+        let outer = temp_dir.join("naechste.json");
+        fs::write(
+            &outer,
+            r#"{
+                "fail_on": "warning",
+                "rules": {
+                    "server_side_exports": { "severity": "error" },
+                    "component_nesting_depth": { "severity": "error", "options": { "max_nesting_depth": 2 } }
+                }
+            }"#,
+        )
+        .unwrap();
+        let inner = temp_dir.join("packages/web/naechste.yaml");
+        fs::write(&inner, "rules:\n  component_nesting_depth:\n    options:\n      max_nesting_depth: 5\n").unwrap();
+
+        let paths = vec![inner.clone(), outer.clone()];
+        let (config, used) = Config::load_cascade(&paths).unwrap();
+        assert_eq!(used, paths);
+        assert_eq!(config.fail_on, FailOn::Warning);
+        assert_eq!(config.rules.server_side_exports.severity, Severity::Error);
+        // Objects merge key by key: the outer severity stays, the inner option wins
+        assert_eq!(config.rules.component_nesting_depth.severity, Severity::Error);
+        assert_eq!(config.rules.component_nesting_depth.options.max_nesting_depth, 5);
+
+        // root: true stops inheritance
+        fs::write(
+            &inner,
+            "root: true\nrules:\n  component_nesting_depth:\n    options:\n      max_nesting_depth: 5\n",
+        )
+        .unwrap();
+        let (config, used) = Config::load_cascade(&paths).unwrap();
+        assert_eq!(used, vec![inner.clone()]);
+        assert_eq!(config.fail_on, FailOn::Error);
+        assert_eq!(config.rules.server_side_exports.severity, Severity::Warn);
+        assert_eq!(config.rules.component_nesting_depth.severity, Severity::Warn);
+        assert_eq!(config.rules.component_nesting_depth.options.max_nesting_depth, 5);
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_apply_env_overrides() {
+        let mut config: Config = serde_json::from_str(
+            r#"{
+                "rules": {
+                    "filename_style": { "severity": "warn", "options": { "filename_style": "pascal-case" } }
+                },
+                "env_overrides": {
+                    "ci": { "fail_on": "warning", "rules": { "filename_style": { "severity": "error" } } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert!(!config.apply_env("staging").unwrap());
+        assert_eq!(config.env, None);
+        assert_eq!(config.rules.filename_style.severity, Severity::Warn);
+
+        assert!(config.apply_env("ci").unwrap());
+        assert_eq!(config.env.as_deref(), Some("ci"));
+        assert_eq!(config.fail_on, FailOn::Warning);
+        assert_eq!(config.rules.filename_style.severity, Severity::Error);
+        // Objects merge key by key, so the base options stay
+        assert!(matches!(config.rules.filename_style.options.filename_style, FilenameStyle::PascalCase));
+    }
+
+    #[test]
+    fn test_load_layered_merges_checks_by_id() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-config-layered");
+        std::fs::remove_dir_all(&temp_dir).ok();
+        std::fs::create_dir_all(&temp_dir).unwrap();
+
+        let base = temp_dir.join("base.json");
+        fs::write(
+            &base,
+            r#"{
+                "rules": {
+                    "server_side_exports": { "severity": "error" },
+                    "file_organization": { "options": { "file_organization_checks": [
+                        { "id": "hooks", "match": { "glob": "hooks/*.ts" } },
+                        { "id": "components", "match": { "glob": "components/*.tsx" } }
+                    ] } }
+                }
+            }"#,
+        )
+        .unwrap();
+        let local = temp_dir.join("local.jsonc");
+        fs::write(
+            &local,
+            r#"{
+                // Repo-specific additions
+                "rules": {
+                    "file_organization": { "options": { "file_organization_checks": [
+                        { "id": "components", "match": { "glob": "ui/*.tsx" } },
+                        { "id": "actions", "match": { "glob": "**/*.actions.ts" } }
+                    ] } }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let config = Config::load_layered(&[base, local]).unwrap();
+        assert_eq!(config.rules.server_side_exports.severity, Severity::Error);
+        let checks = &config.rules.file_organization.options.file_organization_checks;
+        let ids: Vec<&str> = checks.iter().map(|check| check.id.as_str()).collect();
+        assert_eq!(ids, vec!["hooks", "components", "actions"]);
+        // A later entry replaces the earlier one with the same id
+        assert_eq!(checks[1].r#match.glob, "ui/*.tsx");
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_nonexistent_config_file() {
         let config_path = std::env::temp_dir().join("nonexistent-config.json");
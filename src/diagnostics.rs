@@ -1,7 +1,9 @@
-use crate::config::Severity;
+use crate::config::{FailOn, Severity};
 use colored::*;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Diagnostic {
@@ -11,6 +13,66 @@ pub struct Diagnostic {
     pub file: PathBuf,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub line: Option<usize>,
+    /// Workspace project the diagnostic belongs to, set with `--workspaces`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub project: Option<PathBuf>,
+    /// Rule-specific values for `message_template` placeholders
+    #[serde(skip)]
+    pub tokens: Vec<(&'static str, String)>,
+}
+
+impl Diagnostic {
+    /// Substitute `{file}` (relative to the project root), `{rule}`, `{line}`,
+    /// `{message}` and the diagnostic's own tokens in `template`
+    pub fn render_template(&self, template: &str, project_root: &Path) -> String {
+        let file = self.file.strip_prefix(project_root).unwrap_or(&self.file);
+        let line = self.line.map(|line| line.to_string()).unwrap_or_default();
+        let mut message = template
+            .replace("{file}", &file.display().to_string())
+            .replace("{rule}", &self.rule)
+            .replace("{line}", &line);
+        for (token, value) in &self.tokens {
+            message = message.replace(&format!("{{{}}}", token), value);
+        }
+        // Last, so that placeholders in the default message are kept as is
+        message.replace("{message}", &self.message)
+    }
+}
+
+/// Information about the run included in machine-readable output
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Config file that was loaded, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<PathBuf>,
+    /// All config files merged into the configuration, highest precedence
+    /// first, when there are several
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub config_files: Vec<PathBuf>,
+    /// Environment whose `env_overrides` were applied, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub env: Option<String>,
+    /// Per-project totals, set with `--workspaces`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub projects: Vec<ProjectSummary>,
+}
+
+/// Diagnostic totals for one workspace project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSummary {
+    pub path: PathBuf,
+    /// Config file used for the project, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub config: Option<PathBuf>,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+/// Shape of machine-readable (JSON/YAML) output
+#[derive(Serialize)]
+struct Report<'a> {
+    metadata: &'a Metadata,
+    diagnostics: &'a [Diagnostic],
 }
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -26,15 +88,55 @@ impl DiagnosticCollection {
     }
 
     pub fn add(&mut self, diagnostic: Diagnostic) {
+        // Disabled rules never report
+        if diagnostic.severity == Severity::Off {
+            return;
+        }
         self.diagnostics.push(diagnostic);
     }
 
+    /// Replace the messages of rules that configure a `message_template`,
+    /// keyed by rule id; `file-organization:<check>` uses the
+    /// `file-organization` template
+    pub fn apply_message_templates(
+        &mut self,
+        templates: &BTreeMap<String, String>,
+        project_root: &Path,
+    ) {
+        for diagnostic in &mut self.diagnostics {
+            let rule = diagnostic.rule.split(':').next().unwrap_or_default();
+            if let Some(template) = templates.get(rule) {
+                diagnostic.message = diagnostic.render_template(template, project_root);
+            }
+        }
+    }
+
+    /// Attribute every diagnostic to a workspace project
+    pub fn set_project(&mut self, project: &Path) {
+        for diagnostic in &mut self.diagnostics {
+            diagnostic.project = Some(project.to_path_buf());
+        }
+    }
+
+    /// Append the diagnostics of another collection (e.g. from another project path)
+    pub fn extend(&mut self, other: DiagnosticCollection) {
+        self.diagnostics.extend(other.diagnostics);
+    }
+
     pub fn has_errors(&self) -> bool {
         self.diagnostics
             .iter()
             .any(|d| matches!(d.severity, Severity::Error))
     }
 
+    /// Whether the collected diagnostics should fail the run
+    pub fn should_fail(&self, fail_on: FailOn) -> bool {
+        match fail_on {
+            FailOn::Error => self.has_errors(),
+            FailOn::Warning => !self.diagnostics.is_empty(),
+        }
+    }
+
     pub fn error_count(&self) -> usize {
         self.diagnostics
             .iter()
@@ -50,16 +152,17 @@ impl DiagnosticCollection {
     }
 }
 
-pub fn print_human(collection: &DiagnosticCollection) {
+pub fn write_human(out: &mut dyn Write, collection: &DiagnosticCollection) -> io::Result<()> {
     if collection.diagnostics.is_empty() {
-        println!("{}", "✓ No issues found!".green().bold());
-        return;
+        writeln!(out, "{}", "✓ No issues found!".green().bold())?;
+        return Ok(());
     }
 
     for diagnostic in &collection.diagnostics {
         let severity_str = match diagnostic.severity {
             Severity::Error => "error".red().bold(),
             Severity::Warn => "warn".yellow().bold(),
+            Severity::Off => continue,
         };
 
         let file_path = diagnostic.file.display();
@@ -69,38 +172,175 @@ pub fn print_human(collection: &DiagnosticCollection) {
             format!("{}", file_path)
         };
 
-        println!(
+        writeln!(
+            out,
             "{}: {} [{}]",
             severity_str,
             diagnostic.message,
             diagnostic.rule.cyan()
-        );
-        println!("  {} {}", "-->".blue(), location);
-        println!();
+        )?;
+        writeln!(out, "  {} {}", "-->".blue(), location)?;
+        writeln!(out)?;
     }
 
     let error_count = collection.error_count();
     let warning_count = collection.warning_count();
 
     if error_count > 0 {
-        println!(
+        writeln!(
+            out,
             "{} {} error(s), {} warning(s) found",
             "✗".red().bold(),
             error_count,
             warning_count
-        );
+        )
     } else {
-        println!(
+        writeln!(
+            out,
             "{} {} warning(s) found",
             "⚠".yellow().bold(),
             warning_count
-        );
+        )
+    }
+}
+
+/// Write one line of totals per workspace project
+pub fn write_project_summary(out: &mut dyn Write, projects: &[ProjectSummary]) -> io::Result<()> {
+    writeln!(out)?;
+    writeln!(out, "{}", "Projects:".bold())?;
+    for project in projects {
+        let status = if project.errors > 0 {
+            "✗".red().bold()
+        } else if project.warnings > 0 {
+            "⚠".yellow().bold()
+        } else {
+            "✓".green().bold()
+        };
+        writeln!(
+            out,
+            "  {} {}: {} error(s), {} warning(s)",
+            status,
+            project.path.display(),
+            project.errors,
+            project.warnings
+        )?;
     }
+    Ok(())
 }
 
-pub fn print_json(collection: &DiagnosticCollection) {
-    let json = serde_json::to_string_pretty(collection).unwrap();
-    println!("{}", json);
+pub fn write_json(
+    out: &mut dyn Write,
+    collection: &DiagnosticCollection,
+    metadata: &Metadata,
+) -> io::Result<()> {
+    let report = Report {
+        metadata,
+        diagnostics: &collection.diagnostics,
+    };
+    let json = serde_json::to_string_pretty(&report).unwrap();
+    writeln!(out, "{}", json)
+}
+
+pub fn write_yaml(
+    out: &mut dyn Write,
+    collection: &DiagnosticCollection,
+    metadata: &Metadata,
+) -> io::Result<()> {
+    write!(out, "{}", to_yaml(collection, metadata))
+}
+
+/// Write a compact plaintext digest for automated reviewers
+pub fn write_agent(
+    out: &mut dyn Write,
+    collection: &DiagnosticCollection,
+    max_per_rule: usize,
+) -> io::Result<()> {
+    write!(out, "{}", to_agent(collection, max_per_rule))
+}
+
+/// Render diagnostics as one section per rule with `path:line` entries,
+/// sorted so the output is stable across runs
+///
+/// Each entry carries a fingerprint so findings can be referred to later.
+/// Sections are truncated after `max_per_rule` entries; the last line holds
+/// the totals as `key=value` pairs.
+fn to_agent(collection: &DiagnosticCollection, max_per_rule: usize) -> String {
+    let mut by_rule: BTreeMap<&str, Vec<&Diagnostic>> = BTreeMap::new();
+    for diagnostic in &collection.diagnostics {
+        by_rule.entry(&diagnostic.rule).or_default().push(diagnostic);
+    }
+
+    let mut out = String::new();
+    let mut shown = 0;
+    for (rule, mut diagnostics) in by_rule {
+        diagnostics.sort_by(|a, b| {
+            (&a.file, a.line, &a.message).cmp(&(&b.file, b.line, &b.message))
+        });
+
+        out.push_str(&format!(
+            "## {} ({}): {}\n",
+            rule,
+            diagnostics.len(),
+            crate::rules::rule_description(rule)
+        ));
+        for diagnostic in diagnostics.iter().take(max_per_rule) {
+            let severity = match diagnostic.severity {
+                Severity::Error => "E",
+                Severity::Warn => "W",
+                Severity::Off => continue,
+            };
+            let location = match diagnostic.line {
+                Some(line) => format!("{}:{}", diagnostic.file.display(), line),
+                None => diagnostic.file.display().to_string(),
+            };
+            out.push_str(&format!(
+                "{} {} {} {}\n",
+                severity,
+                fingerprint(diagnostic),
+                location,
+                diagnostic.message
+            ));
+            shown += 1;
+        }
+        if diagnostics.len() > max_per_rule {
+            out.push_str(&format!("... {} more\n", diagnostics.len() - max_per_rule));
+        }
+    }
+
+    out.push_str(&format!(
+        "TOTAL errors={} warnings={} shown={}\n",
+        collection.error_count(),
+        collection.warning_count(),
+        shown
+    ));
+    out
+}
+
+/// Short stable identifier of a finding (FNV-1a over rule, file, line and message)
+fn fingerprint(diagnostic: &Diagnostic) -> String {
+    let line = diagnostic.line.map(|line| line.to_string()).unwrap_or_default();
+    let key = format!(
+        "{}\0{}\0{}\0{}",
+        diagnostic.rule,
+        diagnostic.file.display(),
+        line,
+        diagnostic.message
+    );
+
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in key.bytes() {
+        hash ^= u64::from(byte);
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    format!("{:08x}", hash >> 32)
+}
+
+fn to_yaml(collection: &DiagnosticCollection, metadata: &Metadata) -> String {
+    let report = Report {
+        metadata,
+        diagnostics: &collection.diagnostics,
+    };
+    serde_yaml::to_string(&report).unwrap()
 }
 
 #[cfg(test)]
@@ -127,6 +367,8 @@ mod tests {
             message: "Test warning".to_string(),
             file: PathBuf::from("test.ts"),
             line: Some(10),
+            project: None,
+            tokens: Vec::new(),
         });
         
         assert_eq!(collection.diagnostics.len(), 1);
@@ -135,6 +377,58 @@ mod tests {
         assert!(!collection.has_errors());
     }
 
+    #[test]
+    fn test_add_ignores_off_severity() {
+        let mut collection = DiagnosticCollection::new();
+        
+        collection.add(Diagnostic {
+            severity: Severity::Off,
+            rule: "test-rule".to_string(),
+            message: "Disabled".to_string(),
+            file: PathBuf::from("test.ts"),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+        
+        assert_eq!(collection.diagnostics.len(), 0);
+    }
+
+    #[test]
+    fn test_apply_message_templates() {
+        let mut collection = DiagnosticCollection::new();
+        collection.add(Diagnostic {
+            severity: Severity::Warn,
+            rule: "file-organization:hooks".to_string(),
+            message: "Hook is misplaced".to_string(),
+            file: PathBuf::from("/project/src/useThing.ts"),
+            line: Some(3),
+            project: None,
+            tokens: Vec::new(),
+        });
+        collection.add(Diagnostic {
+            severity: Severity::Warn,
+            rule: "test-rule".to_string(),
+            message: "Untouched".to_string(),
+            file: PathBuf::from("/project/test.ts"),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+
+        let templates = BTreeMap::from([(
+            "file-organization".to_string(),
+            "[{rule}] {file}:{line} {message}".to_string(),
+        )]);
+        collection.apply_message_templates(&templates, Path::new("/project"));
+
+        assert_eq!(
+            collection.diagnostics[0].message,
+            "[file-organization:hooks] src/useThing.ts:3 Hook is misplaced"
+        );
+        assert_eq!(collection.diagnostics[1].message, "Untouched");
+    }
+
     #[test]
     fn test_has_errors() {
         let mut collection = DiagnosticCollection::new();
@@ -145,6 +439,8 @@ mod tests {
             message: "Test warning".to_string(),
             file: PathBuf::from("test.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         assert!(!collection.has_errors());
@@ -155,11 +451,32 @@ mod tests {
             message: "Test error".to_string(),
             file: PathBuf::from("test.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         assert!(collection.has_errors());
     }
 
+    #[test]
+    fn test_should_fail() {
+        let mut collection = DiagnosticCollection::new();
+        assert!(!collection.should_fail(FailOn::Warning));
+
+        collection.add(Diagnostic {
+            severity: Severity::Warn,
+            rule: "test-rule".to_string(),
+            message: "Test warning".to_string(),
+            file: PathBuf::from("test.ts"),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+
+        assert!(!collection.should_fail(FailOn::Error));
+        assert!(collection.should_fail(FailOn::Warning));
+    }
+
     #[test]
     fn test_error_count() {
         let mut collection = DiagnosticCollection::new();
@@ -170,6 +487,8 @@ mod tests {
             message: "Error 1".to_string(),
             file: PathBuf::from("test1.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         collection.add(Diagnostic {
@@ -178,6 +497,8 @@ mod tests {
             message: "Warning 1".to_string(),
             file: PathBuf::from("test2.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         collection.add(Diagnostic {
@@ -186,6 +507,8 @@ mod tests {
             message: "Error 2".to_string(),
             file: PathBuf::from("test3.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         assert_eq!(collection.error_count(), 2);
@@ -200,6 +523,8 @@ mod tests {
             message: "Test message".to_string(),
             file: PathBuf::from("test.ts"),
             line: Some(42),
+            project: None,
+            tokens: Vec::new(),
         };
         
         let json = serde_json::to_string(&diagnostic).unwrap();
@@ -217,6 +542,8 @@ mod tests {
             message: "Test message".to_string(),
             file: PathBuf::from("test.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         };
         
         let json = serde_json::to_string(&diagnostic).unwrap();
@@ -233,6 +560,8 @@ mod tests {
             message: "Error message".to_string(),
             file: PathBuf::from("error.ts"),
             line: Some(10),
+            project: None,
+            tokens: Vec::new(),
         });
         
         collection.add(Diagnostic {
@@ -241,6 +570,8 @@ mod tests {
             message: "Warning message".to_string(),
             file: PathBuf::from("warn.ts"),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
         
         let json = serde_json::to_string(&collection).unwrap();
@@ -248,4 +579,85 @@ mod tests {
         assert!(json.contains("\"rule1\""));
         assert!(json.contains("\"rule2\""));
     }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let mut collection = DiagnosticCollection::new();
+
+        collection.add(Diagnostic {
+            severity: Severity::Error,
+            rule: "rule1".to_string(),
+            message: "Error message".to_string(),
+            file: PathBuf::from("error.ts"),
+            line: Some(10),
+            project: None,
+            tokens: Vec::new(),
+        });
+
+        collection.add(Diagnostic {
+            severity: Severity::Warn,
+            rule: "rule2".to_string(),
+            message: "Warning message".to_string(),
+            file: PathBuf::from("warn.ts"),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+
+        let yaml = to_yaml(&collection, &Metadata::default());
+        assert!(yaml.contains("line: 10"));
+        assert_eq!(yaml.matches("line:").count(), 1);
+
+        let parsed: DiagnosticCollection = serde_yaml::from_str(&yaml).unwrap();
+        assert_eq!(parsed.diagnostics.len(), 2);
+        assert_eq!(parsed.diagnostics[0].rule, "rule1");
+        assert_eq!(parsed.diagnostics[0].line, Some(10));
+        assert_eq!(parsed.diagnostics[1].severity, Severity::Warn);
+        assert_eq!(parsed.diagnostics[1].line, None);
+    }
+
+    #[test]
+    fn test_agent_format() {
+        let mut collection = DiagnosticCollection::new();
+        for (file, line) in [("b.ts", Some(3)), ("a.ts", Some(7)), ("a.ts", Some(2))] {
+            collection.add(Diagnostic {
+                severity: Severity::Warn,
+                rule: "filename-style".to_string(),
+                message: "Bad name".to_string(),
+                file: PathBuf::from(file),
+                line,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+        collection.add(Diagnostic {
+            severity: Severity::Error,
+            rule: "server-side-exports".to_string(),
+            message: "Server-side export".to_string(),
+            file: PathBuf::from("app/page.tsx"),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+
+        let output = to_agent(&collection, 2);
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(
+            lines[0],
+            "## filename-style (3): Filenames must follow the configured naming style"
+        );
+        assert!(lines[1].starts_with("W ") && lines[1].ends_with(" a.ts:2 Bad name"));
+        assert!(lines[2].ends_with(" a.ts:7 Bad name"));
+        assert_eq!(lines[3], "... 1 more");
+        assert!(lines[4].starts_with("## server-side-exports (1): "));
+        assert!(lines[5].starts_with("E ") && lines[5].ends_with(" app/page.tsx Server-side export"));
+        assert_eq!(lines[6], "TOTAL errors=1 warnings=3 shown=3");
+        assert!(!output.contains('\u{1b}'));
+
+        // Fingerprints don't depend on the order diagnostics were found in
+        collection.diagnostics.reverse();
+        assert_eq!(to_agent(&collection, 2), output);
+    }
 }
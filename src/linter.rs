@@ -1,64 +1,236 @@
 use crate::config::Config;
 use crate::diagnostics::DiagnosticCollection;
 use crate::rules;
-use std::path::Path;
-use walkdir::WalkDir;
+use crate::snapshot;
+use crate::tsconfig::PathAliases;
+use indicatif::ProgressBar;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use ignore::WalkBuilder;
 
-pub fn lint(path: &Path, config: &Config) -> DiagnosticCollection {
-    let mut diagnostics = DiagnosticCollection::new();
-    let mut all_files = Vec::new();
+/// Files found while walking a project
+#[derive(Default)]
+struct ProjectFiles {
+    /// Source files rules run on
+    source: Vec<PathBuf>,
+    /// Static assets under public/
+    public: Vec<PathBuf>,
+    /// Every package.json, including the root one
+    package_json: Vec<PathBuf>,
+    /// Directories below the project root, for rules that check their names
+    directories: Vec<PathBuf>,
+}
 
-    // Walk through the project directory
-    for entry in WalkDir::new(path)
-        .into_iter()
+/// Collect the source files to lint, the static assets under public/,
+/// package.json files and directories
+fn collect_files(path: &Path, config: &Config) -> ProjectFiles {
+    let mut files = ProjectFiles::default();
+    let public_dir = path.join("public");
+
+    // Hidden files are linted like any other; .gitignore rules apply unless
+    // disabled, also outside a git repository
+    let respect_gitignore = config.files.gitignore;
+    let walker = WalkBuilder::new(path)
+        .standard_filters(false)
+        .git_ignore(respect_gitignore)
+        .git_exclude(respect_gitignore)
+        .git_global(respect_gitignore)
+        .parents(respect_gitignore)
+        .require_git(false)
         .filter_entry(|e| !is_ignored(e.path()))
-    {
-        if let Ok(entry) = entry {
-            let file_path = entry.path();
+        .build();
 
-            // Skip directories
-            if !file_path.is_file() {
-                continue;
-            }
+    // Walk through the project directory
+    for entry in walker.flatten() {
+        let file_path = entry.path();
 
-            // Skip non-relevant files
-            if !is_relevant_file(file_path) {
-                continue;
+        // Directories are only collected for path-based rules
+        if !file_path.is_file() {
+            if file_path.is_dir() && file_path != path {
+                files.directories.push(file_path.to_path_buf());
             }
+            continue;
+        }
 
-            // Collect all files for batch processing
-            all_files.push(file_path.to_path_buf());
-
-            // Run per-file rules
-            rules::check_server_side_exports(file_path, config, &mut diagnostics);
-            rules::check_component_nesting_depth(file_path, config, &mut diagnostics);
-            rules::check_filename_style(file_path, config, &mut diagnostics);
-
-            // Bassist per-file rules
-            rules::check_bassist_locale_nesting(file_path, config, &mut diagnostics);
-            rules::check_bassist_service_client_restriction(file_path, config, &mut diagnostics);
-            rules::check_bassist_supabase_client_imports(file_path, config, &mut diagnostics);
-            rules::check_bassist_i18n_hook_usage(file_path, config, &mut diagnostics);
-            rules::check_bassist_test_colocation(file_path, config, &mut diagnostics);
-            rules::check_bassist_test_naming(file_path, config, &mut diagnostics);
-            rules::check_bassist_api_route_structure(file_path, config, &mut diagnostics);
-            rules::check_bassist_domain_isolation(file_path, config, &mut diagnostics);
-            rules::check_bassist_i18n_namespaces(file_path, config, &mut diagnostics);
+        // Static assets are checked regardless of their extension
+        if file_path.starts_with(&public_dir) {
+            files.public.push(file_path.to_path_buf());
         }
+
+        if file_path.file_name().and_then(|n| n.to_str()) == Some("package.json") {
+            files.package_json.push(file_path.to_path_buf());
+        }
+
+        // Skip non-relevant files
+        if !is_relevant_file(file_path, &config.files.extensions) {
+            continue;
+        }
+
+        files.source.push(file_path.to_path_buf());
     }
 
+    files
+}
+
+/// Lint a project; `progress` counts the files checked by per-file rules
+/// (pass `ProgressBar::hidden()` to report nothing)
+pub fn lint(path: &Path, config: &Config, progress: &ProgressBar) -> DiagnosticCollection {
+    lint_files(path, config, None, progress)
+}
+
+/// Lint only `changed` files; batch rules still see the whole project but
+/// only their diagnostics for changed files are kept
+pub fn lint_changed(
+    path: &Path,
+    config: &Config,
+    changed: &HashSet<PathBuf>,
+    progress: &ProgressBar,
+) -> DiagnosticCollection {
+    lint_files(path, config, Some(changed), progress)
+}
+
+fn lint_files(
+    path: &Path,
+    config: &Config,
+    only: Option<&HashSet<PathBuf>>,
+    progress: &ProgressBar,
+) -> DiagnosticCollection {
+    let mut diagnostics = DiagnosticCollection::new();
+    let files = collect_files(path, config);
+    let all_files = files.source;
+    let is_selected = |file: &Path| only.is_none_or(|only| only.contains(file));
+
+    let aliases = PathAliases::load(path).with_import_aliases(path, &config.import_aliases);
+
+    let selected: Vec<&PathBuf> = all_files.iter().filter(|file| is_selected(file)).collect();
+    progress.set_length(selected.len() as u64);
+
+    for file_path in selected {
+        progress.inc(1);
+
+        // Run per-file rules
+        rules::check_server_side_exports(file_path, config, &mut diagnostics);
+        rules::check_component_nesting_depth(file_path, config, &mut diagnostics);
+        rules::check_filename_style(path, file_path, config, &mut diagnostics);
+        rules::check_server_only_next_apis_in_client(path, file_path, &aliases, config, &mut diagnostics);
+        rules::check_actions_filename(file_path, config, &mut diagnostics);
+        rules::check_fetch_cache_option(file_path, config, &mut diagnostics);
+        rules::check_legacy_special_files_in_app(file_path, config, &mut diagnostics);
+        rules::check_no_focused_tests(file_path, config, &mut diagnostics);
+        rules::check_hook_file_naming(file_path, config, &mut diagnostics);
+        rules::check_no_next_head_in_app(file_path, config, &mut diagnostics);
+        rules::check_route_explicit_status(file_path, config, &mut diagnostics);
+        rules::check_default_export_name_match(file_path, config, &mut diagnostics);
+        rules::check_export_placement(file_path, config, &mut diagnostics);
+        rules::check_client_only_libs(file_path, config, &mut diagnostics);
+        rules::check_dynamic_in_server(file_path, config, &mut diagnostics);
+        rules::check_hydration_risk(file_path, config, &mut diagnostics);
+        rules::check_client_env_prefix(file_path, config, &mut diagnostics);
+        rules::check_page_default_export(file_path, config, &mut diagnostics);
+        rules::check_max_url_depth(file_path, config, &mut diagnostics);
+        rules::check_import_quote_consistency(file_path, config, &mut diagnostics);
+        rules::check_page_file_focus(file_path, config, &mut diagnostics);
+        rules::check_promise_style(file_path, config, &mut diagnostics);
+        rules::check_no_top_level_await_client(file_path, config, &mut diagnostics);
+        rules::check_layout_renders_children(file_path, config, &mut diagnostics);
+        rules::check_no_redundant_use_strict(file_path, config, &mut diagnostics);
+        rules::check_conflicting_directives(file_path, config, &mut diagnostics);
+        rules::check_no_client_hooks_in_server(file_path, config, &mut diagnostics);
+        rules::check_require_metadata(file_path, config, &mut diagnostics);
+        rules::check_conflicting_metadata_exports(file_path, config, &mut diagnostics);
+        rules::check_no_client_metadata(file_path, config, &mut diagnostics);
+        rules::check_no_console(path, file_path, config, &mut diagnostics);
+        rules::check_api_location(path, file_path, config, &mut diagnostics);
+        rules::check_client_only_imports(file_path, config, &mut diagnostics);
+
+        // Bassist per-file rules
+        rules::check_bassist_locale_nesting(file_path, config, &mut diagnostics);
+        rules::check_bassist_service_client_restriction(file_path, config, &mut diagnostics);
+        rules::check_bassist_supabase_client_imports(file_path, config, &mut diagnostics);
+        rules::check_bassist_i18n_hook_usage(file_path, config, &mut diagnostics);
+        rules::check_bassist_test_colocation(file_path, config, &mut diagnostics);
+        rules::check_bassist_test_naming(file_path, config, &mut diagnostics);
+        rules::check_bassist_api_route_structure(file_path, config, &mut diagnostics);
+        rules::check_bassist_domain_isolation(file_path, config, &mut diagnostics);
+        rules::check_bassist_i18n_namespaces(file_path, config, &mut diagnostics);
+    }
+    progress.finish_and_clear();
+
     // Run batch rules that need all files
-    rules::check_file_organization(path, &all_files, config, &mut diagnostics);
+    rules::check_file_organization(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_enforce_location_dirs(path, config, &mut diagnostics);
+    rules::check_required_directories(path, config, &mut diagnostics);
+    rules::check_public_asset_naming(path, &files.public, config, &mut diagnostics);
+    rules::check_no_nested_package_json(path, &files.package_json, config, &mut diagnostics);
+    rules::check_router_migration_boundaries(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_single_style_file(path, &all_files, config, &mut diagnostics);
+    rules::check_catch_all_api_routes(path, &all_files, config, &mut diagnostics);
+    rules::check_import_style_consistency(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_require_memo(path, &all_files, config, &mut diagnostics);
+    rules::check_file_header(path, &all_files, config, &mut diagnostics);
+    rules::check_feature_ownership(path, &all_files, config, &mut diagnostics);
+    rules::check_boundary_consistency(path, &all_files, config, &mut diagnostics);
+    rules::check_max_component_lines(path, &all_files, config, &mut diagnostics);
+    rules::check_outlier_file_size(&all_files, config, &mut diagnostics);
+    rules::check_exported_type_docs(path, &all_files, config, &mut diagnostics);
+    rules::check_no_barrel_files(path, &all_files, config, &mut diagnostics);
+    rules::check_barrel_self_cycle(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_no_circular_imports(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_unresolved_imports(path, &all_files, &aliases, config, &mut diagnostics);
+    rules::check_unique_page_names(path, &all_files, config, &mut diagnostics);
+    rules::check_dynamic_segment_naming(path, &all_files, config, &mut diagnostics);
+    rules::check_no_duplicate_routes(path, &all_files, config, &mut diagnostics);
+    rules::check_route_group_naming(path, &files.directories, config, &mut diagnostics);
+    rules::check_intercepting_routes(path, &files.directories, config, &mut diagnostics);
+    rules::check_no_conflicting_dynamic_segments(path, &all_files, &files.directories, config, &mut diagnostics);
+    rules::check_middleware_location(path, &all_files, config, &mut diagnostics);
 
     // Bassist batch rules
     rules::check_bassist_domain_structure(path, &all_files, config, &mut diagnostics);
     rules::check_bassist_locale_layout(path, &all_files, config, &mut diagnostics);
     rules::check_bassist_route_group_names(path, &all_files, config, &mut diagnostics);
 
+    if config.snapshot.enforce {
+        snapshot::check(path, &config.snapshot, &mut diagnostics);
+    }
+
+    diagnostics.diagnostics.retain(|d| is_selected(&d.file));
+    diagnostics.apply_message_templates(&config.rules.message_templates(), path);
     diagnostics
 }
 
-fn is_ignored(path: &Path) -> bool {
+/// Files changed relative to the git ref `since`, as paths under `path`
+pub fn changed_files(path: &Path, since: &str) -> Result<HashSet<PathBuf>, String> {
+    // `--relative` limits the diff to `path` and prints paths relative to it
+    let output = Command::new("git")
+        .args(["diff", "--name-only", "--relative", since, "--"])
+        .current_dir(path)
+        .output()
+        .map_err(|e| format!("Could not run git: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "git diff against '{}' failed: {}",
+            since,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| path.join(line))
+        .collect())
+}
+
+/// Apply the automatic fixes rules provide; returns the number of files changed
+pub fn fix(path: &Path, config: &Config) -> usize {
+    let files = collect_files(path, config);
+    rules::fix_file_header(path, &files.source, config)
+}
+
+pub(crate) fn is_ignored(path: &Path) -> bool {
     let ignored_dirs = [
         "node_modules",
         ".next",
@@ -79,10 +251,12 @@ fn is_ignored(path: &Path) -> bool {
     })
 }
 
-fn is_relevant_file(path: &Path) -> bool {
+fn is_relevant_file(path: &Path, extensions: &[String]) -> bool {
     if let Some(ext) = path.extension() {
         let ext_str = ext.to_str().unwrap_or("");
-        matches!(ext_str, "js" | "jsx" | "ts" | "tsx" | "mjs" | "cjs")
+        extensions
+            .iter()
+            .any(|allowed| allowed.trim_start_matches('.') == ext_str)
     } else {
         false
     }
@@ -91,6 +265,7 @@ fn is_relevant_file(path: &Path) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::FilesConfig;
     use std::fs;
     use std::io::Write;
 
@@ -138,21 +313,31 @@ mod tests {
 
     #[test]
     fn test_is_relevant_file_js_files() {
-        assert!(is_relevant_file(Path::new("test.js")));
-        assert!(is_relevant_file(Path::new("test.jsx")));
-        assert!(is_relevant_file(Path::new("test.ts")));
-        assert!(is_relevant_file(Path::new("test.tsx")));
-        assert!(is_relevant_file(Path::new("test.mjs")));
-        assert!(is_relevant_file(Path::new("test.cjs")));
+        let extensions = FilesConfig::default().extensions;
+        assert!(is_relevant_file(Path::new("test.js"), &extensions));
+        assert!(is_relevant_file(Path::new("test.jsx"), &extensions));
+        assert!(is_relevant_file(Path::new("test.ts"), &extensions));
+        assert!(is_relevant_file(Path::new("test.tsx"), &extensions));
+        assert!(is_relevant_file(Path::new("test.mjs"), &extensions));
+        assert!(is_relevant_file(Path::new("test.cjs"), &extensions));
     }
 
     #[test]
     fn test_is_relevant_file_non_js_files() {
-        assert!(!is_relevant_file(Path::new("test.css")));
-        assert!(!is_relevant_file(Path::new("test.json")));
-        assert!(!is_relevant_file(Path::new("test.md")));
-        assert!(!is_relevant_file(Path::new("test.txt")));
-        assert!(!is_relevant_file(Path::new("README")));
+        let extensions = FilesConfig::default().extensions;
+        assert!(!is_relevant_file(Path::new("test.css"), &extensions));
+        assert!(!is_relevant_file(Path::new("test.json"), &extensions));
+        assert!(!is_relevant_file(Path::new("test.md"), &extensions));
+        assert!(!is_relevant_file(Path::new("test.txt"), &extensions));
+        assert!(!is_relevant_file(Path::new("README"), &extensions));
+    }
+
+    #[test]
+    fn test_is_relevant_file_configured_extensions() {
+        let extensions = vec!["mdx".to_string(), ".mts".to_string()];
+        assert!(is_relevant_file(Path::new("app/blog/page.mdx"), &extensions));
+        assert!(is_relevant_file(Path::new("lib/db.mts"), &extensions));
+        assert!(!is_relevant_file(Path::new("lib/db.ts"), &extensions));
     }
 
     #[test]
@@ -161,7 +346,7 @@ mod tests {
         fs::create_dir_all(&temp_dir).ok();
         
         let config = Config::default();
-        let diagnostics = lint(&temp_dir, &config);
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
         
         assert_eq!(diagnostics.diagnostics.len(), 0);
         
@@ -177,7 +362,7 @@ mod tests {
         create_temp_file(&file_path, "'use client'\nexport const getServerSideProps = () => {}");
         
         let config = Config::default();
-        let diagnostics = lint(&temp_dir, &config);
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
         
         assert_eq!(diagnostics.diagnostics.len(), 0);
         
@@ -193,12 +378,12 @@ mod tests {
         create_temp_file(&file_path, "export function MyComponent() {}");
         
         let mut config = Config::default();
-        config.rules.filename_style_consistency.options.filename_style = crate::config::FilenameStyle::KebabCase;
-        config.rules.filename_style_consistency.severity = crate::config::Severity::Error;
+        config.rules.filename_style.options.filename_style = crate::config::FilenameStyle::KebabCase;
+        config.rules.filename_style.severity = crate::config::Severity::Error;
         
-        let diagnostics = lint(&temp_dir, &config);
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
         
-        assert!(diagnostics.diagnostics.len() > 0);
+        assert!(!diagnostics.diagnostics.is_empty());
         
         fs::remove_dir_all(&temp_dir).ok();
     }
@@ -213,15 +398,55 @@ mod tests {
         create_temp_file(&temp_dir.join("File3.tsx"), "export function File3() {}");
         
         let mut config = Config::default();
-        config.rules.filename_style_consistency.options.filename_style = crate::config::FilenameStyle::KebabCase;
+        config.rules.filename_style.options.filename_style = crate::config::FilenameStyle::KebabCase;
         
-        let diagnostics = lint(&temp_dir, &config);
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
         
         assert_eq!(diagnostics.diagnostics.len(), 3);
         
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_lint_checks_directory_names() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-directory-names");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        // An empty route group directory is still checked
+        fs::create_dir_all(temp_dir.join("app/(Marketing)")).unwrap();
+        fs::create_dir_all(temp_dir.join("node_modules/(Ignored)")).unwrap();
+
+        let config = Config::default();
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "route-group-naming");
+        assert_eq!(diagnostics.diagnostics[0].file, temp_dir.join("app/(Marketing)"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_lint_configured_extensions() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-configured-extensions");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("lib/MyUtil.mts"), "export const util = 1;");
+
+        let mut config = Config::default();
+        config.rules.filename_style.options.filename_style = crate::config::FilenameStyle::KebabCase;
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.files.extensions.push("mts".to_string());
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "filename-style");
+        assert_eq!(diagnostics.diagnostics[0].file, temp_dir.join("lib/MyUtil.mts"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_lint_ignores_non_js_files() {
         let temp_dir = std::env::temp_dir().join("naechste-tests-non-js");
@@ -232,7 +457,7 @@ mod tests {
         create_temp_file(&temp_dir.join("styles.css"), "body {}");
         
         let config = Config::default();
-        let diagnostics = lint(&temp_dir, &config);
+        let diagnostics = lint(&temp_dir, &config, &ProgressBar::hidden());
         
         assert_eq!(diagnostics.diagnostics.len(), 0);
         
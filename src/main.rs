@@ -1,33 +1,145 @@
-use clap::{Parser, ValueEnum};
-use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
+use indicatif::{ProgressBar, ProgressStyle};
+use std::io::{IsTerminal, Write};
+use std::path::{Path, PathBuf};
 use std::process;
 
 mod config;
 mod diagnostics;
 mod linter;
 mod rules;
+mod schema;
+mod snapshot;
+mod tsconfig;
 mod utils;
+mod workspace;
 
 #[derive(Parser)]
 #[command(name = "naechste")]
 #[command(version = "0.1.0")]
 #[command(about = "A fast, Rust-first CLI to enforce Next.js file-structure conventions", long_about = None)]
 struct Cli {
-    /// Path to the Next.js project directory
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Paths to the Next.js project directories
     #[arg(default_value = ".")]
-    path: PathBuf,
+    paths: Vec<PathBuf>,
 
     /// Output format
     #[arg(short, long, value_enum, default_value_t = OutputFormat::Human)]
     format: OutputFormat,
 
-    /// Path to configuration file
-    #[arg(short, long, default_value = "naechste.json")]
-    config: PathBuf,
+    /// Path to configuration file (skips discovery); repeat to merge several,
+    /// later files over earlier ones
+    #[arg(short, long)]
+    config: Vec<PathBuf>,
 
     /// Preset to apply (e.g., "bassist")
     #[arg(short, long)]
     preset: Option<String>,
+
+    /// Exit with a non-zero code on warnings too (overrides `fail_on` in config)
+    #[arg(long)]
+    error_on_warning: bool,
+
+    /// Apply automatic fixes where available (e.g. inserting missing file headers)
+    #[arg(long)]
+    fix: bool,
+
+    /// Maximum findings listed per rule with `--format agent`
+    #[arg(long, default_value_t = 20)]
+    max_per_rule: usize,
+
+    /// Lint files matched by `.gitignore` too
+    #[arg(long)]
+    no_gitignore: bool,
+
+    /// Treat the paths as workspace roots and lint every Next.js app declared
+    /// in `pnpm-workspace.yaml` or the `workspaces` of package.json
+    #[arg(long)]
+    workspaces: bool,
+
+    /// Only report files changed since this git ref (e.g. `origin/main`)
+    #[arg(long, value_name = "REF")]
+    since: Option<String>,
+
+    /// Environment whose `env_overrides` apply (default: `ci` when the `CI`
+    /// variable is set)
+    #[arg(long, value_name = "NAME")]
+    env: Option<String>,
+
+    /// Write the report to this file instead of stdout, creating parent
+    /// directories as needed
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Don't show a progress bar on stderr while linting
+    #[arg(long)]
+    no_progress: bool,
+
+    /// Print additional information, such as the config file in use
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// Describe a rule and how to fix its findings, then exit without linting
+    #[arg(long, value_name = "RULE")]
+    explain: Option<String>,
+
+    /// When to color the output: `auto` colors it on a terminal
+    #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+    color: ColorChoice,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the JSON Schema for the configuration file
+    Schema {
+        /// Embed the crate version into the schema `$id`
+        #[arg(long)]
+        version_tag: bool,
+    },
+    /// Write a starter `naechste.json` with every rule at its default severity
+    Init {
+        /// Directory to write the config into
+        #[arg(default_value = ".")]
+        path: PathBuf,
+
+        /// Overwrite an existing `naechste.json`
+        #[arg(long)]
+        force: bool,
+    },
+    /// Rewrite a config file in the current config format
+    MigrateConfig {
+        /// Config file, or a directory to discover it from
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+    /// Manage the structure snapshot (`naechste-structure.lock`)
+    Snapshot {
+        #[command(subcommand)]
+        action: SnapshotAction,
+    },
+}
+
+#[derive(Subcommand)]
+enum SnapshotAction {
+    /// Record the current route tree into `naechste-structure.lock`
+    Write {
+        /// Path to the Next.js project directory
+        #[arg(default_value = ".")]
+        path: PathBuf,
+    },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum ColorChoice {
+    /// Color when writing to a terminal
+    Auto,
+    /// Always emit ANSI colors, also when piped or with `--output`
+    Always,
+    /// Never emit ANSI colors
+    Never,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
@@ -36,26 +148,318 @@ enum OutputFormat {
     Human,
     /// JSON output for CI/CD
     Json,
+    /// YAML output with the same structure as JSON
+    Yaml,
+    /// Compact plaintext digest for automated code reviewers
+    Agent,
 }
 
 fn main() {
     let cli = Cli::parse();
 
-    // Determine config path - if not explicitly provided, look in project directory
-    let config_path = if cli.config.to_str() == Some("naechste.json") {
-        // Default case: look for config in the project directory across supported formats
-        find_config_in_directory(&cli.path)
+    match cli.color {
+        ColorChoice::Auto => {}
+        ColorChoice::Always => colored::control::set_override(true),
+        ColorChoice::Never => colored::control::set_override(false),
+    }
+
+    match &cli.command {
+        Some(Command::Schema { version_tag }) => {
+            let schema = schema::generate(*version_tag);
+            println!("{}", serde_json::to_string_pretty(&schema).unwrap());
+            return;
+        }
+        Some(Command::Init { path, force }) => {
+            match config::Config::write_starter(path, *force) {
+                Ok(config_path) => println!("Created {}", config_path.display()),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::MigrateConfig { path }) => {
+            let config_path = if path.is_file() {
+                Some(path.clone())
+            } else {
+                find_config_in_directory(path)
+            };
+            let config_path = match config_path {
+                Some(config_path) => config_path,
+                None => {
+                    eprintln!("Error: No config file found for {}", path.display());
+                    process::exit(1);
+                }
+            };
+
+            match config::Config::migrate_file(&config_path) {
+                Ok(Some(from)) if from == config::CONFIG_VERSION => println!(
+                    "Moved renamed rules in {} to their current names",
+                    config_path.display()
+                ),
+                Ok(Some(from)) => println!(
+                    "Migrated {} from version {} to version {}",
+                    config_path.display(),
+                    from,
+                    config::CONFIG_VERSION
+                ),
+                Ok(None) => println!(
+                    "{} is already at version {}",
+                    config_path.display(),
+                    config::CONFIG_VERSION
+                ),
+                Err(e) => {
+                    eprintln!("Error: Could not migrate {}: {}", config_path.display(), e);
+                    process::exit(1);
+                }
+            }
+            return;
+        }
+        Some(Command::Snapshot {
+            action: SnapshotAction::Write { path },
+        }) => {
+            let lock_path = path.join(snapshot::LOCK_FILE_NAME);
+            let structure = snapshot::StructureSnapshot::capture(path);
+            if let Err(e) = structure.write(&lock_path) {
+                eprintln!("Error: Could not write {}: {}", lock_path.display(), e);
+                process::exit(1);
+            }
+            println!(
+                "Recorded {} routes in {}",
+                structure.routes.len(),
+                lock_path.display()
+            );
+            return;
+        }
+        None => {}
+    }
+
+    if let Some(rule) = &cli.explain {
+        match rules::explain_rule(rule) {
+            Some(explanation) => print!("{}", explanation),
+            None => {
+                let valid: Vec<&str> = rules::RULES.iter().map(|(id, _)| *id).collect();
+                eprintln!(
+                    "Error: Unknown rule '{}'. Valid rules: {}",
+                    rule,
+                    valid.join(", ")
+                );
+                process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let project_paths = if cli.workspaces {
+        let mut projects = Vec::new();
+        for root in &cli.paths {
+            let found = workspace::find_next_projects(root);
+            if found.is_empty() {
+                eprintln!(
+                    "Error: No Next.js projects found in workspace {}",
+                    root.display()
+                );
+                process::exit(2);
+            }
+            projects.extend(found);
+        }
+        projects
+    } else {
+        cli.paths.clone()
+    };
+
+    // A path that can't be read would otherwise lint as an empty, clean project
+    for path in &project_paths {
+        if let Err(e) = std::fs::read_dir(path) {
+            eprintln!("Error: Could not read project directory {}: {}", path.display(), e);
+            process::exit(2);
+        }
+    }
+
+    let mut diagnostics = diagnostics::DiagnosticCollection::new();
+    let mut should_fail = false;
+    let mut loaded_config_paths = Vec::new();
+    let mut applied_env = None;
+    let mut projects = Vec::new();
+
+    // Each path gets its own config so packages in a monorepo can differ
+    for path in &project_paths {
+        let (config, used_config_paths) = load_config(&cli, path);
+        let loaded_config_path = used_config_paths.first().cloned();
+
+        if cli.fix {
+            let fixed = linter::fix(path, &config);
+            if fixed > 0 {
+                eprintln!("Fixed {} file(s) in {}", fixed, path.display());
+            }
+        }
+
+        // Run the linter
+        let progress = progress_bar(&cli);
+        let mut path_diagnostics = match &cli.since {
+            Some(since) => match linter::changed_files(path, since) {
+                Ok(changed) => linter::lint_changed(path, &config, &changed, &progress),
+                Err(e) => {
+                    eprintln!("Error: {}", e);
+                    process::exit(2);
+                }
+            },
+            None => linter::lint(path, &config, &progress),
+        };
+
+        // The CLI flag takes precedence over config
+        let fail_on = if cli.error_on_warning {
+            config::FailOn::Warning
+        } else {
+            config.fail_on
+        };
+        should_fail |= path_diagnostics.should_fail(fail_on);
+
+        if cli.workspaces {
+            path_diagnostics.set_project(path);
+            projects.push(diagnostics::ProjectSummary {
+                path: path.clone(),
+                config: loaded_config_path.clone(),
+                errors: path_diagnostics.error_count(),
+                warnings: path_diagnostics.warning_count(),
+            });
+        }
+
+        diagnostics.extend(path_diagnostics);
+        if config.env.is_some() {
+            applied_env = config.env.clone();
+        }
+        if !loaded_config_paths.contains(&used_config_paths) {
+            loaded_config_paths.push(used_config_paths);
+        }
+    }
+
+    // Output diagnostics; the config is only reported when all paths shared one
+    let shared_config_paths = match loaded_config_paths.as_slice() {
+        [single] => single.clone(),
+        _ => Vec::new(),
+    };
+    let metadata = diagnostics::Metadata {
+        config: shared_config_paths.first().cloned(),
+        config_files: if shared_config_paths.len() > 1 {
+            shared_config_paths
+        } else {
+            Vec::new()
+        },
+        env: applied_env,
+        projects,
+    };
+    let mut out = match output_writer(&cli) {
+        Ok(out) => out,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            process::exit(2);
+        }
+    };
+    let written = match cli.format {
+        OutputFormat::Human => {
+            diagnostics::write_human(&mut out, &diagnostics).and_then(|()| {
+                if metadata.projects.is_empty() {
+                    Ok(())
+                } else {
+                    diagnostics::write_project_summary(&mut out, &metadata.projects)
+                }
+            })
+        }
+        OutputFormat::Json => diagnostics::write_json(&mut out, &diagnostics, &metadata),
+        OutputFormat::Yaml => diagnostics::write_yaml(&mut out, &diagnostics, &metadata),
+        OutputFormat::Agent => diagnostics::write_agent(&mut out, &diagnostics, cli.max_per_rule),
+    };
+    if let Err(e) = written.and_then(|()| out.flush()) {
+        eprintln!("Error: Could not write the report: {}", e);
+        process::exit(2);
+    }
+
+    if let Some(output) = &cli.output {
+        if cli.format == OutputFormat::Human {
+            // The report itself is in the file; keep the outcome visible
+            eprintln!(
+                "{} error(s), {} warning(s); report written to {}",
+                diagnostics.error_count(),
+                diagnostics.warning_count(),
+                output.display()
+            );
+        } else if cli.verbose {
+            eprintln!("Report written to {}", output.display());
+        }
+    }
+
+    // Exit with appropriate code
+    let exit_code = if should_fail { 1 } else { 0 };
+    process::exit(exit_code);
+}
+
+/// Load the config for a project path, honouring `--config` and `--preset`
+///
+/// Returns the config and the files it was merged from, highest precedence first.
+fn load_config(cli: &Cli, path: &Path) -> (config::Config, Vec<PathBuf>) {
+    // An explicit --config always wins over discovery and inherits nothing
+    let loaded = if cli.config.is_empty() {
+        let config_paths = find_configs(path);
+        (!config_paths.is_empty()).then(|| config::Config::load_cascade(&config_paths))
     } else {
-        // Explicitly provided config path
-        cli.config
+        let loaded = match cli.config.as_slice() {
+            [config_path] => config::Config::load(config_path).map_err(|e| {
+                format!("Could not load config file {}: {}", config_path.display(), e).into()
+            }),
+            config_paths => config::Config::load_layered(config_paths),
+        };
+        // Reported like discovered configs, highest precedence first
+        let used = cli.config.iter().rev().cloned().collect();
+        Some(loaded.map(|config| (config, used)))
     };
 
     // Load configuration
-    let mut config = config::Config::load(&config_path).unwrap_or_else(|e| {
-        eprintln!("Warning: Could not load config file: {}", e);
-        eprintln!("Using default configuration");
-        config::Config::default()
-    });
+    let mut used_config_paths = Vec::new();
+    let mut config = match loaded {
+        None => config::Config::default(),
+        Some(loaded) => match loaded {
+            Ok((config, used)) => {
+                for warning in &config.warnings {
+                    eprintln!("Warning: {}", warning);
+                }
+                used_config_paths = used;
+                config
+            }
+            // Falling back to defaults would silently ignore the whole config;
+            // defaults are only used when there is no config file at all
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(2);
+            }
+        },
+    };
+    let loaded_config_path = used_config_paths.first().cloned();
+
+    if cli.verbose {
+        match &loaded_config_path {
+            Some(config_path) if used_config_paths.len() > 1 => eprintln!(
+                "Using config file: {} for {} (inheriting from {})",
+                config_path.display(),
+                path.display(),
+                used_config_paths[1..]
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            Some(config_path) => eprintln!(
+                "Using config file: {} for {}",
+                config_path.display(),
+                path.display()
+            ),
+            None => eprintln!(
+                "No config file found for {}, using default configuration",
+                path.display()
+            ),
+        }
+    }
 
     // Apply CLI preset if provided (overrides config file preset)
     if let Some(preset_str) = &cli.preset {
@@ -70,21 +474,90 @@ fn main() {
         }
     }
 
-    // Run the linter
-    let diagnostics = linter::lint(&cli.path, &config);
+    if let Some(env) = active_env(cli) {
+        match config.apply_env(&env) {
+            Ok(true) if cli.verbose => {
+                eprintln!("Applying env_overrides for environment '{}'", env)
+            }
+            Ok(true) => {}
+            // Only an explicit --env is expected to have overrides
+            Ok(false) if cli.env.is_some() => {
+                eprintln!("Warning: No env_overrides for environment '{}'", env)
+            }
+            Ok(false) => {}
+            Err(e) => {
+                eprintln!("Error: {}", e);
+                process::exit(2);
+            }
+        }
+    }
 
-    // Output diagnostics
-    match cli.format {
-        OutputFormat::Human => diagnostics::print_human(&diagnostics),
-        OutputFormat::Json => diagnostics::print_json(&diagnostics),
+    if cli.no_gitignore {
+        config.files.gitignore = false;
     }
 
-    // Exit with appropriate code
-    let exit_code = if diagnostics.has_errors() { 1 } else { 0 };
-    process::exit(exit_code);
+    (config, used_config_paths)
+}
+
+/// Progress bar for the per-file phase, drawn on stderr only when it is a
+/// terminal and the output isn't meant for machines
+fn progress_bar(cli: &Cli) -> ProgressBar {
+    let machine_readable = matches!(cli.format, OutputFormat::Json | OutputFormat::Yaml);
+    if cli.no_progress || machine_readable || !std::io::stderr().is_terminal() {
+        return ProgressBar::hidden();
+    }
+
+    let style = ProgressStyle::with_template("{bar:40} {pos}/{len} files")
+        .unwrap_or_else(|_| ProgressStyle::default_bar());
+    ProgressBar::new(0).with_style(style)
 }
 
-fn find_config_in_directory(base: &std::path::Path) -> std::path::PathBuf {
+/// Where the report goes: the `--output` file, without colors unless
+/// `--color always`, or stdout
+fn output_writer(cli: &Cli) -> Result<Box<dyn Write>, String> {
+    let path = match &cli.output {
+        Some(path) => path,
+        None => return Ok(Box::new(std::io::stdout().lock())),
+    };
+
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Could not create {}: {}", parent.display(), e))?;
+    }
+    let file = std::fs::File::create(path)
+        .map_err(|e| format!("Could not write {}: {}", path.display(), e))?;
+    if cli.color != ColorChoice::Always {
+        colored::control::set_override(false);
+    }
+    Ok(Box::new(std::io::BufWriter::new(file)))
+}
+
+/// Environment whose `env_overrides` apply: `--env`, or `ci` when the `CI`
+/// variable is set to anything but an empty string, `false` or `0`
+fn active_env(cli: &Cli) -> Option<String> {
+    cli.env.clone().or_else(|| {
+        std::env::var("CI")
+            .ok()
+            .filter(|ci| !matches!(ci.as_str(), "" | "false" | "0"))
+            .map(|_| "ci".to_string())
+    })
+}
+
+/// Look for a config file in `base` and then in each of its ancestors
+///
+/// The first directory containing a config file wins, so a project config
+/// is found from anywhere inside the project and a workspace-level config
+/// is picked up for packages that don't have their own.
+fn find_config_in_directory(base: &Path) -> Option<PathBuf> {
+    find_configs(base).into_iter().next()
+}
+
+/// Config files in `base` and each of its ancestors, nearest first, at most
+/// one per directory
+///
+/// Which of them are inherited is decided when loading, since a config with
+/// `root: true` stops the chain.
+fn find_configs(base: &Path) -> Vec<PathBuf> {
     let candidates = [
         "naechste.json",
         "naechste.jsonc",
@@ -92,13 +565,13 @@ fn find_config_in_directory(base: &std::path::Path) -> std::path::PathBuf {
         "naechste.yml",
     ];
 
-    for candidate in candidates {
-        let path = base.join(candidate);
-        if path.exists() {
-            return path;
-        }
-    }
-
-    // Fallback to the default JSON path even if it does not exist
-    base.join("naechste.json")
+    let base = base.canonicalize().unwrap_or_else(|_| base.to_path_buf());
+    base.ancestors()
+        .filter_map(|dir| {
+            candidates
+                .iter()
+                .map(|candidate| dir.join(candidate))
+                .find(|path| path.is_file())
+        })
+        .collect()
 }
@@ -1,8 +1,162 @@
-use crate::config::{Config, FilenameStyle};
+use crate::config::{Config, FileHeaderOptions, FilenameStyle};
 use crate::diagnostics::{Diagnostic, DiagnosticCollection};
+use crate::tsconfig::PathAliases;
 use regex::Regex;
 use std::fs;
 use std::path::Path;
+use std::sync::LazyLock;
+
+/// Current id of a rule, mapping the ids of renamed rules to their new ones
+pub fn canonical_rule_id(rule: &str) -> String {
+    crate::config::DEPRECATED_RULE_NAMES
+        .iter()
+        .find(|(old, _, _)| old.replace('_', "-") == rule)
+        .map_or_else(|| rule.to_string(), |(_, new, _)| new.replace('_', "-"))
+}
+
+/// Every rule id with its one-line description
+pub const RULES: &[(&str, &str)] = &[
+    ("server-side-exports", "Client components must not export server-side data fetching functions"),
+    ("component-nesting-depth", "Component directories must not be nested too deeply"),
+    ("filename-style", "Filenames must follow the configured naming style"),
+    ("file-organization", "Files must match the configured organization checks"),
+    ("public-asset-naming", "Files and directories under public/ must follow the naming style"),
+    ("actions-filename", "Modules with 'use server' must be named *.actions.ts"),
+    ("fetch-cache-option", "fetch() in server components must state its caching behaviour"),
+    ("no-server-only-next-apis-in-client", "Client components must not import server-only Next.js APIs or modules"),
+    ("legacy-special-files-in-app", "_app/_document files do nothing under app/"),
+    ("router-migration-boundaries", "app/ and pages/ must not import each other during a router migration"),
+    ("no-focused-tests", "Tests must not be focused or skipped"),
+    ("single-style-file", "A component must have at most one style file"),
+    ("hook-file-naming", "Hook files must be named after the hook they export"),
+    ("catch-all-api-routes", "Catch-all API routes must be allowlisted and validate their input"),
+    ("require-memo", "Exported components must be wrapped in memo"),
+    ("file-header", "Source files must start with the configured header"),
+    ("no-next-head-in-app", "next/head does not work in the App Router"),
+    ("import-style-consistency", "Project imports must follow the preferred alias/relative style"),
+    ("default-export-name-match", "A component file's default export must be named after the file"),
+    ("export-placement", "Exports must be declared inline or in one block at the bottom, as configured"),
+    ("client-only-libs", "Server components must not import client-only packages"),
+    ("boundary-consistency", "A client layout must not wrap a server page in the same segment"),
+    ("max-component-lines", "Files must not exceed the maximum line count"),
+    ("exported-type-docs", "Exported types and interfaces in public API files must have JSDoc"),
+    ("dynamic-in-server", "dynamic() with ssr: false must only be used in client components"),
+    ("no-barrel-files", "index barrel files are forbidden in the configured directories"),
+    ("hydration-risk", "Time and random values rendered in JSX need suppressHydrationWarning"),
+    ("client-env-prefix", "Client components may only read NEXT_PUBLIC_ environment variables"),
+    ("enforce-location-missing-dir", "enforce_location must_be_under entries must be existing directories"),
+    ("max-url-depth", "Pages must not be nested deeper in the URL than the configured maximum"),
+    ("barrel-self-cycle", "Modules must not import an index barrel that re-exports them"),
+    ("import-quote-consistency", "Import specifiers in a file must use one quote style"),
+    ("page-file-focus", "Page files must only export the page, its metadata and route segment config"),
+    ("promise-style", "Server components must consume promises in the configured style"),
+    ("page-default-export", "App router pages and the configured special files must have a default export"),
+    ("no-top-level-await-client", "Client components must not use top-level await"),
+    ("layout-renders-children", "App router layouts must accept and render children"),
+    ("required-directories", "Required scaffold directories must exist"),
+    ("no-circular-imports", "Modules must not import each other in a cycle"),
+    ("no-redundant-use-strict", "ES modules must not have a redundant 'use strict' directive"),
+    ("conflicting-directives", "A module must not declare both 'use client' and 'use server'"),
+    ("outlier-file-size", "Files must not be much longer than the other files in their directory"),
+    ("no-client-hooks-in-server", "Server components must not use client-only React hooks"),
+    ("require-metadata", "Pages and the root layout must export metadata or generateMetadata"),
+    ("unique-page-names", "Page components must have unique names"),
+    ("conflicting-metadata-exports", "Files must not export both metadata and generateMetadata"),
+    ("unresolved-import", "Relative and alias imports must resolve to a file"),
+    ("no-client-metadata", "Client components must not export metadata or generateMetadata"),
+    ("dynamic-segment-naming", "Dynamic route segments must be well-formed and follow the parameter style"),
+    ("no-duplicate-routes", "Pages must not resolve to the same URL as another page"),
+    ("route-group-naming", "Route groups must be named in the configured style and unambiguously"),
+    ("intercepting-routes", "Intercepting route markers must be well-formed and match an existing route"),
+    ("no-console", "Production code must not call the disallowed console methods"),
+    ("api-location", "Route handlers must live under the designated API directories"),
+    ("no-conflicting-dynamic-segments", "Sibling dynamic segments must use the same parameter name and kind"),
+    ("middleware-location", "Middleware must sit at the project root or in src/ and export a handler"),
+    ("client-only-imports", "Server components must not import client-only modules"),
+    ("no-nested-package-json", "Source folders must not contain their own package.json"),
+    ("route-explicit-status", "Route handler responses must set an explicit status"),
+    ("feature-ownership", "Each feature under app/ must have an owner"),
+    ("structure-snapshot", "The route tree must match naechste-structure.lock"),
+    ("bassist-domain-structure", "Route groups must contain a [locale]/ directory"),
+    ("bassist-locale-layout", "Each [locale]/ directory must have a layout file"),
+    ("bassist-locale-nesting", "Pages in route groups must be inside [locale]/"),
+    ("bassist-route-group-names", "Route groups must match the configured domain names"),
+    ("bassist-service-client-restriction", "The service client may only be used in tests and seed scripts"),
+    ("bassist-supabase-client-imports", "Supabase clients must match the component type"),
+    ("bassist-i18n-hook-usage", "i18n helpers must match the component type"),
+    ("bassist-test-colocation", "Tests must be colocated with the implementation"),
+    ("bassist-test-naming", "Test files must use the extension for their test type"),
+    ("bassist-api-route-structure", "Route handlers must live in api/ directories"),
+    ("bassist-domain-isolation", "Domains must not import sibling domains' lib/ or components/"),
+    ("bassist-i18n-namespaces", "i18n keys must follow the domain.context pattern"),
+];
+
+/// One-line description of a rule, used by compact output formats
+pub fn rule_description(rule: &str) -> &'static str {
+    let rule = canonical_rule_id(rule);
+    RULES
+        .iter()
+        .find(|(id, _)| *id == rule)
+        .map_or("No description available", |(_, description)| description)
+}
+
+/// Longer explanation of a rule for `--explain`: its description followed by
+/// the rule's README section with rationale, options and examples
+pub fn explain_rule(rule: &str) -> Option<String> {
+    let rule = canonical_rule_id(rule);
+    let description = RULES.iter().find(|(id, _)| *id == rule)?.1;
+    let mut explanation = format!("{}: {}\n", rule, description);
+
+    let heading = format!("(`{}`)", rule);
+    let mut lines = include_str!("../README.md")
+        .lines()
+        .skip_while(|line| !(line.starts_with("### ") && line.ends_with(&heading)));
+    if let Some(title) = lines.next() {
+        explanation.push('\n');
+        // Drop the section number, e.g. "1. Server-Side Exports (...)"
+        let title = title.trim_start_matches('#').trim_start();
+        explanation.push_str(title.split_once(". ").map_or(title, |(_, title)| title));
+        explanation.push('\n');
+        let section: Vec<&str> = lines
+            .take_while(|line| !line.starts_with("## ") && !line.starts_with("### "))
+            .collect();
+        explanation.push_str(section.join("\n").trim_end());
+        explanation.push('\n');
+    }
+
+    Some(explanation)
+}
+
+/// File stems `filename-style` skips: special Next.js files and
+/// config files
+const DEFAULT_IGNORED_FILENAMES: [&str; 17] = [
+    "page",
+    "layout",
+    "template",
+    "loading",
+    "error",
+    "not-found",
+    "route",
+    "default",
+    "middleware",
+    // Config files
+    "next.config",
+    "tailwind.config",
+    "postcss.config",
+    "eslint.config",
+    "tsconfig",
+    "jsconfig",
+    "vitest.config",
+    "jest.config",
+];
+
+/// Server-side only data fetching exports, with the pattern matching their export
+static SERVER_EXPORT_PATTERNS: LazyLock<[(&str, Regex); 4]> = LazyLock::new(|| {
+    ["getServerSideProps", "getStaticProps", "getStaticPaths", "getInitialProps"].map(|export| {
+        let pattern = format!(r"export\s+(const|function|async\s+function)\s+{}", export);
+        (export, Regex::new(&pattern).unwrap())
+    })
+});
 
 /// Check for server-side exports in client components
 pub fn check_server_side_exports(
@@ -16,39 +170,24 @@ pub fn check_server_side_exports(
         Err(_) => return,
     };
 
-    // Check if file has 'use client' directive
-    let has_use_client = content.lines().any(|line| {
-        let trimmed = line.trim();
-        trimmed == "'use client'" || trimmed == "\"use client\""
-    });
-
-    if !has_use_client {
+    if !has_top_level_directive(&content, "use client") {
         return;
     }
 
-    // List of server-side only exports
-    let server_exports = [
-        "getServerSideProps",
-        "getStaticProps",
-        "getStaticPaths",
-        "getInitialProps",
-    ];
-
-    for export in &server_exports {
-        let pattern = format!(r"export\s+(const|function|async\s+function)\s+{}", export);
-        if let Ok(re) = Regex::new(&pattern) {
-            if re.is_match(&content) {
-                diagnostics.add(Diagnostic {
-                    severity: config.rules.server_side_exports.severity,
-                    rule: "server-side-exports".to_string(),
-                    message: format!(
-                        "Server-side export '{}' found in client component",
-                        export
-                    ),
-                    file: path.to_path_buf(),
-                    line: None,
-                });
-            }
+    for (export, re) in SERVER_EXPORT_PATTERNS.iter() {
+        if re.is_match(&content) {
+            diagnostics.add(Diagnostic {
+                severity: config.rules.server_side_exports.severity,
+                rule: "server-side-exports".to_string(),
+                message: format!(
+                    "Server-side export '{}' found in client component",
+                    export
+                ),
+                file: path.to_path_buf(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
         }
     }
 }
@@ -87,16 +226,28 @@ pub fn check_component_nesting_depth(
             ),
             file: path.to_path_buf(),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
     }
 }
 
+/// Count path segments, ignoring route groups `(group)`, parallel route
+/// slots `@slot` and intercepting routes `(.)x`/`(..)x`/`(...)x`, which
+/// organize files without adding URL depth
 fn count_depth(path_part: &str) -> usize {
-    path_part.split('/').filter(|s| !s.is_empty()).count()
+    path_part
+        .split('/')
+        .filter(|s| !s.is_empty())
+        .filter(|s| !(s.starts_with('(') && s.ends_with(')')))
+        .filter(|s| !s.starts_with('@'))
+        .filter(|s| !s.starts_with("(.)") && !s.starts_with("(..)") && !s.starts_with("(...)"))
+        .count()
 }
 
 /// Check filename style consistency
 pub fn check_filename_style(
+    project_root: &Path,
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
@@ -106,78 +257,98 @@ pub fn check_filename_style(
         None => return,
     };
 
-    // Skip special Next.js files and config files
-    let special_files = [
-        "page",
-        "layout",
-        "template",
-        "loading",
-        "error",
-        "not-found",
-        "route",
-        "default",
-        "middleware",
-        // Config files
-        "next.config",
-        "tailwind.config",
-        "postcss.config",
-        "eslint.config",
-        "tsconfig",
-        "jsconfig",
-        "vitest.config",
-        "jest.config",
-    ];
-
-    if special_files.contains(&filename) {
+    let options = &config.rules.filename_style.options;
+    let builtin = if options.override_ignored_filenames {
+        &[][..]
+    } else {
+        &DEFAULT_IGNORED_FILENAMES[..]
+    };
+    let is_ignored = builtin
+        .iter()
+        .copied()
+        .chain(options.ignored_filenames.iter().map(String::as_str))
+        .any(|ignored| {
+            ignored == filename
+                || glob::Pattern::new(ignored).is_ok_and(|pattern| pattern.matches(filename))
+        });
+    if is_ignored || options.allowed_exceptions.is_match(filename) {
         return;
     }
 
-    let expected_style = config.rules.filename_style_consistency.options.filename_style;
+    use crate::utils;
+
+    // The first `by_path` entry matching the file decides the style
+    let selected_by = options
+        .by_path
+        .iter()
+        .find(|entry| utils::matches_glob(path, &entry.glob, project_root));
+    let expected_style = selected_by.map_or(&options.filename_style, |entry| &entry.style);
     let matches_style = match expected_style {
         FilenameStyle::KebabCase => is_kebab_case(filename),
         FilenameStyle::CamelCase => is_camel_case(filename),
         FilenameStyle::PascalCase => is_pascal_case(filename),
         FilenameStyle::SnakeCase => is_snake_case(filename),
+        FilenameStyle::Custom(pattern) => pattern.is_match(filename),
     };
 
     if !matches_style {
         diagnostics.add(Diagnostic {
-            severity: config.rules.filename_style_consistency.severity,
-            rule: "filename-style-consistency".to_string(),
-            message: format!(
-                "Filename '{}' does not match expected style: {:?}",
-                filename, expected_style
-            ),
+            severity: config.rules.filename_style.severity,
+            rule: "filename-style".to_string(),
+            message: match selected_by {
+                Some(entry) => format!(
+                    "Filename '{}' does not match expected style: {} (selected by '{}')",
+                    filename,
+                    expected_style.describe(),
+                    entry.glob
+                ),
+                None => format!(
+                    "Filename '{}' does not match expected style: {}",
+                    filename,
+                    expected_style.describe()
+                ),
+            },
             file: path.to_path_buf(),
             line: None,
+            project: None,
+            tokens: vec![
+                ("filename", filename.to_string()),
+                ("expected_style", expected_style.describe()),
+            ],
         });
     }
 }
 
+static KEBAB_CASE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9]*(-[a-z0-9]+)*$").unwrap());
+static CAMEL_CASE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z][a-zA-Z0-9]*$").unwrap());
+static PASCAL_CASE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z][a-zA-Z0-9]*$").unwrap());
+static SNAKE_CASE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[a-z][a-z0-9]*(_[a-z0-9]+)*$").unwrap());
+
 fn is_kebab_case(s: &str) -> bool {
-    let re = Regex::new(r"^[a-z][a-z0-9]*(-[a-z0-9]+)*$").unwrap();
-    re.is_match(s)
+    KEBAB_CASE_RE.is_match(s)
 }
 
 fn is_camel_case(s: &str) -> bool {
-    let re = Regex::new(r"^[a-z][a-zA-Z0-9]*$").unwrap();
-    re.is_match(s) && s.chars().any(|c| c.is_uppercase())
+    CAMEL_CASE_RE.is_match(s) && s.chars().any(|c| c.is_uppercase())
 }
 
 fn is_pascal_case(s: &str) -> bool {
-    let re = Regex::new(r"^[A-Z][a-zA-Z0-9]*$").unwrap();
-    re.is_match(s) && s.chars().any(|c| c.is_lowercase())
+    PASCAL_CASE_RE.is_match(s) && s.chars().any(|c| c.is_lowercase())
 }
 
 fn is_snake_case(s: &str) -> bool {
-    let re = Regex::new(r"^[a-z][a-z0-9]*(_[a-z0-9]+)*$").unwrap();
-    re.is_match(s)
+    SNAKE_CASE_RE.is_match(s)
 }
 
 /// Check file organization rules
 pub fn check_file_organization(
     project_root: &Path,
     all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
@@ -193,7 +364,7 @@ pub fn check_file_organization(
     }
     
     // Build import index for when_imported_by checks
-    let import_index = utils::build_import_index(all_files, project_root);
+    let import_index = utils::build_import_index(all_files, project_root, aliases, &config.files.extensions);
     
     // Pre-compile regex patterns for all checks
     let mut compiled_patterns: HashMap<String, Vec<Regex>> = HashMap::new();
@@ -240,24 +411,41 @@ pub fn check_file_organization(
                                     ),
                                     file: file.clone(),
                                     line: None,
+                                    project: None,
+                                    tokens: Vec::new(),
                                 });
                             }
                         }
                     }
                     RequireKind::SiblingGlob { glob } => {
                         if let Some(parent) = file.parent() {
-                            let siblings = utils::find_sibling_by_glob(parent, glob);
+                            let mut siblings = utils::find_sibling_by_glob(parent, glob);
+                            let mut named_after = String::new();
+                            if check.strict_companion_stem {
+                                let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+                                let prefix = format!("{}.", stem);
+                                siblings.retain(|sibling| {
+                                    sibling
+                                        .file_name()
+                                        .and_then(|n| n.to_str())
+                                        .is_some_and(|name| name.starts_with(&prefix))
+                                });
+                                named_after = format!(" and named '{}*'", prefix);
+                            }
                             if siblings.is_empty() {
                                 diagnostics.add(Diagnostic {
                                     severity: config.rules.file_organization.severity,
                                     rule: format!("file-organization:{}", check.id),
                                     message: format!(
-                                        "Missing required companion file matching '{}' next to '{}'",
+                                        "Missing required companion file matching '{}'{} next to '{}'",
                                         glob,
+                                        named_after,
                                         file.display()
                                     ),
                                     file: file.clone(),
                                     line: None,
+                                    project: None,
+                                    tokens: Vec::new(),
                                 });
                             }
                         }
@@ -312,6 +500,8 @@ pub fn check_file_organization(
                                     message: msg,
                                     file: file.clone(),
                                     line: None,
+                                    project: None,
+                                    tokens: Vec::new(),
                                 });
                                 break; // Only report once per file
                             }
@@ -323,563 +513,5007 @@ pub fn check_file_organization(
     }
 }
 
-// ==================== BASSIST PRESET RULES ====================
-
-/// Check that each route group has a proper domain structure with [locale] directory
-pub fn check_bassist_domain_structure(
+/// Check that the `must_be_under` directories of `enforce_location` checks
+/// exist, since a check pointing at a missing directory can never pass;
+/// glob entries name no single directory and are skipped
+pub fn check_enforce_location_dirs(
     project_root: &Path,
-    all_files: &[std::path::PathBuf],
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    use std::collections::HashSet;
-    
-    // Find all route groups in app directory
-    let mut route_groups = HashSet::new();
-    
-    for file in all_files {
-        let path_str = file.to_str().unwrap_or("");
-        
-        // Match app/(route-group)/ pattern
-        if let Some(app_pos) = path_str.find("/app/") {
-            let after_app = &path_str[app_pos + 5..];
-            if after_app.starts_with('(') {
-                if let Some(close_paren) = after_app.find(')') {
-                    let route_group = &after_app[1..close_paren];
-                    let route_group_path = project_root.join("app").join(format!("({})", route_group));
-                    route_groups.insert(route_group_path);
-                }
-            }
-        }
+    let rule = &config.rules.enforce_location_missing_dir;
+    if !rule.is_enabled() || !config.rules.file_organization.is_enabled() {
+        return;
     }
-    
-    // Check each route group has [locale]/ directory
-    for route_group_path in route_groups {
-        let locale_dir = route_group_path.join("[locale]");
-        if !locale_dir.exists() {
+
+    for check in &config.rules.file_organization.options.file_organization_checks {
+        let enforce_loc = match &check.enforce_location {
+            Some(enforce_loc) => enforce_loc,
+            None => continue,
+        };
+
+        for prefix in enforce_loc.must_be_under.iter().filter(|p| !p.contains('*')) {
+            // Same normalization as the location check itself
+            let dir = project_root.join(prefix.trim_start_matches('/').trim_end_matches('/'));
+            if dir.is_dir() {
+                continue;
+            }
+
             diagnostics.add(Diagnostic {
-                severity: config.rules.bassist_domain_structure.severity,
-                rule: "bassist-domain-structure".to_string(),
+                severity: rule.severity,
+                rule: "enforce-location-missing-dir".to_string(),
                 message: format!(
-                    "Route group '{}' must contain a '[locale]/' directory for i18n support",
-                    route_group_path.file_name().unwrap().to_str().unwrap()
+                    "must_be_under entry '{}' of organization check '{}' is not a directory in the project, so the check can never pass",
+                    prefix, check.id
                 ),
-                file: route_group_path.clone(),
+                file: dir,
                 line: None,
+                project: None,
+                tokens: Vec::new(),
             });
         }
     }
 }
 
-/// Check that each route group's [locale] directory has a layout.tsx
-pub fn check_bassist_locale_layout(
+/// Check that the `required_dirs` scaffold directories exist and are not
+/// empty, since git doesn't keep empty directories
+pub fn check_required_directories(
     project_root: &Path,
-    all_files: &[std::path::PathBuf],
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
+    let rule = &config.rules.required_directories;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for required in &rule.options.required_dirs {
+        let dir = project_root.join(required.trim_start_matches('/').trim_end_matches('/'));
+        let message = if !dir.is_dir() {
+            format!("Required directory '{}' is missing", required)
+        } else if fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_none()) {
+            format!(
+                "Required directory '{}' is empty and won't be kept by git; add a .gitkeep",
+                required
+            )
+        } else {
+            continue;
+        };
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "required-directories".to_string(),
+            message,
+            file: dir,
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that files and directories under public/ follow the configured naming style
+pub fn check_public_asset_naming(
+    project_root: &Path,
+    public_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
     use std::collections::HashSet;
-    
-    // Find all [locale] directories in route groups
-    let mut locale_dirs = HashSet::new();
-    
-    for file in all_files {
-        let path_str = file.to_str().unwrap_or("");
-        
-        // Match app/(route-group)/[locale]/ pattern
-        if let Some(app_pos) = path_str.find("/app/") {
-            let after_app = &path_str[app_pos + 5..];
-            if after_app.starts_with('(') {
-                if let Some(close_paren) = after_app.find(')') {
-                    let after_route_group = &after_app[close_paren + 1..];
-                    if after_route_group.starts_with("/[locale]") {
-                        let route_group = &after_app[1..close_paren];
-                        let locale_path = project_root
-                            .join("app")
-                            .join(format!("({})", route_group))
-                            .join("[locale]");
-                        locale_dirs.insert(locale_path);
-                    }
+
+    let rule = &config.rules.public_asset_naming;
+    let style = &rule.options.public_asset_style;
+    let public_dir = project_root.join("public");
+    let mut reported_dirs = HashSet::new();
+
+    for file in public_files {
+        if utils::is_excluded(file, &rule.options.public_asset_ignore, &public_dir) {
+            continue;
+        }
+
+        let relative = match file.strip_prefix(&public_dir) {
+            Ok(rel) => rel,
+            Err(_) => continue,
+        };
+
+        // Validate every directory between public/ and the file, once each
+        let mut current = public_dir.clone();
+        let components: Vec<_> = relative.components().collect();
+        for (index, component) in components.iter().enumerate() {
+            let name = match component.as_os_str().to_str() {
+                Some(name) => name,
+                None => continue,
+            };
+            current = current.join(name);
+
+            let is_file = index == components.len() - 1;
+            if !is_file && !reported_dirs.insert(current.clone()) {
+                continue;
+            }
+
+            let kind = if is_file { "file" } else { "directory" };
+
+            // Custom patterns can't suggest a name, only validate it
+            if let FilenameStyle::Custom(pattern) = style {
+                let base = match name.rsplit_once('.') {
+                    Some((base, _)) if is_file && !base.is_empty() => base,
+                    _ => name,
+                };
+                if !pattern.is_match(base) {
+                    diagnostics.add(Diagnostic {
+                        severity: rule.severity,
+                        rule: "public-asset-naming".to_string(),
+                        message: format!(
+                            "Public {} name '{}' does not match expected style: {}",
+                            kind,
+                            name,
+                            style.describe()
+                        ),
+                        file: current.clone(),
+                        line: None,
+                        project: None,
+                        tokens: Vec::new(),
+                    });
                 }
+                continue;
             }
-        }
-    }
-    
-    // Check each [locale] directory has layout.tsx
-    for locale_dir in locale_dirs {
-        let layout_file = locale_dir.join("layout.tsx");
-        let layout_js = locale_dir.join("layout.jsx");
-        
-        if !layout_file.exists() && !layout_js.exists() {
+
+            let suggestion = suggest_asset_name(name, style);
+            if suggestion == name {
+                continue;
+            }
+
+            let problems = asset_name_problems(name, style);
+            let message = if problems.is_empty() {
+                format!(
+                    "Public {} name '{}' does not match expected style: {:?}; rename to '{}'",
+                    kind, name, style, suggestion
+                )
+            } else {
+                format!(
+                    "Public {} name '{}' contains {}; rename to '{}'",
+                    kind,
+                    name,
+                    problems.join(", "),
+                    suggestion
+                )
+            };
+
             diagnostics.add(Diagnostic {
-                severity: config.rules.bassist_locale_layout.severity,
-                rule: "bassist-locale-layout".to_string(),
-                message: format!(
-                    "Locale directory '{}' must contain a layout.tsx file for i18n routing",
-                    locale_dir.display()
-                ),
-                file: locale_dir.clone(),
+                severity: rule.severity,
+                rule: "public-asset-naming".to_string(),
+                message,
+                file: current.clone(),
                 line: None,
+                project: None,
+                tokens: Vec::new(),
             });
         }
     }
 }
 
-/// Check that pages in route groups are inside [locale]/ dynamic segments
-pub fn check_bassist_locale_nesting(
-    path: &Path,
-    config: &Config,
-    diagnostics: &mut DiagnosticCollection,
-) {
-    let path_str = path.to_str().unwrap_or("");
-    
-    // Check for special Next.js files in route groups
-    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-    let is_special_file = matches!(file_name, "page.tsx" | "page.jsx" | "layout.tsx" | "layout.jsx" | "loading.tsx" | "loading.jsx" | "error.tsx" | "error.jsx" | "not-found.tsx" | "not-found.jsx");
-    
-    if !is_special_file {
-        return;
+/// List the characters in an asset name that make for unfriendly URLs
+fn asset_name_problems(name: &str, style: &FilenameStyle) -> Vec<&'static str> {
+    let mut problems = Vec::new();
+    if name.contains(' ') {
+        problems.push("spaces");
     }
-    
-    // Check if file is in a route group
-    if let Some(app_pos) = path_str.find("/app/") {
-        let after_app = &path_str[app_pos + 5..];
-        if after_app.starts_with('(') {
-            // In a route group - check if it's inside [locale]/
-            if !after_app.contains("/[locale]/") {
-                diagnostics.add(Diagnostic {
-                    severity: config.rules.bassist_locale_nesting.severity,
-                    rule: "bassist-locale-nesting".to_string(),
-                    message: format!(
-                        "Page file '{}' in route group must be inside [locale]/ directory for i18n routing",
-                        file_name
-                    ),
-                    file: path.to_path_buf(),
-                    line: None,
-                });
+    let uppercase_allowed = matches!(style, FilenameStyle::CamelCase | FilenameStyle::PascalCase);
+    if !uppercase_allowed && name.chars().any(|c| c.is_ascii_uppercase()) {
+        problems.push("uppercase letters");
+    }
+    if name.contains('(') || name.contains(')') {
+        problems.push("parentheses");
+    }
+    if !name.is_ascii() {
+        problems.push("non-ASCII characters");
+    }
+    problems
+}
+
+/// Rewrite an asset name in the given style, keeping dot-separated parts
+/// (e.g. `hero.2x.png`) and a leading dot intact; the extension is lowercased
+fn suggest_asset_name(name: &str, style: &FilenameStyle) -> String {
+    let (prefix, rest) = match name.strip_prefix('.') {
+        Some(rest) => (".", rest),
+        None => ("", name),
+    };
+
+    let parts: Vec<&str> = rest.split('.').collect();
+    let last = parts.len() - 1;
+    let converted: Vec<String> = parts
+        .iter()
+        .enumerate()
+        .map(|(index, part)| {
+            if index == last && last > 0 {
+                part.to_ascii_lowercase()
+            } else {
+                convert_to_style(part, style)
+            }
+        })
+        .collect();
+
+    format!("{}{}", prefix, converted.join("."))
+}
+
+/// Convert an arbitrary string into the given naming style, dropping characters
+/// that are not ASCII alphanumerics
+fn convert_to_style(s: &str, style: &FilenameStyle) -> String {
+    let mut words: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut previous_lowercase = false;
+
+    for c in s.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && previous_lowercase && !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            previous_lowercase = c.is_ascii_lowercase() || c.is_ascii_digit();
+            current.push(c);
+        } else {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
             }
+            previous_lowercase = false;
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    let capitalize = |word: &str| {
+        let lower = word.to_ascii_lowercase();
+        let mut chars = lower.chars();
+        match chars.next() {
+            Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+            None => String::new(),
         }
+    };
+
+    match style {
+        FilenameStyle::KebabCase => words
+            .iter()
+            .map(|w| w.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("-"),
+        FilenameStyle::SnakeCase => words
+            .iter()
+            .map(|w| w.to_ascii_lowercase())
+            .collect::<Vec<_>>()
+            .join("_"),
+        FilenameStyle::PascalCase => words.iter().map(|w| capitalize(w)).collect(),
+        FilenameStyle::CamelCase => words
+            .iter()
+            .enumerate()
+            .map(|(i, w)| if i == 0 { w.to_ascii_lowercase() } else { capitalize(w) })
+            .collect(),
+        // A regex can't be used to rewrite a name
+        FilenameStyle::Custom(_) => s.to_string(),
     }
 }
 
-/// Check that route group names match allowed list
-pub fn check_bassist_route_group_names(
+/// Kinds of dynamic route segments
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DynamicSegment {
+    /// `[param]`
+    Single,
+    /// `[...param]`
+    CatchAll,
+    /// `[[...param]]`
+    OptionalCatchAll,
+}
+
+impl DynamicSegment {
+    fn format(self, param: &str) -> String {
+        match self {
+            DynamicSegment::Single => format!("[{}]", param),
+            DynamicSegment::CatchAll => format!("[...{}]", param),
+            DynamicSegment::OptionalCatchAll => format!("[[...{}]]", param),
+        }
+    }
+}
+
+/// Parse a route segment name using the bracket syntax into its kind and
+/// parameter; for malformed syntax the error names the problem along with
+/// the kind and parameter the segment most likely meant
+fn parse_dynamic_segment(
+    name: &str,
+) -> Result<(DynamicSegment, &str), (&'static str, DynamicSegment, &str)> {
+    // Text outside the outermost brackets isn't part of the parameter
+    let start = name.find('[').unwrap_or(0);
+    let end = name.rfind(']').map_or(name.len(), |end| end + 1).max(start);
+    let bracketed = &name[start..end];
+
+    let inner = bracketed.trim_start_matches('[').trim_end_matches(']');
+    let param = inner.trim_start_matches('.');
+    let dots = inner.len() - param.len();
+    let kind = if bracketed.starts_with("[[") {
+        DynamicSegment::OptionalCatchAll
+    } else if dots > 0 {
+        DynamicSegment::CatchAll
+    } else {
+        DynamicSegment::Single
+    };
+
+    let problem = if name.matches('[').count() != name.matches(']').count() {
+        "unmatched brackets"
+    } else if bracketed != name {
+        "text outside the brackets"
+    } else if param.contains('[') || param.contains(']') {
+        "more than one parameter"
+    } else if param.is_empty() {
+        "an empty parameter name"
+    } else if kind == DynamicSegment::OptionalCatchAll && dots == 0 {
+        "optional segments that aren't catch-all"
+    } else if dots > 0 && dots != 3 {
+        "catch-all dots other than '...'"
+    } else if kind.format(param) != name {
+        "unbalanced brackets"
+    } else {
+        return Ok((kind, param));
+    };
+
+    Err((problem, kind, param))
+}
+
+/// The route segment a pages router file name stands for, e.g. `[...slug]`
+/// for `[...slug].tsx`; catch-all params contain dots, so only the extension
+/// after the brackets is cut
+fn route_file_segment(name: &str) -> &str {
+    let after = name.rfind(']').map_or(0, |end| end + 1);
+    name[after..].find('.').map_or(name, |dot| &name[..after + dot])
+}
+
+/// Check that dynamic route segments (`[param]`, `[...param]`, `[[...param]]`)
+/// are well-formed and their parameter follows the configured style
+///
+/// App router segments are directories; in the pages router file names are
+/// segments too.
+pub fn check_dynamic_segment_naming(
     project_root: &Path,
     all_files: &[std::path::PathBuf],
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
     use std::collections::HashSet;
-    
-    let allowed_groups: HashSet<&str> = config
-        .rules
-        .bassist_route_group_names
-        .options
-        .bassist
-        .allowed_route_groups
-        .iter()
-        .map(|s| s.as_str())
-        .collect();
-    
-    let mut found_groups = HashSet::new();
-    
-    // Find all route groups
+
+    let rule = &config.rules.dynamic_segment_naming;
+    if !rule.is_enabled() {
+        return;
+    }
+    let style = &rule.options.param_style;
+    let mut checked = HashSet::new();
+
     for file in all_files {
-        let path_str = file.to_str().unwrap_or("");
-        
-        if let Some(app_pos) = path_str.find("/app/") {
-            let after_app = &path_str[app_pos + 5..];
-            if after_app.starts_with('(') {
-                if let Some(close_paren) = after_app.find(')') {
-                    let route_group = &after_app[1..close_paren];
-                    found_groups.insert(route_group.to_string());
+        let relative = match file.strip_prefix(project_root) {
+            Ok(rel) => rel,
+            Err(_) => continue,
+        };
+        let router = match router_of(relative) {
+            Some(router) => router,
+            None => continue,
+        };
+
+        let mut current = project_root.to_path_buf();
+        let components: Vec<_> = relative.components().collect();
+        for (index, component) in components.iter().enumerate() {
+            let name = match component.as_os_str().to_str() {
+                Some(name) => name,
+                None => continue,
+            };
+            current = current.join(name);
+
+            let is_file = index == components.len() - 1;
+            let segment = if is_file {
+                if router != Router::Pages {
+                    continue;
                 }
+                route_file_segment(name)
+            } else {
+                name
+            };
+            // Intercepting routes prefix the segment with (.), (..) or (...)
+            let segment = segment.trim_start_matches(['(', ')', '.']);
+            if !segment.contains('[') && !segment.contains(']') {
+                continue;
             }
-        }
-    }
-    
-    // Check for unknown route groups
-    for group in found_groups {
-        if !allowed_groups.contains(group.as_str()) {
-            let route_group_path = project_root.join("app").join(format!("({})", group));
+            if !checked.insert(current.clone()) {
+                continue;
+            }
+
+            let kind = if is_file { "file" } else { "directory" };
+            let message = match parse_dynamic_segment(segment) {
+                Ok((segment_kind, param)) => {
+                    if let FilenameStyle::Custom(pattern) = style {
+                        if pattern.is_match(param) {
+                            continue;
+                        }
+                        format!(
+                            "Dynamic segment {} '{}' has parameter '{}' that does not match expected style: {}",
+                            kind,
+                            name,
+                            param,
+                            style.describe()
+                        )
+                    } else {
+                        let suggestion = convert_to_style(param, style);
+                        if suggestion == param {
+                            continue;
+                        }
+                        format!(
+                            "Dynamic segment {} '{}' has parameter '{}' that does not match expected style: {}; rename to '{}'",
+                            kind,
+                            name,
+                            param,
+                            style.describe(),
+                            name.replacen(segment, &segment_kind.format(&suggestion), 1)
+                        )
+                    }
+                }
+                Err((problem, segment_kind, param)) => {
+                    let param = convert_to_style(param, style);
+                    if param.is_empty() {
+                        format!("Dynamic segment {} '{}' has {}", kind, name, problem)
+                    } else {
+                        format!(
+                            "Dynamic segment {} '{}' has {}; rename to '{}'",
+                            kind,
+                            name,
+                            problem,
+                            name.replacen(segment, &segment_kind.format(&param), 1)
+                        )
+                    }
+                }
+            };
+
             diagnostics.add(Diagnostic {
-                severity: config.rules.bassist_route_group_names.severity,
-                rule: "bassist-route-group-names".to_string(),
-                message: format!(
-                    "Unknown route group '({})'. Expected one of: {}",
-                    group,
-                    allowed_groups.iter().map(|s| format!("({})", s)).collect::<Vec<_>>().join(", ")
-                ),
-                file: route_group_path,
+                severity: rule.severity,
+                rule: "dynamic-segment-naming".to_string(),
+                message,
+                file: current.clone(),
                 line: None,
+                project: None,
+                tokens: Vec::new(),
             });
         }
     }
 }
 
-/// Check that service client is not used in production code
-pub fn check_bassist_service_client_restriction(
+/// Module references: `import ... from`, side-effect imports, `export ...
+/// from` and `require()`; group 1 is the import clause, group 2 the specifier
+static MODULE_REFERENCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r#"(?:(?:import|export)\s+(?:type\s+)?(?:([^;'"]*?)\s*from\s+)?|require\s*\(\s*)['"]([^'"]+)['"]"#,
+    )
+    .unwrap()
+});
+
+/// Check that client components don't import server-only Next.js APIs or
+/// modules, directly or through a local module that imports one
+pub fn check_server_only_next_apis_in_client(
+    project_root: &Path,
     path: &Path,
+    aliases: &PathAliases,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    let path_str = path.to_str().unwrap_or("");
-    
-    // Allow service client in test files and seed directories
-    if path_str.contains(".test.") || path_str.contains(".spec.") || path_str.contains("/seed/") {
+    use crate::utils;
+
+    let rule = &config.rules.no_server_only_next_apis_in_client;
+    if !rule.is_enabled() {
         return;
     }
-    
-    // Read file content
+
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return,
     };
-    
-    // Check for service client usage
-    let service_client_patterns = [
-        r"createTestServiceClient",
-        r#"from\s+['"]@/lib/supabase/service['"]"#,
-        r#"from\s+['"]@/tests/utils/supabase-service['"]"#,
-    ];
-    
-    for pattern_str in &service_client_patterns {
-        if let Ok(re) = Regex::new(pattern_str) {
-            if re.is_match(&content) {
-                diagnostics.add(Diagnostic {
-                    severity: config.rules.bassist_service_client_restriction.severity,
-                    rule: "bassist-service-client-restriction".to_string(),
-                    message: "Service client (createTestServiceClient) must only be used in test files or seed scripts. This bypasses RLS policies and is a security risk in production code.".to_string(),
-                    file: path.to_path_buf(),
-                    line: None,
-                });
-                break;
-            }
-        }
+
+    if !has_top_level_directive(&content, "use client") {
+        return;
     }
-}
 
-/// Check correct Supabase client imports based on file type
-pub fn check_bassist_supabase_client_imports(
-    path: &Path,
-    config: &Config,
-    diagnostics: &mut DiagnosticCollection,
-) {
-    // Read file content
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return,
+    let forbidden: Vec<(&str, Option<&str>)> = rule
+        .options
+        .forbidden_client_imports
+        .iter()
+        .map(|entry| match entry.split_once('#') {
+            Some((specifier, name)) => (specifier, Some(name)),
+            None => (entry.as_str(), None),
+        })
+        .collect();
+    let is_forbidden_module = |specifier: &str| {
+        forbidden
+            .iter()
+            .any(|(module, name)| name.is_none() && is_module_or_subpath(specifier, module))
     };
-    
-    // Check for "use client" directive in first 10 lines
-    let has_use_client = content.lines().take(10).any(|line| {
-        let trimmed = line.trim();
-        trimmed == "'use client'" || trimmed == "\"use client\""
-    });
-    
-    // Check for server client import
-    let has_server_import = Regex::new(r#"from\s+['"]@/lib/supabase/server['"]"#)
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    // Check for browser client import
-    let has_client_import = Regex::new(r#"from\s+['"]@/lib/supabase/client['"]"#)
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    if has_use_client && has_server_import {
-        diagnostics.add(Diagnostic {
-            severity: config.rules.bassist_supabase_client_imports.severity,
-            rule: "bassist-supabase-client-imports".to_string(),
-            message: "Client component ('use client') should import from '@/lib/supabase/client', not '@/lib/supabase/server'".to_string(),
-            file: path.to_path_buf(),
-            line: None,
-        });
-    }
-    
-    if !has_use_client && has_client_import {
-        // Check if this might be a server component (files in app/ that aren't client)
-        let path_str = path.to_str().unwrap_or("");
-        if path_str.contains("/app/") {
+
+    for cap in MODULE_REFERENCE_RE.captures_iter(&content) {
+        let specifier = &cap[2];
+        let clause = cap.get(1).map_or("", |c| c.as_str());
+        let line = utils::line_number_at(&content, cap.get(0).unwrap().start());
+
+        let mut messages = Vec::new();
+        for (forbidden_specifier, forbidden_name) in &forbidden {
+            match forbidden_name {
+                None if is_module_or_subpath(specifier, forbidden_specifier) => messages.push(format!(
+                    "Client component imports server-only module '{}'",
+                    specifier
+                )),
+                Some(name)
+                    if specifier == *forbidden_specifier
+                        && named_imports(clause).iter().any(|n| n == name) =>
+                {
+                    messages.push(format!(
+                        "Client component imports server-only '{}' from '{}'",
+                        name, specifier
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        // One level deep: a local module that pulls in a server-only module,
+        // unless it is a server actions module the client may call
+        if messages.is_empty() {
+            let target = utils::resolve_import(specifier, path, project_root, aliases, &config.files.extensions);
+            let target_content = target.and_then(|target| fs::read_to_string(target).ok());
+            if let Some(target_content) = target_content {
+                let inner = MODULE_REFERENCE_RE
+                    .captures_iter(&target_content)
+                    .map(|cap| cap[2].to_string())
+                    .find(|inner| is_forbidden_module(inner));
+                if let Some(inner) = inner {
+                    if !has_top_level_directive(&target_content, "use server") {
+                        messages.push(format!(
+                            "Client component imports '{}', which imports server-only module '{}'",
+                            specifier, inner
+                        ));
+                    }
+                }
+            }
+        }
+
+        for message in messages {
             diagnostics.add(Diagnostic {
-                severity: config.rules.bassist_supabase_client_imports.severity,
-                rule: "bassist-supabase-client-imports".to_string(),
-                message: "Server component should import from '@/lib/supabase/server', not '@/lib/supabase/client'".to_string(),
+                severity: rule.severity,
+                rule: "no-server-only-next-apis-in-client".to_string(),
+                message,
                 file: path.to_path_buf(),
-                line: None,
+                line: Some(line),
+                project: None,
+                tokens: Vec::new(),
             });
         }
     }
 }
 
-/// Check correct i18n hook usage based on file type
-pub fn check_bassist_i18n_hook_usage(
+/// Whether `specifier` is `module` or one of its subpaths, treating `node:`
+/// builtins like their bare names
+fn is_module_or_subpath(specifier: &str, module: &str) -> bool {
+    let specifier = specifier.strip_prefix("node:").unwrap_or(specifier);
+    let module = module.strip_prefix("node:").unwrap_or(module);
+    specifier == module
+        || specifier
+            .strip_prefix(module)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Names bound by the `{ ... }` part of an import clause, ignoring `as` aliases
+fn named_imports(clause: &str) -> Vec<String> {
+    let (start, end) = match (clause.find('{'), clause.rfind('}')) {
+        (Some(start), Some(end)) if start < end => (start, end),
+        _ => return Vec::new(),
+    };
+
+    clause[start + 1..end]
+        .split(',')
+        .filter_map(|part| {
+            let part = part.trim();
+            let part = part.strip_prefix("type ").unwrap_or(part);
+            let name = part.split_whitespace().next()?;
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+static REACT_IMPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"import\s+(?:type\s+)?([^;'"]*?)\s*from\s+['"]react['"]"#).unwrap()
+});
+
+/// Check that server components under `app/` don't use client-only hooks,
+/// either by importing them from `react` or by calling them
+pub fn check_no_client_hooks_in_server(
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    // Read file content
+    use crate::utils;
+
+    let rule = &config.rules.no_client_hooks_in_server;
+    if !rule.is_enabled() || rule.options.client_hooks.is_empty() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return,
     };
-    
-    // Check for "use client" directive
-    let has_use_client = content.lines().take(10).any(|line| {
-        let trimmed = line.trim();
-        trimmed == "'use client'" || trimmed == "\"use client\""
-    });
-    
-    // Check for getExtracted (server only)
-    let has_get_extracted = Regex::new(r"\bgetExtracted\s*\(")
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    // Check for useExtracted (client only)
-    let has_use_extracted = Regex::new(r"\buseExtracted\s*\(")
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    if has_use_client && has_get_extracted {
+
+    if has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    let hooks = &rule.options.client_hooks;
+    let mut usages: Vec<(usize, String)> = Vec::new();
+
+    for cap in REACT_IMPORT_RE.captures_iter(&content) {
+        let start = cap.get(0).unwrap().start();
+        usages.extend(
+            named_imports(&cap[1])
+                .into_iter()
+                .filter(|name| hooks.contains(name))
+                .map(|hook| (start, hook)),
+        );
+    }
+
+    let alternatives: Vec<String> = hooks.iter().map(|hook| regex::escape(hook)).collect();
+    let call_re = Regex::new(&format!(
+        r"\b({})\s*(?:<[^>]*>\s*)?\(",
+        alternatives.join("|")
+    ))
+    .unwrap();
+    for cap in call_re.captures_iter(&content) {
+        let hook = cap.get(1).unwrap();
+        usages.push((hook.start(), hook.as_str().to_string()));
+    }
+
+    let first = usages.into_iter().min_by_key(|(offset, _)| *offset);
+    if let Some((offset, hook)) = first {
         diagnostics.add(Diagnostic {
-            severity: config.rules.bassist_i18n_hook_usage.severity,
-            rule: "bassist-i18n-hook-usage".to_string(),
-            message: "Client component should use 'useExtracted()' hook, not 'getExtracted()' function".to_string(),
+            severity: rule.severity,
+            rule: "no-client-hooks-in-server".to_string(),
+            message: format!(
+                "'{}' only works in client components; add 'use client' or move it into a client component",
+                hook
+            ),
             file: path.to_path_buf(),
-            line: None,
+            line: Some(utils::line_number_at(&content, offset)),
+            project: None,
+            tokens: Vec::new(),
         });
     }
-    
-    if !has_use_client && has_use_extracted {
-        // Check if this is in app/ directory (likely server component)
-        let path_str = path.to_str().unwrap_or("");
-        if path_str.contains("/app/") {
-            diagnostics.add(Diagnostic {
-                severity: config.rules.bassist_i18n_hook_usage.severity,
-                rule: "bassist-i18n-hook-usage".to_string(),
-                message: "Server component should use 'getExtracted()' function, not 'useExtracted()' React hook".to_string(),
-                file: path.to_path_buf(),
-                line: None,
-            });
-        }
+}
+
+/// Which Next.js router a project file belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Router {
+    App,
+    Pages,
+}
+
+fn router_of(relative_path: &Path) -> Option<Router> {
+    let path_str = relative_path.to_str().unwrap_or("");
+    let path_str = path_str.strip_prefix("src/").unwrap_or(path_str);
+    if path_str.starts_with("app/") {
+        Some(Router::App)
+    } else if path_str.starts_with("pages/") {
+        Some(Router::Pages)
+    } else {
+        None
     }
 }
 
-/// Check test files are colocated, not in root /tests directory
-pub fn check_bassist_test_colocation(
-    path: &Path,
+/// Check that app/ and pages/ only import each other through allowlisted modules
+/// while both routers coexist, in the configured `direction`
+pub fn check_router_migration_boundaries(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    let path_str = path.to_str().unwrap_or("");
-    
-    // Check if this is a test file
-    let is_test_file = path_str.contains(".test.") || path_str.contains(".spec.");
-    
-    if !is_test_file {
+    use crate::config::RouterDirection;
+    use crate::utils;
+
+    let rule = &config.rules.router_migration_boundaries;
+    if !rule.is_enabled() {
         return;
     }
-    
-    // Check if it's in a root /tests directory (not domain-specific)
-    if path_str.starts_with("tests/") || path_str.contains("/tests/") && !path_str.contains("/app/") {
+
+    // Once pages/ is empty the migration is done and there is nothing to guard
+    let has_pages = all_files.iter().any(|file| {
+        let relative = file.strip_prefix(project_root).unwrap_or(file);
+        router_of(relative) == Some(Router::Pages)
+    });
+    if !has_pages {
+        return;
+    }
+
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+
+    let mut violations = Vec::new();
+    for importer in all_files {
+        let importer_relative = importer.strip_prefix(project_root).unwrap_or(importer);
+        let importer_router = match router_of(importer_relative) {
+            Some(router) => router,
+            None => continue,
+        };
+        let (allowlist, option_name, checked) = match importer_router {
+            Router::App => (
+                &rule.options.app_may_import_from_pages,
+                "app_may_import_from_pages",
+                rule.options.direction != RouterDirection::PagesToApp,
+            ),
+            Router::Pages => (
+                &rule.options.pages_may_import_from_app,
+                "pages_may_import_from_app",
+                rule.options.direction != RouterDirection::AppToPages,
+            ),
+        };
+        if !checked {
+            continue;
+        }
+
+        let content = match fs::read_to_string(importer) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        for (specifier, _, offset) in utils::extract_quoted_imports(&content) {
+            let target = match utils::resolve_import(
+                &specifier,
+                importer,
+                project_root,
+                aliases,
+                &config.files.extensions,
+            ) {
+                Some(target) => target,
+                None => continue,
+            };
+            let target_relative = match target.strip_prefix(&root) {
+                Ok(rel) => rel,
+                Err(_) => continue,
+            };
+            if router_of(target_relative).is_none_or(|router| router == importer_router) {
+                continue;
+            }
+            if allowlist
+                .iter()
+                .any(|glob| utils::matches_glob(&target, glob, &root))
+            {
+                continue;
+            }
+
+            violations.push((
+                importer.clone(),
+                utils::line_number_at(&content, offset),
+                format!(
+                    "'{}' imports '{}' across the app/pages router boundary; allow it via '{}'",
+                    importer_relative.display(),
+                    target_relative.display(),
+                    option_name
+                ),
+            ));
+        }
+    }
+
+    violations.sort();
+    for (importer, line, message) in violations {
         diagnostics.add(Diagnostic {
-            severity: config.rules.bassist_test_colocation.severity,
-            rule: "bassist-test-colocation".to_string(),
-            message: "Test files should be colocated with their implementation in domain folders (app/), not in a separate /tests directory".to_string(),
-            file: path.to_path_buf(),
-            line: None,
+            severity: rule.severity,
+            rule: "router-migration-boundaries".to_string(),
+            message,
+            file: importer,
+            line: Some(line),
+            project: None,
+            tokens: Vec::new(),
         });
     }
 }
 
-/// Check test file naming conventions based on test type
-pub fn check_bassist_test_naming(
+/// Check that imports of project files follow the preferred style: the `@/`
+/// alias instead of deep `../` chains, or relative paths for nearby files
+pub fn check_import_style_consistency(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::ImportStyle;
+    use crate::utils;
+
+    let rule = &config.rules.import_style_consistency;
+    if !rule.is_enabled() {
+        return;
+    }
+    let max_levels = rule.options.max_parent_levels;
+
+    for file in all_files {
+        let dir = match file.parent() {
+            Some(dir) => dir,
+            None => continue,
+        };
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        for spec in utils::extract_imports(file) {
+            let message = match rule.options.prefer {
+                ImportStyle::Alias => {
+                    let levels = spec.split('/').take_while(|part| *part == "..").count();
+                    if levels <= max_levels {
+                        continue;
+                    }
+                    // Only suggest an alias the project actually configures
+                    let alias = match aliases.alias_for(&dir.join(&spec)) {
+                        Some(alias) => alias,
+                        None => continue,
+                    };
+                    format!(
+                        "Import '{}' climbs {} parent directories; use '{}' instead",
+                        spec, levels, alias
+                    )
+                }
+                ImportStyle::Relative => {
+                    let rest = match spec.strip_prefix("@/") {
+                        Some(rest) => rest,
+                        None => continue,
+                    };
+                    let relative = utils::relative_import_path(dir, &project_root.join(rest));
+                    let levels = relative.split('/').take_while(|part| *part == "..").count();
+                    if levels > max_levels {
+                        continue;
+                    }
+                    format!(
+                        "Import '{}' points to a nearby file; use '{}' instead",
+                        spec, relative
+                    )
+                }
+            };
+
+            let offset = content
+                .find(&format!("'{}'", spec))
+                .or_else(|| content.find(&format!("\"{}\"", spec)));
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "import-style-consistency".to_string(),
+                message,
+                file: file.clone(),
+                line: offset.map(|offset| utils::line_number_at(&content, offset)),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that source folders don't contain stray package.json files, which
+/// change module resolution for everything below them
+pub fn check_no_nested_package_json(
+    project_root: &Path,
+    package_json_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_nested_package_json;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let patterns: Vec<glob::Pattern> = crate::workspace::package_globs(project_root)
+        .iter()
+        .filter(|glob| !glob.starts_with('!'))
+        .filter_map(|glob| glob::Pattern::new(glob).ok())
+        .collect();
+    let match_options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+
+    // Directories of the packages declared as workspaces
+    let workspace_roots: Vec<&Path> = package_json_files
+        .iter()
+        .filter_map(|file| file.parent())
+        .filter(|dir| {
+            let relative = dir.strip_prefix(project_root).unwrap_or(dir);
+            let relative = relative.to_str().unwrap_or("");
+            !relative.is_empty()
+                && patterns
+                    .iter()
+                    .any(|pattern| pattern.matches_with(relative, match_options))
+        })
+        .collect();
+
+    for file in package_json_files {
+        let dir = match file.parent() {
+            Some(dir) => dir,
+            None => continue,
+        };
+        if dir == project_root || workspace_roots.contains(&dir) {
+            continue;
+        }
+        if !rule
+            .options
+            .source_roots
+            .iter()
+            .any(|glob| utils::matches_glob(file, glob, project_root))
+        {
+            continue;
+        }
+
+        let relative_dir = dir.strip_prefix(project_root).unwrap_or(dir);
+        let nearest_workspace = workspace_roots
+            .iter()
+            .filter(|root| dir.starts_with(root))
+            .max_by_key(|root| root.components().count());
+        let destination = match nearest_workspace {
+            Some(root) => format!(
+                "the workspace package at '{}'",
+                root.strip_prefix(project_root).unwrap_or(root).display()
+            ),
+            None => "the root package.json".to_string(),
+        };
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-nested-package-json".to_string(),
+            message: format!(
+                "Nested package.json in '{}' changes module resolution and tooling for that folder; its settings likely belong in {}",
+                relative_dir.display(),
+                destination
+            ),
+            file: file.clone(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that modules with a top-level 'use server' directive are named `*.actions.ts`
+pub fn check_actions_filename(
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    let path_str = path.to_str().unwrap_or("");
-    
-    // Only check test files
-    if !path_str.contains(".test.") && !path_str.contains(".spec.") {
+    let rule = &config.rules.actions_filename;
+    if !rule.is_enabled() {
         return;
     }
-    
-    // Read file content
+
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return,
     };
-    
-    // Heuristic checks based on imports
-    let has_playwright = Regex::new(r#"from\s+['"]@playwright/test['"]"#)
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    let has_db_test_utils = Regex::new(r"(createTestServiceClient|ensureTestUser)")
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    let has_mastra = Regex::new(r#"from\s+['"]@/src/mastra"#)
-        .map(|re| re.is_match(&content))
-        .unwrap_or(false);
-    
-    // Suggest appropriate extension
-    if has_playwright && !path_str.ends_with(".spec.ts") {
-        diagnostics.add(Diagnostic {
-            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
-                config.rules.bassist_test_naming.severity
-            } else {
-                crate::config::Severity::Warn
-            },
-            rule: "bassist-test-naming".to_string(),
-            message: "E2E tests using Playwright should use '*.spec.ts' extension".to_string(),
-            file: path.to_path_buf(),
-            line: None,
-        });
-    } else if has_db_test_utils && !path_str.contains(".test.db.") {
-        diagnostics.add(Diagnostic {
-            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
-                config.rules.bassist_test_naming.severity
-            } else {
-                crate::config::Severity::Warn
-            },
-            rule: "bassist-test-naming".to_string(),
-            message: "Database tests using service client or test users should use '*.test.db.ts' extension".to_string(),
-            file: path.to_path_buf(),
-            line: None,
-        });
-    } else if has_mastra && !path_str.contains(".test.gen.") {
+
+    if !has_top_level_directive(&content, "use server") {
+        return;
+    }
+
+    let stem = match path.file_stem().and_then(|s| s.to_str()) {
+        Some(stem) => stem,
+        None => return,
+    };
+
+    let suffix = &rule.options.actions_suffix;
+    if !stem.ends_with(suffix.as_str()) {
+        let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or(stem);
+        let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("ts");
         diagnostics.add(Diagnostic {
-            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
-                config.rules.bassist_test_naming.severity
-            } else {
-                crate::config::Severity::Warn
-            },
-            rule: "bassist-test-naming".to_string(),
-            message: "AI generation tests should use '*.test.gen.ts' extension".to_string(),
+            severity: rule.severity,
+            rule: "actions-filename".to_string(),
+            message: format!(
+                "Server action module '{}' should be named '*{}.{}'",
+                file_name, suffix, extension
+            ),
             file: path.to_path_buf(),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
     }
 }
 
-/// Check API routes are in proper api/ directories
-pub fn check_bassist_api_route_structure(
+static FETCH_CALL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bfetch\s*\(").unwrap());
+static FETCH_CACHE_OPTION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b(cache|next)\s*[:,}]").unwrap());
+
+/// Check that `fetch` calls in server components state their caching behaviour
+///
+/// Best effort: a call passes when its options argument is not an object
+/// literal (it can't be inspected) or when the literal mentions `cache`/`next`.
+pub fn check_fetch_cache_option(
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
+    use crate::utils;
+
+    let rule = &config.rules.fetch_cache_option;
+    if !rule.is_enabled() {
+        return;
+    }
+
     let path_str = path.to_str().unwrap_or("");
-    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-    
-    // Check if this is a route.ts file (API route)
-    if file_name != "route.ts" && file_name != "route.js" {
+    if !path_str.contains("/app/") {
         return;
     }
-    
-    // Check if it's in an /api/ directory
-    if !path_str.contains("/api/") {
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    for m in FETCH_CALL_RE.find_iter(&content) {
+        // Skip method calls such as `client.fetch(` and identifiers like `prefetch(`
+        let preceding = content[..m.start()].chars().next_back();
+        if matches!(preceding, Some(c) if c == '.' || c == '$' || c == '_' || c.is_alphanumeric()) {
+            continue;
+        }
+
+        let args = match utils::split_call_arguments(&content, m.end() - 1) {
+            Some(args) => args,
+            None => continue,
+        };
+
+        let has_cache_option = match args.get(1) {
+            Some(options) if options.starts_with('{') => FETCH_CACHE_OPTION_RE.is_match(options),
+            Some(_) => true,
+            None => false,
+        };
+
+        if !has_cache_option {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "fetch-cache-option".to_string(),
+                message: "fetch() in server component should pass an explicit 'cache' or 'next' option".to_string(),
+                file: path.to_path_buf(),
+                line: Some(utils::line_number_at(&content, m.start())),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that pages-router `_app`/`_document` files don't live under app/
+pub fn check_legacy_special_files_in_app(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.legacy_special_files_in_app;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if stem == "_app" || stem == "_document" {
         diagnostics.add(Diagnostic {
-            severity: config.rules.bassist_api_route_structure.severity,
-            rule: "bassist-api-route-structure".to_string(),
-            message: "API route files (route.ts) should be placed in /api/ directories".to_string(),
+            severity: rule.severity,
+            rule: "legacy-special-files-in-app".to_string(),
+            message: format!(
+                "'{}' is a pages-router file and has no effect under app/; use a root layout instead",
+                path.file_name().and_then(|n| n.to_str()).unwrap_or(stem)
+            ),
             file: path.to_path_buf(),
             line: None,
+            project: None,
+            tokens: Vec::new(),
         });
     }
 }
 
-/// Check for cross-domain imports that violate domain boundaries
-pub fn check_bassist_domain_isolation(
+/// Check that files under app/ don't import `next/head`, which has no effect
+/// in the App Router
+pub fn check_no_next_head_in_app(
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
+    use crate::utils;
+
+    let rule = &config.rules.no_next_head_in_app;
+    if !rule.is_enabled() {
+        return;
+    }
+
     let path_str = path.to_str().unwrap_or("");
-    
-    // Extract current file's route group
-    let current_route_group = if let Some(app_pos) = path_str.find("/app/") {
-        let after_app = &path_str[app_pos + 5..];
-        if after_app.starts_with('(') {
-            if let Some(close_paren) = after_app.find(')') {
-                Some(&after_app[1..close_paren])
-            } else {
-                None
-            }
-        } else {
-            None
-        }
-    } else {
-        None
-    };
-    
-    if current_route_group.is_none() {
-        return; // Not in a route group
+    if !path_str.contains("/app/") {
+        return;
     }
-    
-    // Read file content
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return,
-    };
-    
-    let allowed_paths: Vec<&str> = config
-        .rules
-        .bassist_domain_isolation
-        .options
-        .bassist
-        .cross_domain_allowed_paths
-        .iter()
-        .map(|s| s.as_str())
-        .collect();
-    
-    // Check for imports from other route groups
-    let import_pattern = r#"from\s+['"]@/app/\(([^)]+)\)/(lib|components|schemas|[^'"]+)['"]"#;
-    if let Ok(re) = Regex::new(import_pattern) {
-        for cap in re.captures_iter(&content) {
-            if let (Some(imported_group), Some(imported_path)) = (cap.get(1), cap.get(2)) {
-                let imported_group_str = imported_group.as_str();
-                let imported_path_str = imported_path.as_str();
-                
-                // Check if importing from a different route group
-                if Some(imported_group_str) != current_route_group {
-                    // Check if the imported path is allowed
-                    let is_allowed = allowed_paths.iter().any(|allowed| {
-                        imported_path_str == *allowed || imported_path_str.starts_with(&format!("{}/", allowed))
-                    });
-                    
-                    if !is_allowed {
-                        diagnostics.add(Diagnostic {
-                            severity: config.rules.bassist_domain_isolation.severity,
-                            rule: "bassist-domain-isolation".to_string(),
-                            message: format!(
-                                "Cross-domain import from '({})' detected. Domains should not import '{}' from sibling domains. Consider moving shared code to root /lib or /components, or configure allowed paths.",
-                                imported_group_str, imported_path_str
-                            ),
-                            file: path.to_path_buf(),
-                            line: None,
-                        });
-                    }
-                }
-            }
-        }
+
+    if utils::extract_imports(path).iter().any(|spec| spec == "next/head") {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-next-head-in-app".to_string(),
+            message: "'next/head' does not work in the App Router; export `metadata` or `generateMetadata` instead".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
     }
 }
 
-/// Check i18n namespace conventions
-pub fn check_bassist_i18n_namespaces(
+static RESPONSE_CALL_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b(new\s+(?:Next)?Response|(?:Next)?Response\.json)\s*\(").unwrap()
+});
+static STATUS_OPTION_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bstatus\s*[:,}]|\.\.\.").unwrap());
+
+/// Check that responses built in route handlers pass an explicit status
+///
+/// Best-effort: a second argument that isn't an object literal (e.g. a shared
+/// `init` variable or a spread) is assumed to carry the status.
+pub fn check_route_explicit_status(
     path: &Path,
     config: &Config,
     diagnostics: &mut DiagnosticCollection,
 ) {
-    // Read file content
+    use crate::utils;
+
+    let rule = &config.rules.route_explicit_status;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    if path.file_stem().and_then(|s| s.to_str()) != Some("route") {
+        return;
+    }
+
     let content = match fs::read_to_string(path) {
         Ok(c) => c,
         Err(_) => return,
     };
-    
-    // Check for useExtracted or getExtracted calls
+
+    for caps in RESPONSE_CALL_RE.captures_iter(&content) {
+        let call = caps.get(0).unwrap();
+        let args = match utils::split_call_arguments(&content, call.end() - 1) {
+            Some(args) => args,
+            None => continue,
+        };
+
+        let has_status = match args.get(1) {
+            Some(init) if init.starts_with('{') => STATUS_OPTION_RE.is_match(init),
+            Some(_) => true,
+            None => false,
+        };
+
+        if !has_status {
+            let constructor = caps[1].split_whitespace().collect::<Vec<_>>().join(" ");
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "route-explicit-status".to_string(),
+                message: format!(
+                    "{}(...) in route handler should pass an explicit status, e.g. {{ status: 200 }}",
+                    constructor
+                ),
+                file: path.to_path_buf(),
+                line: Some(utils::line_number_at(&content, call.start())),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+static TYPE_ONLY_STATEMENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:import|export)\s+type\s").unwrap());
+
+/// Whether a module reference only imports or re-exports types, which are
+/// erased at build time: `import type`, or braces naming only `type` entries
+fn is_type_only_reference(cap: &regex::Captures) -> bool {
+    let statement = cap.get(0).map_or("", |m| m.as_str());
+    let clause = cap.get(1).map_or("", |m| m.as_str()).trim();
+
+    TYPE_ONLY_STATEMENT_RE.is_match(statement)
+        || clause.starts_with('{')
+            && clause.ends_with('}')
+            && clause[1..clause.len() - 1]
+                .split(',')
+                .map(str::trim)
+                .filter(|entry| !entry.is_empty())
+                .all(|entry| entry.starts_with("type "))
+}
+
+/// Module references of a server component (a file under `app/`, or also
+/// `pages/` with `include_pages`, without `'use client'`) with their line,
+/// skipping type-only imports
+fn server_component_imports(path: &Path, include_pages: bool) -> Vec<(String, usize)> {
+    use crate::utils;
+
+    let path_str = path.to_str().unwrap_or("");
+    let in_scope = path_str.contains("/app/") || include_pages && path_str.contains("/pages/");
+    if !in_scope {
+        return Vec::new();
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    if has_top_level_directive(&content, "use client") {
+        return Vec::new();
+    }
+
+    MODULE_REFERENCE_RE
+        .captures_iter(&content)
+        .filter(|cap| !is_type_only_reference(cap))
+        .map(|cap| {
+            let line = utils::line_number_at(&content, cap.get(0).unwrap().start());
+            (cap[2].to_string(), line)
+        })
+        .collect()
+}
+
+/// Check that server components under app/ don't import client-only packages
+pub fn check_client_only_libs(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.client_only_libs;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for (spec, line) in server_component_imports(path, false) {
+        let package = rule
+            .options
+            .client_only_packages
+            .iter()
+            .find(|package| is_module_or_subpath(&spec, package));
+
+        if let Some(package) = package {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "client-only-libs".to_string(),
+                message: format!(
+                    "Server component imports client-only package '{}'; add 'use client' or move the import into a client component",
+                    package
+                ),
+                file: path.to_path_buf(),
+                line: Some(line),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that server components don't import modules marked client-only,
+/// such as the `client-only` package, which fails the build on the server
+pub fn check_client_only_imports(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.client_only_imports;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for (spec, line) in server_component_imports(path, rule.options.include_pages) {
+        let matched = rule.options.client_only_modules.iter().any(|entry| {
+            if entry.contains('*') {
+                glob::Pattern::new(entry).is_ok_and(|pattern| pattern.matches(&spec))
+            } else {
+                is_module_or_subpath(&spec, entry)
+            }
+        });
+        if !matched {
+            continue;
+        }
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "client-only-imports".to_string(),
+            message: format!(
+                "Server component imports client-only module '{}'; add 'use client' or move the import into a client component",
+                spec
+            ),
+            file: path.to_path_buf(),
+            line: Some(line),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static DYNAMIC_CALL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^\w$.])dynamic\s*\(").unwrap());
+static SSR_FALSE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bssr\s*:\s*false\b").unwrap());
+
+/// Check that `next/dynamic` with `ssr: false` is only used in client
+/// components, since server components can't opt out of server rendering
+pub fn check_dynamic_in_server(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.dynamic_in_server;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    for call in DYNAMIC_CALL_RE.find_iter(&content) {
+        let args = match utils::split_call_arguments(&content, call.end() - 1) {
+            Some(args) => args,
+            None => continue,
+        };
+
+        if args.get(1).is_some_and(|options| SSR_FALSE_RE.is_match(options)) {
+            let offset = call.start() + call.as_str().find("dynamic").unwrap_or(0);
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "dynamic-in-server".to_string(),
+                message: "dynamic() with ssr: false is not allowed in a server component; add 'use client' or move it into a client component".to_string(),
+                file: path.to_path_buf(),
+                line: Some(utils::line_number_at(&content, offset)),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// An expression container in element text (`>{...}`) or an attribute (`x={...}`)
+static HYDRATION_RISK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?:>|\b[A-Za-z][\w-]*=)\s*\{[^{}]*?\b(Date\.now\(\)|new\s+Date\(\s*\)|Math\.random\(\))",
+    )
+    .unwrap()
+});
+static TAG_OPEN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[A-Za-z]").unwrap());
+
+/// Check that time or random values rendered in JSX are marked with
+/// `suppressHydrationWarning`, since they differ between server and client
+pub fn check_hydration_risk(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.hydration_risk;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if extension != "tsx" && extension != "jsx" {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    for caps in HYDRATION_RISK_RE.captures_iter(&content) {
+        let call = caps.get(1).unwrap();
+
+        // The attribute only silences its own element, so look at the nearest opening tag
+        let tag_start = TAG_OPEN_RE
+            .find_iter(&content[..call.start()])
+            .last()
+            .map(|m| m.start())
+            .unwrap_or(0);
+        if content[tag_start..call.start()].contains("suppressHydrationWarning") {
+            continue;
+        }
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "hydration-risk".to_string(),
+            message: format!(
+                "{} rendered in JSX differs between server and client; add suppressHydrationWarning to the element or compute it after mount",
+                call.as_str().split_whitespace().collect::<Vec<_>>().join(" ")
+            ),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, call.start())),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static SERVER_ONLY_IMPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"(?m)^\s*import\s+['"]server-only['"]"#).unwrap());
+/// `process.env.NAME` and `process.env['NAME']`
+static ENV_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"process\.env(?:\.([A-Za-z_$][\w$]*)|\[\s*['"]([^'"]+)['"]\s*\])"#).unwrap()
+});
+
+/// Check that client components only read `NEXT_PUBLIC_` environment
+/// variables, the only ones Next.js inlines into the browser bundle
+///
+/// With `public_in_server_severity`, `NEXT_PUBLIC_` variables read in
+/// server-only files are reported too, since whatever they hold is shipped to
+/// the browser as well.
+pub fn check_client_env_prefix(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::Severity;
+    use crate::utils;
+
+    let rule = &config.rules.client_env_prefix;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let is_client = has_top_level_directive(&content, "use client");
+    let is_server_only = !is_client
+        && rule.options.public_in_server_severity != Severity::Off
+        && (has_top_level_directive(&content, "use server")
+            || SERVER_ONLY_IMPORT_RE.is_match(&content));
+    if !is_client && !is_server_only {
+        return;
+    }
+
+    for caps in ENV_RE.captures_iter(&content) {
+        let name = match caps.get(1).or_else(|| caps.get(2)) {
+            Some(name) => name.as_str(),
+            None => continue,
+        };
+        if rule.options.allowed_env_vars.iter().any(|v| v == name) {
+            continue;
+        }
+
+        let is_public = name.starts_with("NEXT_PUBLIC_");
+        let (severity, message) = if is_client && !is_public {
+            (
+                rule.severity,
+                format!(
+                    "process.env.{} is undefined in client components; only NEXT_PUBLIC_ variables are exposed to the browser",
+                    name
+                ),
+            )
+        } else if is_server_only && is_public {
+            (
+                rule.options.public_in_server_severity,
+                format!(
+                    "process.env.{} is read in a server-only file but NEXT_PUBLIC_ variables are also exposed to the browser",
+                    name
+                ),
+            )
+        } else {
+            continue;
+        };
+
+        diagnostics.add(Diagnostic {
+            severity,
+            rule: "client-env-prefix".to_string(),
+            message,
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, caps.get(0).unwrap().start())),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that app router pages don't sit deeper in the URL than allowed;
+/// route groups, parallel route slots and private folders add no segments
+pub fn check_max_url_depth(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.max_url_depth;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    if path.file_stem().and_then(|s| s.to_str()) != Some("page") {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    let route_dir = match path_str.find("/app/") {
+        Some(pos) => path_str[pos + 5..].rsplit_once('/').map_or("", |(dir, _)| dir),
+        None => return,
+    };
+    let public_segments: Vec<&str> = route_dir
+        .split('/')
+        .filter(|s| !s.starts_with('_'))
+        .collect();
+    let depth = count_depth(&public_segments.join("/"));
+
+    let max_depth = rule.options.max_url_depth;
+    if depth > max_depth {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "max-url-depth".to_string(),
+            message: format!(
+                "Route is {} URL segments deep, more than the maximum of {}",
+                depth, max_depth
+            ),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that a file writes all its import specifiers with the same quote
+/// character, and with the preferred one when configured
+pub fn check_import_quote_consistency(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.import_quote_consistency;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let imports = utils::extract_quoted_imports(&content);
+    let expected = match (rule.options.prefer, imports.first()) {
+        (Some(prefer), _) => prefer.quote(),
+        (None, Some((_, quote, _))) => *quote,
+        (None, None) => return,
+    };
+
+    let offending = imports.iter().find(|(_, quote, _)| *quote != expected);
+    if let Some((specifier, quote, offset)) = offending {
+        let message = if rule.options.prefer.is_some() {
+            format!(
+                "Import of '{}' uses {} quotes; imports must use {} quotes",
+                specifier,
+                quote_name(*quote),
+                quote_name(expected)
+            )
+        } else {
+            format!(
+                "Import of '{}' uses {} quotes while earlier imports in this file use {} quotes",
+                specifier,
+                quote_name(*quote),
+                quote_name(expected)
+            )
+        };
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "import-quote-consistency".to_string(),
+            message,
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, *offset)),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+fn quote_name(quote: char) -> &'static str {
+    if quote == '"' {
+        "double"
+    } else {
+        "single"
+    }
+}
+
+/// Exports Next.js reads from a page module besides the default export:
+/// metadata, static params and route segment config
+const PAGE_ROUTE_EXPORTS: [&str; 13] = [
+    "metadata",
+    "generateMetadata",
+    "viewport",
+    "generateViewport",
+    "generateStaticParams",
+    "dynamic",
+    "dynamicParams",
+    "revalidate",
+    "fetchCache",
+    "runtime",
+    "preferredRegion",
+    "maxDuration",
+    "experimental_ppr",
+];
+
+static INLINE_VALUE_EXPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?m)^[ \t]*export\s+(?:declare\s+)?(?:async\s+)?(?:const|let|var|function\*?|class|enum|abstract\s+class)\s+([A-Za-z_$][\w$]*)",
+    )
+    .unwrap()
+});
+static EXPORT_BLOCK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*export\s*\{([^}]*)\}").unwrap());
+
+/// Names of a module's value exports with the offset of their export
+/// statement, in source order; `export { a as b }` yields `b` and type-only
+/// exports are skipped
+fn value_exports(content: &str) -> Vec<(usize, String)> {
+    let mut exports: Vec<(usize, String)> = INLINE_VALUE_EXPORT_RE
+        .captures_iter(content)
+        .map(|cap| (cap.get(0).unwrap().start(), cap[1].to_string()))
+        .collect();
+    for cap in EXPORT_BLOCK_RE.captures_iter(content) {
+        let offset = cap.get(0).unwrap().start();
+        for item in cap[1].split(',').map(str::trim) {
+            if item.is_empty() || item.starts_with("type ") {
+                continue;
+            }
+            let name = item.rsplit(" as ").next().unwrap_or(item).trim();
+            exports.push((offset, name.to_string()));
+        }
+    }
+    exports.sort();
+    exports
+}
+
+/// Check that pages and layouts export `metadata` or `generateMetadata`
+///
+/// Client components can't export metadata at all, so they get their own
+/// diagnostic asking to move the directive into a child component.
+pub fn check_require_metadata(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.require_metadata;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if !path_str.contains("/app/") || !rule.options.apply_to.iter().any(|name| name == stem) {
+        return;
+    }
+    if stem == "layout" && rule.options.root_layout_only && !is_root_layout(path) {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let message = if has_top_level_directive(&content, "use client") {
+        format!(
+            "'{}' is a client component and can't export metadata or generateMetadata; move 'use client' into a child component",
+            stem
+        )
+    } else if value_exports(&content)
+        .iter()
+        .any(|(_, name)| name == "metadata" || name == "generateMetadata")
+    {
+        return;
+    } else {
+        format!("'{}' exports neither metadata nor generateMetadata", stem)
+    };
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "require-metadata".to_string(),
+        message,
+        file: path.to_path_buf(),
+        line: None,
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+/// Check that files under `app/` don't export both `metadata` and
+/// `generateMetadata`, which fails the Next.js build
+pub fn check_conflicting_metadata_exports(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.conflicting_metadata_exports;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let exports = value_exports(&content);
+    let find = |name: &str| exports.iter().find(|(_, export)| export == name).map(|(offset, _)| *offset);
+    let (metadata, generate) = match (find("metadata"), find("generateMetadata")) {
+        (Some(metadata), Some(generate)) => (
+            utils::line_number_at(&content, metadata),
+            utils::line_number_at(&content, generate),
+        ),
+        _ => return,
+    };
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "conflicting-metadata-exports".to_string(),
+        message: format!(
+            "Exports both metadata (line {}) and generateMetadata (line {}); Next.js allows only one of them per segment",
+            metadata, generate
+        ),
+        file: path.to_path_buf(),
+        line: Some(metadata.max(generate)),
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+/// Check that client components under `app/` don't export `metadata` or
+/// `generateMetadata`, which Next.js silently ignores there
+pub fn check_no_client_metadata(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_client_metadata;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if !has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    for (offset, name) in value_exports(&content) {
+        if name != "metadata" && name != "generateMetadata" {
+            continue;
+        }
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-client-metadata".to_string(),
+            message: format!(
+                "'{}' is ignored in a 'use client' file; metadata exports only work in server components",
+                name
+            ),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, offset)),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static CONSOLE_CALL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\bconsole\s*\.\s*([A-Za-z_$][\w$]*)\s*\(").unwrap());
+
+/// Check that production code doesn't call the disallowed `console` methods;
+/// test and story files and `exclude_glob` matches are skipped
+pub fn check_no_console(
+    project_root: &Path,
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_console;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if [".test.", ".spec.", ".stories."]
+        .iter()
+        .any(|suffix| file_name.contains(suffix))
+    {
+        return;
+    }
+    if utils::is_excluded(path, &rule.options.exclude_glob, project_root) {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    for caps in CONSOLE_CALL_RE.captures_iter(&content) {
+        let method = &caps[1];
+        if !rule.options.disallowed_methods.iter().any(|m| m == method) {
+            continue;
+        }
+        let offset = caps.get(0).map_or(0, |m| m.start());
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-console".to_string(),
+            message: format!("console.{}() call left in production code", method),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, offset)),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// HTTP methods Next.js route handlers export
+const HTTP_METHOD_EXPORTS: [&str; 7] = ["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+
+/// Check that route handlers live where Next.js serves them: `route` files
+/// under `app/`, and HTTP method exports only in `route` files or under
+/// `allowed_roots`
+pub fn check_api_location(
+    project_root: &Path,
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.api_location;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let relative = path.strip_prefix(project_root).unwrap_or(path);
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if [".test.", ".spec.", ".stories."]
+        .iter()
+        .any(|suffix| file_name.contains(suffix))
+    {
+        return;
+    }
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("ts");
+    let app_dir = if project_root.join("src/app").is_dir() { "src/app" } else { "app" };
+    let is_route_file = stem == "route";
+
+    let (line, message) = if is_route_file {
+        if router_of(relative) == Some(Router::App) {
+            return;
+        }
+        let name = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .and_then(|n| n.to_str())
+            .unwrap_or("handler");
+        (
+            None,
+            format!(
+                "Route handler '{}' is outside {}/ and is never served; move it to {}/api/{}/route.{}",
+                relative.display(),
+                app_dir,
+                app_dir,
+                name,
+                extension
+            ),
+        )
+    } else {
+        if rule
+            .options
+            .allowed_roots
+            .iter()
+            .any(|glob| utils::matches_glob(path, glob, project_root))
+        {
+            return;
+        }
+        let content = match fs::read_to_string(path) {
+            Ok(c) => c,
+            Err(_) => return,
+        };
+        let handlers: Vec<(usize, String)> = value_exports(&content)
+            .into_iter()
+            .filter(|(_, name)| HTTP_METHOD_EXPORTS.contains(&name.as_str()))
+            .collect();
+        let offset = match handlers.first() {
+            Some((offset, _)) => *offset,
+            None => return,
+        };
+        let names: Vec<&str> = handlers.iter().map(|(_, name)| name.as_str()).collect();
+        (
+            Some(utils::line_number_at(&content, offset)),
+            format!(
+                "'{}' exports route handlers ({}) outside {}; move them to {}/api/{}/route.{}",
+                relative.display(),
+                names.join(", "),
+                rule.options.allowed_roots.join(", "),
+                app_dir,
+                stem,
+                extension
+            ),
+        )
+    };
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "api-location".to_string(),
+        message,
+        file: path.to_path_buf(),
+        line,
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+/// Check that `page` files export little besides the page component and
+/// the metadata and segment config Next.js reads from them
+///
+/// Type-only exports are ignored since they don't end up in the route module.
+pub fn check_page_file_focus(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.page_file_focus;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    if path.file_stem().and_then(|s| s.to_str()) != Some("page") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let exports = value_exports(&content);
+
+    let extra: Vec<&(usize, String)> = exports
+        .iter()
+        .filter(|(_, name)| name != "default" && !PAGE_ROUTE_EXPORTS.contains(&name.as_str()))
+        .collect();
+    if extra.len() <= rule.options.max_extra_exports {
+        return;
+    }
+
+    let names: Vec<&str> = extra.iter().map(|(_, name)| name.as_str()).collect();
+    let (offset, _) = extra[rule.options.max_extra_exports];
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "page-file-focus".to_string(),
+        message: format!(
+            "Page exports {} symbol(s) besides the page component and route config ({}), more than the {} allowed; move them to their own module",
+            extra.len(),
+            names.join(", "),
+            rule.options.max_extra_exports
+        ),
+        file: path.to_path_buf(),
+        line: Some(utils::line_number_at(&content, *offset)),
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+static THEN_CALL_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\.then\s*\(").unwrap());
+
+/// Check that server components consume promises in the configured style,
+/// `await` or `.then()`
+///
+/// Best-effort: every `.then(` or `await` in the file is reported, including
+/// those in strings and comments.
+pub fn check_promise_style(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::PromiseStyle;
+    use crate::utils;
+
+    let rule = &config.rules.promise_style;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    if !path_str.contains("/app/") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    let (style_re, message) = match rule.options.promise_style {
+        PromiseStyle::AsyncAwait => (
+            &*THEN_CALL_RE,
+            "Use await instead of .then() in server components",
+        ),
+        PromiseStyle::Then => (
+            &*AWAIT_RE,
+            "Use .then() instead of await in server components",
+        ),
+    };
+
+    for found in style_re.find_iter(&content) {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "promise-style".to_string(),
+            message: message.to_string(),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, found.start())),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static AWAIT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bawait\b").unwrap());
+
+/// Check that client components don't use `await` at the top level of the
+/// module, which only server components support
+///
+/// Best-effort: an `await` counts as top-level when it isn't inside braces
+/// and its line has no arrow function or `function` before it. Braces in
+/// strings and comments are counted too.
+pub fn check_no_top_level_await_client(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_top_level_await_client;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if !has_top_level_directive(&content, "use client") {
+        return;
+    }
+
+    let mut depth: i64 = 0;
+    let mut scanned = 0;
+
+    for found in AWAIT_RE.find_iter(&content) {
+        for c in content[scanned..found.start()].chars() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+        scanned = found.start();
+        if depth > 0 {
+            continue;
+        }
+
+        let line_start = content[..found.start()].rfind('\n').map_or(0, |i| i + 1);
+        let before = content[line_start..found.start()].trim_start();
+        if before.starts_with("//") || before.starts_with('*') {
+            continue;
+        }
+        if before.contains("=>") || before.contains("function") {
+            continue;
+        }
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-top-level-await-client".to_string(),
+            message: "Top-level await is not supported in client components; move it into an effect, an event handler or a server component".to_string(),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, found.start())),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that ES modules don't carry a `'use strict'` directive, since
+/// modules are always strict
+pub fn check_no_redundant_use_strict(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.no_redundant_use_strict;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+    if !matches!(extension, "ts" | "tsx" | "mts" | "mjs") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    for (directive, line) in top_level_directives(&content) {
+        if directive != "use strict" {
+            continue;
+        }
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-redundant-use-strict".to_string(),
+            message: "'use strict' is redundant in ES modules, which are always strict".to_string(),
+            file: path.to_path_buf(),
+            line: Some(line),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that a module doesn't declare both `'use client'` and
+/// `'use server'`; function-level `'use server'` is not a module directive
+pub fn check_conflicting_directives(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.conflicting_directives;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let directives = top_level_directives(&content);
+    let find = |name: &str| directives.iter().find(|(directive, _)| directive == name);
+    let (client, server) = match (find("use client"), find("use server")) {
+        (Some(client), Some(server)) => (client, server),
+        _ => return,
+    };
+    let (first, second) = if client.1 <= server.1 {
+        (client, server)
+    } else {
+        (server, client)
+    };
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "conflicting-directives".to_string(),
+        message: format!(
+            "'{}' conflicts with '{}' on line {}; a module is either a client component or a server actions file",
+            second.0, first.0, first.1
+        ),
+        file: path.to_path_buf(),
+        line: Some(second.1),
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+/// Focused/skipped test calls; the leading character keeps method calls such
+/// as `map.fit(` or `foo.xit(` from matching
+static FOCUSED_TEST_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?m)(?:^|[^.\w$])((?:describe|context|suite|it|test)\.(?:only|skip)|fdescribe|fit|xdescribe|xit|xtest)\s*\(",
+    )
+    .unwrap()
+});
+
+/// Check that test files don't contain focused or skipped tests
+pub fn check_no_focused_tests(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_focused_tests;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    if !file_name.contains(".test.") && !file_name.contains(".spec.") {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    for cap in FOCUSED_TEST_RE.captures_iter(&content) {
+        let m = cap.get(1).unwrap();
+        let call = m.as_str();
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-focused-tests".to_string(),
+            message: format!(
+                "'{}' must not be committed; it changes which tests run",
+                call
+            ),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, m.start())),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that a component has at most one style file next to it
+/// (e.g. not both `Button.module.css` and `Button.module.scss`)
+pub fn check_single_style_file(
+    _project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.single_style_file;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let style_extensions = ["css", "scss", "sass", "less", "styl"];
+
+    for file in all_files {
+        if !matches!(
+            file.extension().and_then(|e| e.to_str()),
+            Some("tsx") | Some("jsx")
+        ) {
+            continue;
+        }
+
+        let (parent, stem) = match (file.parent(), file.file_stem().and_then(|s| s.to_str())) {
+            (Some(parent), Some(stem)) => (parent, stem),
+            _ => continue,
+        };
+
+        let glob = format!("{}.*", glob::Pattern::escape(stem));
+        let mut style_files: Vec<String> = utils::find_sibling_by_glob(parent, &glob)
+            .iter()
+            .filter(|sibling| {
+                sibling
+                    .extension()
+                    .and_then(|e| e.to_str())
+                    .is_some_and(|ext| style_extensions.contains(&ext))
+            })
+            .filter_map(|sibling| sibling.file_name().and_then(|n| n.to_str()).map(String::from))
+            .collect();
+
+        if style_files.len() > 1 {
+            style_files.sort();
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "single-style-file".to_string(),
+                message: format!(
+                    "Component '{}' has {} style files ({}); keep a single one",
+                    stem,
+                    style_files.len(),
+                    style_files.join(", ")
+                ),
+                file: file.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Locations GitHub reads a CODEOWNERS file from, relative to the repository root
+const CODEOWNERS_LOCATIONS: [&str; 3] = [".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Whether the last CODEOWNERS rule matching `dir` (or one of its ancestors)
+/// assigns owners
+///
+/// Patterns follow gitignore semantics: a leading or inner `/` anchors the
+/// pattern to the root, otherwise it matches at any depth.
+fn codeowners_covers(codeowners: &str, dir: &Path) -> bool {
+    let match_options = glob::MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    let dirs: Vec<String> = dir
+        .ancestors()
+        .filter_map(|ancestor| ancestor.to_str())
+        .filter(|ancestor| !ancestor.is_empty())
+        .map(String::from)
+        .collect();
+
+    let mut owned = false;
+    for line in codeowners.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut parts = line.split_whitespace();
+        let pattern = match parts.next() {
+            Some(pattern) => pattern,
+            None => continue,
+        };
+        let has_owners = parts.next().is_some_and(|owner| !owner.starts_with('#'));
+
+        let trimmed = pattern.trim_end_matches('/');
+        let glob = match trimmed.strip_prefix('/') {
+            Some(anchored) => anchored.to_string(),
+            None if trimmed.contains('/') => trimmed.to_string(),
+            None => format!("**/{}", trimmed),
+        };
+        let pattern = match glob::Pattern::new(&glob) {
+            Ok(pattern) => pattern,
+            Err(_) => continue,
+        };
+
+        if dirs.iter().any(|dir| pattern.matches_with(dir, match_options)) {
+            owned = has_owners;
+        }
+    }
+
+    owned
+}
+
+/// Check that each first-level directory under app/ has an owner, either
+/// through the repository's CODEOWNERS file or an `OWNERS` file of its own
+pub fn check_feature_ownership(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.feature_ownership;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let codeowners = CODEOWNERS_LOCATIONS
+        .iter()
+        .find_map(|location| fs::read_to_string(project_root.join(location)).ok());
+
+    let mut features: Vec<std::path::PathBuf> = all_files
+        .iter()
+        .filter_map(|file| {
+            let relative = file.strip_prefix(project_root).ok()?;
+            let mut components = relative.components();
+            let mut feature = std::path::PathBuf::new();
+            let mut next = components.next()?.as_os_str();
+            if next == "src" {
+                feature.push(next);
+                next = components.next()?.as_os_str();
+            }
+            if next != "app" {
+                return None;
+            }
+            feature.push(next);
+            feature.push(components.next()?.as_os_str());
+            // Files directly under app/ don't belong to a feature
+            components.next()?;
+            Some(feature)
+        })
+        .collect();
+    features.sort();
+    features.dedup();
+
+    for feature in features {
+        if project_root.join(&feature).join("OWNERS").is_file() {
+            continue;
+        }
+        if codeowners
+            .as_deref()
+            .is_some_and(|codeowners| codeowners_covers(codeowners, &feature))
+        {
+            continue;
+        }
+
+        let feature_str = feature.to_str().unwrap_or("");
+        let message = match codeowners {
+            Some(_) => format!(
+                "Feature '{}' has no owner in CODEOWNERS; add a rule such as '/{}/ @team'",
+                feature_str, feature_str
+            ),
+            None => format!(
+                "Feature '{}' has no owner: no CODEOWNERS file found and no OWNERS file in the feature",
+                feature_str
+            ),
+        };
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "feature-ownership".to_string(),
+            message,
+            file: project_root.join(&feature),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// What a module's default export refers to
+#[derive(Debug, PartialEq)]
+enum DefaultExport {
+    Named(String),
+    Anonymous,
+}
+
+static DEFAULT_REEXPORT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"export\s*\{[^}]*?\b([A-Za-z_$][\w$]*)\s+as\s+default\b").unwrap()
+});
+static DEFAULT_DECLARATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:function\b\s*\*?|class\b)\s*([A-Za-z_$][\w$]*)?").unwrap()
+});
+static LEADING_IDENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Za-z_$][\w$]*").unwrap());
+static LEADING_ASYNC_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^async\b\s*").unwrap());
+
+/// Find the default export of a module and its byte offset
+///
+/// Best-effort: default exports of other expressions (e.g. `memo(Button)`)
+/// are not reported.
+fn find_default_export(content: &str) -> Option<(DefaultExport, usize)> {
+    if let Some(m) = EXPORT_DEFAULT_RE.find(content) {
+        let rest = content[m.end()..].trim_start();
+        let rest = LEADING_ASYNC_RE.find(rest).map_or(rest, |prefix| &rest[prefix.end()..]);
+
+        if let Some(cap) = DEFAULT_DECLARATION_RE.captures(rest) {
+            // `class extends Base {}` and `function () {}` have no name
+            let export = match cap.get(1).map(|name| name.as_str()) {
+                Some(name) if name != "extends" => DefaultExport::Named(name.to_string()),
+                _ => DefaultExport::Anonymous,
+            };
+            return Some((export, m.start()));
+        }
+
+        if rest.starts_with('(') {
+            return Some((DefaultExport::Anonymous, m.start()));
+        }
+
+        let name = LEADING_IDENT_RE.find(rest)?.as_str();
+        let after = rest[name.len()..].trim_start_matches([' ', '\t']);
+        if after.starts_with("=>") {
+            return Some((DefaultExport::Anonymous, m.start()));
+        }
+        if after.is_empty() || after.starts_with([';', '\n', '\r']) {
+            return Some((DefaultExport::Named(name.to_string()), m.start()));
+        }
+        return None;
+    }
+
+    DEFAULT_REEXPORT_RE
+        .captures(content)
+        .map(|cap| (DefaultExport::Named(cap[1].to_string()), cap.get(0).unwrap().start()))
+}
+
+static EXPORT_DEFAULT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*export\s+default\b").unwrap());
+static EXPORT_LIST_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"export\s*\{([^}]*)\}").unwrap());
+
+/// Whether a module has a default export of any kind, including
+/// `export { X as default }` and re-exports like `export { default } from`
+fn has_default_export(content: &str) -> bool {
+    EXPORT_DEFAULT_RE.is_match(content)
+        || EXPORT_LIST_RE.captures_iter(content).any(|cap| {
+            // The exported name is the last word of each entry, so
+            // `default as Page` doesn't count
+            cap[1].split(',').any(|entry| {
+                entry.split_whitespace().last() == Some("default")
+            })
+        })
+}
+
+/// Check that app router pages, and the other special files listed in
+/// `special_files`, have a default export, the component Next.js renders
+pub fn check_page_default_export(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let rule = &config.rules.page_default_export;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if !path_str.contains("/app/") || !rule.options.special_files.iter().any(|name| name == stem) {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    if has_default_export(&content) {
+        return;
+    }
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "page-default-export".to_string(),
+        message: format!(
+            "'{}' file has no default export; Next.js renders the default export of {} files",
+            stem, stem
+        ),
+        file: path.to_path_buf(),
+        line: None,
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+static DEFAULT_FUNCTION_PARAMS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"export\s+default\s+(?:async\s+)?function\s*\w*\s*(?:<[^>]*>\s*)?\(").unwrap()
+});
+static EXPORT_DEFAULT_NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)^[ \t]*export\s+default\s+(\w+)\s*;?\s*$").unwrap());
+
+/// Offset of the opening parenthesis of the default-exported component's
+/// parameter list, for function declarations and `const` components
+fn default_component_params(content: &str) -> Option<usize> {
+    if let Some(m) = DEFAULT_FUNCTION_PARAMS_RE.find(content) {
+        return Some(m.end() - 1);
+    }
+
+    let name = EXPORT_DEFAULT_NAME_RE.captures(content)?.get(1)?.as_str();
+    let definition_re = Regex::new(&format!(
+        r"(?:function\s+{name}\s*(?:<[^>]*>\s*)?\(|(?:const|let)\s+{name}\b[^=]*=\s*(?:async\s*)?\()",
+        name = regex::escape(name)
+    ))
+    .unwrap();
+    definition_re.find(content).map(|m| m.end() - 1)
+}
+
+/// Whether `path` is the root layout, `app/layout` (or `src/app/layout`)
+fn is_root_layout(path: &Path) -> bool {
+    path.file_stem().and_then(|s| s.to_str()) == Some("layout")
+        && path.parent().and_then(|dir| dir.file_name()) == Some("app".as_ref())
+}
+
+static CHILDREN_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bchildren\b").unwrap());
+
+/// Check that app router layouts accept `children` and render them, since a
+/// layout that doesn't swallows every nested route
+///
+/// Regex-level: `children` must appear in the parameter list (or the layout
+/// takes a props object) and again after it. With `require_html_body` the
+/// root layout must also render `<html>` and `<body>`.
+pub fn check_layout_renders_children(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.layout_renders_children;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let path_str = path.to_str().unwrap_or("");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if !path_str.contains("/app/") || stem != "layout" {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let mut report = |message: &str, line: Option<usize>| {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "layout-renders-children".to_string(),
+            message: message.to_string(),
+            file: path.to_path_buf(),
+            line,
+            project: None,
+            tokens: Vec::new(),
+        });
+    };
+
+    if let Some(open_paren) = default_component_params(&content) {
+        let line = Some(utils::line_number_at(&content, open_paren));
+        let params = utils::split_call_arguments(&content, open_paren)
+            .unwrap_or_default()
+            .join(", ");
+        let in_params = CHILDREN_RE.find_iter(&params).count();
+        let after_params = CHILDREN_RE.find_iter(&content[open_paren..]).count() - in_params;
+
+        // A props object (`props`, `props: LayoutProps`) or a rest element
+        // (`{ params, ...rest }`) may still carry children
+        let takes_props = !params.is_empty() && !params.starts_with('{');
+        let props_name = if takes_props {
+            params.split([':', '=', ',']).next().map(str::trim)
+        } else {
+            params
+                .split_once("...")
+                .and_then(|(_, rest)| rest.split([',', '}', ':']).next())
+                .map(str::trim)
+        };
+        // `<Shell {...props} />` passes children along
+        let spreads_props = props_name.filter(|name| !name.is_empty()).is_some_and(|name| {
+            Regex::new(&format!(r"\{{\s*\.\.\.\s*{}\s*\}}", regex::escape(name)))
+                .unwrap()
+                .is_match(&content[open_paren..])
+        });
+
+        if in_params == 0 && !takes_props && !spreads_props {
+            report(
+                "Layout does not accept `children`; nested routes will not render",
+                line,
+            );
+        } else if after_params == 0 && !spreads_props {
+            report(
+                "Layout does not render `children`; nested routes will not render",
+                line,
+            );
+        }
+    }
+
+    if rule.options.require_html_body
+        && is_root_layout(path)
+        && !(content.contains("<html") && content.contains("<body"))
+    {
+        report("Root layout must render <html> and <body>", None);
+    }
+}
+
+/// Check that PascalCase component files default-export a component of the
+/// same name
+pub fn check_default_export_name_match(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.default_export_name_match;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+    if !is_pascal_case(stem) {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let (export, offset) = match find_default_export(&content) {
+        Some(found) => found,
+        None => return,
+    };
+
+    let message = match export {
+        DefaultExport::Named(name) if name == stem => return,
+        DefaultExport::Named(name) => format!(
+            "Default export '{}' does not match the filename '{}'",
+            name, stem
+        ),
+        DefaultExport::Anonymous => rule.options.anonymous_export_message.replace("{name}", stem),
+    };
+
+    diagnostics.add(Diagnostic {
+        severity: rule.severity,
+        rule: "default-export-name-match".to_string(),
+        message,
+        file: path.to_path_buf(),
+        line: Some(utils::line_number_at(&content, offset)),
+        project: None,
+        tokens: Vec::new(),
+    });
+}
+
+/// Check that the default-exported components of `page` files have unique
+/// names, so stack traces and React DevTools tell pages apart
+pub fn check_unique_page_names(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+    use std::collections::BTreeMap;
+
+    let rule = &config.rules.unique_page_names;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let mut by_name: BTreeMap<String, Vec<(&std::path::PathBuf, usize)>> = BTreeMap::new();
+    for file in all_files {
+        if file.file_stem().and_then(|s| s.to_str()) != Some("page") {
+            continue;
+        }
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        if let Some((DefaultExport::Named(name), offset)) = find_default_export(&content) {
+            let line = utils::line_number_at(&content, offset);
+            by_name.entry(name).or_default().push((file, line));
+        }
+    }
+
+    for (name, pages) in by_name {
+        if pages.len() < 2 {
+            continue;
+        }
+
+        for (file, line) in &pages {
+            let others: Vec<String> = pages
+                .iter()
+                .filter(|(other, _)| other != file)
+                .map(|(other, _)| other.strip_prefix(project_root).unwrap_or(other).display().to_string())
+                .collect();
+
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "unique-page-names".to_string(),
+                message: format!(
+                    "Page component '{}' has the same name as the pages in {}",
+                    name,
+                    others.join(", ")
+                ),
+                file: (*file).clone(),
+                line: Some(*line),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that no two pages resolve to the same URL, e.g. the same path in
+/// two route groups, which fails the Next.js build, or in both app/ and
+/// pages/ during a migration
+///
+/// Route groups add no URL segment and `index` files in pages/ map to their
+/// directory; dynamic segments collide whatever their parameter name.
+/// Parallel route slots are kept in the key since pages in different slots
+/// render side by side. Private folders and `_app`-style files are skipped.
+pub fn check_no_duplicate_routes(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::BTreeMap;
+
+    let rule = &config.rules.no_duplicate_routes;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    // Normalized route -> pages with the route as written
+    let mut by_route: BTreeMap<String, Vec<(&std::path::PathBuf, String)>> = BTreeMap::new();
+    for file in all_files {
+        let relative = match file.strip_prefix(project_root) {
+            Ok(rel) => rel,
+            Err(_) => continue,
+        };
+        let relative_str = relative.to_str().unwrap_or("");
+        let relative_str = relative_str.strip_prefix("src/").unwrap_or(relative_str);
+        let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+
+        let mut segments: Vec<&str> = match router_of(relative) {
+            Some(Router::App) if stem == "page" => relative_str
+                .trim_start_matches("app/")
+                .rsplit_once('/')
+                .map_or("", |(dir, _)| dir)
+                .split('/')
+                .filter(|s| !(s.is_empty() || s.starts_with('(') && s.ends_with(')')))
+                .collect(),
+            Some(Router::Pages) => {
+                let mut segments: Vec<&str> = relative_str
+                    .trim_start_matches("pages/")
+                    .split('/')
+                    .collect();
+                segments.pop();
+                if stem != "index" {
+                    segments.push(stem);
+                }
+                segments
+            }
+            _ => continue,
+        };
+        if segments.iter().any(|s| s.starts_with('_')) {
+            continue;
+        }
+        segments.retain(|s| !s.is_empty());
+
+        let normalized: Vec<String> = segments
+            .iter()
+            .map(|segment| match parse_dynamic_segment(segment) {
+                Ok((kind, _)) if segment.starts_with('[') => kind.format(""),
+                _ => segment.to_string(),
+            })
+            .collect();
+        by_route
+            .entry(format!("/{}", normalized.join("/")))
+            .or_default()
+            .push((file, format!("/{}", segments.join("/"))));
+    }
+
+    for mut pages in by_route.into_values() {
+        if pages.len() < 2 {
+            continue;
+        }
+        pages.sort();
+
+        let (first, route) = &pages[0];
+        let first = first.strip_prefix(project_root).unwrap_or(first);
+        for (page, _) in &pages[1..] {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "no-duplicate-routes".to_string(),
+                message: format!(
+                    "Pages '{}' and '{}' both resolve to the route '{}'",
+                    first.display(),
+                    page.strip_prefix(project_root).unwrap_or(page).display(),
+                    route
+                ),
+                file: (*page).clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check the names of app router route groups `(name)`: they must follow the
+/// configured style, not be empty, and not repeat a sibling's or an
+/// enclosing group's name, which makes the route tree ambiguous
+pub fn check_route_group_naming(
+    project_root: &Path,
+    directories: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::BTreeMap;
+
+    let rule = &config.rules.route_group_naming;
+    if !rule.is_enabled() {
+        return;
+    }
+    let style = &rule.options.group_style;
+
+    let group_name = |dir: &Path| -> Option<String> {
+        let name = dir.file_name()?.to_str()?;
+        let inner = name.strip_prefix('(')?.strip_suffix(')')?;
+        // Intercepting routes like `(..)photo` or `(....)` aren't groups
+        let is_marker = !inner.is_empty() && inner.chars().all(|c| c == '.');
+        (!inner.contains('(') && !inner.contains(')') && !is_marker).then(|| inner.to_string())
+    };
+    // Names that only differ in case or separators are ambiguous
+    let normalize = |name: &str| convert_to_style(name, &FilenameStyle::KebabCase);
+
+    let mut groups: Vec<&std::path::PathBuf> = directories
+        .iter()
+        .filter(|dir| {
+            dir.strip_prefix(project_root)
+                .is_ok_and(|relative| router_of(relative) == Some(Router::App))
+        })
+        .filter(|dir| group_name(dir).is_some())
+        .collect();
+    groups.sort();
+
+    let mut siblings: BTreeMap<(&Path, String), Vec<&std::path::PathBuf>> = BTreeMap::new();
+    for dir in &groups {
+        let name = group_name(dir).unwrap_or_default();
+        let mut report = |message: String| {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "route-group-naming".to_string(),
+                message,
+                file: (*dir).clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        };
+
+        if name.trim().is_empty() {
+            report(format!("Route group '({})' is empty; give it a name", name));
+            continue;
+        }
+
+        if let FilenameStyle::Custom(pattern) = style {
+            if !pattern.is_match(&name) {
+                report(format!(
+                    "Route group '({})' does not match expected style: {}",
+                    name,
+                    style.describe()
+                ));
+            }
+        } else {
+            let suggestion = convert_to_style(&name, style);
+            if suggestion != name {
+                report(format!(
+                    "Route group '({})' does not match expected style: {}; rename to '({})'",
+                    name,
+                    style.describe(),
+                    suggestion
+                ));
+            }
+        }
+
+        let enclosing = dir
+            .ancestors()
+            .skip(1)
+            .take_while(|ancestor| *ancestor != project_root)
+            .filter_map(group_name)
+            .find(|ancestor| normalize(ancestor) == normalize(&name));
+        if let Some(ancestor) = enclosing {
+            report(format!(
+                "Route group '({})' is nested in a group with the same name '({})'",
+                name, ancestor
+            ));
+        }
+
+        if let Some(parent) = dir.parent() {
+            siblings.entry((parent, normalize(&name))).or_default().push(dir);
+        }
+    }
+
+    for dirs in siblings.values().filter(|dirs| dirs.len() > 1) {
+        for dir in &dirs[1..] {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "route-group-naming".to_string(),
+                message: format!(
+                    "Route group '{}' has the same name as its sibling '{}'",
+                    dir.file_name().and_then(|n| n.to_str()).unwrap_or(""),
+                    dirs[0].file_name().and_then(|n| n.to_str()).unwrap_or("")
+                ),
+                file: (*dir).clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Parse the interception marker a directory name starts with, e.g. `(..)`
+/// in `(..)photo`: how many route segments it climbs (`None` for `(...)`,
+/// the app root) and the segment name after it
+///
+/// Returns `None` for names without a marker and an error message for
+/// malformed markers.
+fn parse_interception_marker(name: &str) -> Option<Result<(Option<usize>, &str), String>> {
+    let mut rest = name;
+    let mut markers = Vec::new();
+    while let Some(inner) = rest.strip_prefix('(') {
+        let close = match inner.find(')') {
+            Some(close) => close,
+            None => break,
+        };
+        let dots = &inner[..close];
+        if dots.is_empty() || !dots.chars().all(|c| c == '.') {
+            break;
+        }
+        markers.push(dots.len());
+        rest = &inner[close + 1..];
+    }
+    if markers.is_empty() {
+        return None;
+    }
+
+    let marker = &name[..name.len() - rest.len()];
+    let levels = match markers.as_slice() {
+        [1] => Some(0),
+        [3] => None,
+        _ if markers.iter().all(|&dots| dots == 2) => Some(markers.len()),
+        _ => {
+            return Some(Err(format!(
+                "Malformed interception marker '{}' in '{}'; use (.), (..), (..)(..) or (...)",
+                marker, name
+            )))
+        }
+    };
+    if rest.is_empty() || rest.starts_with(['.', '(']) {
+        return Some(Err(format!(
+            "Interception marker '{}' in '{}' must be followed by a segment name",
+            marker, name
+        )));
+    }
+
+    Some(Ok((levels, rest)))
+}
+
+/// Check the syntax of intercepting route directories like `(.)photo` and
+/// that the segment they intercept exists
+///
+/// Malformed markers are reported with the rule's severity; targets that
+/// don't resolve to an existing route are warnings. Route groups and
+/// parallel route slots don't count as route segments.
+pub fn check_intercepting_routes(
+    project_root: &Path,
+    directories: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::Severity;
+    use std::collections::HashSet;
+
+    let rule = &config.rules.intercepting_routes;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    // Directories below app/, as their components relative to it
+    let mut app_dirs: Vec<(&std::path::PathBuf, Vec<&str>)> = directories
+        .iter()
+        .filter_map(|dir| {
+            let relative = dir.strip_prefix(project_root).ok()?.to_str()?;
+            let relative = relative.strip_prefix("src/").unwrap_or(relative);
+            let components = relative.strip_prefix("app/")?.split('/').collect();
+            Some((dir, components))
+        })
+        .collect();
+    app_dirs.sort();
+
+    let route_of = |components: &[&str]| -> Vec<String> {
+        components
+            .iter()
+            .filter(|c| !(c.starts_with('@') || c.starts_with('(') && c.ends_with(')')))
+            .map(|c| c.to_string())
+            .collect()
+    };
+    let routes: HashSet<Vec<String>> = app_dirs
+        .iter()
+        .filter(|(_, components)| {
+            !components
+                .iter()
+                .any(|c| parse_interception_marker(c).is_some())
+        })
+        .map(|(_, components)| route_of(components))
+        .collect();
+
+    for (dir, components) in &app_dirs {
+        let name = components.last().copied().unwrap_or("");
+        let (severity, message) = match parse_interception_marker(name) {
+            None => continue,
+            Some(Err(message)) => (rule.severity, message),
+            Some(Ok((levels, segment))) => {
+                let mut target = route_of(&components[..components.len() - 1]);
+                match levels {
+                    Some(levels) if levels > target.len() => {
+                        diagnostics.add(Diagnostic {
+                            severity: Severity::Warn,
+                            rule: "intercepting-routes".to_string(),
+                            message: format!("Intercepting route '{}' climbs above the app root", name),
+                            file: (*dir).clone(),
+                            line: None,
+                            project: None,
+                            tokens: Vec::new(),
+                        });
+                        continue;
+                    }
+                    Some(levels) => target.truncate(target.len() - levels),
+                    None => target.clear(),
+                }
+                target.push(segment.to_string());
+                if routes.contains(&target) {
+                    continue;
+                }
+                (
+                    Severity::Warn,
+                    format!(
+                        "Intercepting route '{}' doesn't match an existing route '/{}'",
+                        name,
+                        target.join("/")
+                    ),
+                )
+            }
+        };
+
+        diagnostics.add(Diagnostic {
+            severity,
+            rule: "intercepting-routes".to_string(),
+            message,
+            file: (*dir).clone(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that no directory under `app/` or `pages/` has sibling dynamic
+/// segments with different parameter names or kinds (`[id]` next to
+/// `[slug]` or `[...id]`), which Next.js rejects
+///
+/// In the pages router dynamic file names like `[id].tsx` count as segments.
+pub fn check_no_conflicting_dynamic_segments(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    directories: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    let rule = &config.rules.no_conflicting_dynamic_segments;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let mut by_parent: BTreeMap<&Path, BTreeSet<String>> = BTreeMap::new();
+    let entries = directories
+        .iter()
+        .map(|dir| (dir, true))
+        .chain(all_files.iter().map(|file| (file, false)));
+    for (entry, is_dir) in entries {
+        let router = match entry.strip_prefix(project_root).ok().and_then(router_of) {
+            Some(router) => router,
+            None => continue,
+        };
+        if !is_dir && router != Router::Pages {
+            continue;
+        }
+        let (name, parent) = match (entry.file_name().and_then(|n| n.to_str()), entry.parent()) {
+            (Some(name), Some(parent)) => (name, parent),
+            _ => continue,
+        };
+        let segment = if is_dir { name } else { route_file_segment(name) };
+        if !segment.starts_with('[') {
+            continue;
+        }
+        if let Ok((kind, param)) = parse_dynamic_segment(segment) {
+            by_parent.entry(parent).or_default().insert(kind.format(param));
+        }
+    }
+
+    for (parent, segments) in by_parent {
+        if segments.len() < 2 {
+            continue;
+        }
+        let segments: Vec<String> = segments.into_iter().collect();
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-conflicting-dynamic-segments".to_string(),
+            message: format!(
+                "'{}' has conflicting dynamic segments {}; use a single parameter name and kind",
+                parent.strip_prefix(project_root).unwrap_or(parent).display(),
+                segments.join(", ")
+            ),
+            file: parent.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static AS_DEFAULT_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\bas\s+default\b").unwrap());
+
+/// Check that Next.js can pick up the project's middleware: it must sit at
+/// the project root or in `src/`, exist only once, and export a
+/// `middleware` function or a default export
+///
+/// Misplaced files and missing exports use the rule's severity; extra
+/// middleware files are warnings.
+pub fn check_middleware_location(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::Severity;
+
+    let rule = &config.rules.middleware_location;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let mut middleware: Vec<&std::path::PathBuf> = all_files
+        .iter()
+        .filter(|file| file.file_stem().and_then(|s| s.to_str()) == Some("middleware"))
+        .collect();
+    middleware.sort();
+
+    let mut report = |file: &std::path::PathBuf, severity: Severity, message: String| {
+        diagnostics.add(Diagnostic {
+            severity,
+            rule: "middleware-location".to_string(),
+            message,
+            file: file.clone(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    };
+
+    let mut active: Option<&std::path::PathBuf> = None;
+    for file in middleware {
+        let relative = file.strip_prefix(project_root).unwrap_or(file);
+        let parent = relative.parent().and_then(|p| p.to_str()).unwrap_or("");
+        if !parent.is_empty() && parent != "src" {
+            report(
+                file,
+                rule.severity,
+                format!(
+                    "Middleware '{}' is ignored by Next.js; move it to the project root or src/",
+                    relative.display()
+                ),
+            );
+            continue;
+        }
+
+        if let Some(first) = active {
+            report(
+                file,
+                Severity::Warn,
+                format!(
+                    "More than one middleware file: '{}' and '{}'; Next.js only runs one",
+                    first.strip_prefix(project_root).unwrap_or(first).display(),
+                    relative.display()
+                ),
+            );
+            continue;
+        }
+        active = Some(file);
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        let exports_middleware = value_exports(&content)
+            .iter()
+            .any(|(_, name)| name == "middleware");
+        let exports_default =
+            EXPORT_DEFAULT_RE.is_match(&content) || AS_DEFAULT_RE.is_match(&content);
+        if !exports_middleware && !exports_default {
+            report(
+                file,
+                rule.severity,
+                format!(
+                    "Middleware '{}' exports neither a middleware function nor a default export",
+                    relative.display()
+                ),
+            );
+        }
+    }
+}
+
+static EXPORT_DECLARATION_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?m)^[ \t]*export\s+(?:default\s+)?(?:declare\s+)?(?:async\s+)?(?:const|let|var|function\*?|class|interface|type|enum|abstract\s+class)\s+([A-Za-z_$][\w$]*)",
+    )
+    .unwrap()
+});
+/// An `export { ... }` block; group 1 is set for re-exports (`} from`)
+static EXPORT_BRACES_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*export\s+(?:type\s+)?\{[^}]*\}(\s*from\b)?").unwrap()
+});
+
+/// Check that exports are declared inline or collected in a single
+/// `export { ... }` block at the bottom, depending on `export_style`
+///
+/// Best-effort: re-exports (`export { x } from '...'`, `export * from '...'`)
+/// and `export default Name` are accepted under both policies.
+pub fn check_export_placement(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::config::ExportStyle;
+    use crate::utils;
+
+    let rule = &config.rules.export_placement;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    let blocks: Vec<usize> = EXPORT_BRACES_RE
+        .captures_iter(&content)
+        .filter(|cap| cap.get(1).is_none())
+        .map(|cap| cap.get(0).unwrap().start())
+        .collect();
+
+    let mut report = |message: String, offset: usize| {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "export-placement".to_string(),
+            message,
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, offset)),
+            project: None,
+            tokens: Vec::new(),
+        });
+    };
+
+    match rule.options.export_style {
+        ExportStyle::Bottom => {
+            for cap in EXPORT_DECLARATION_RE.captures_iter(&content) {
+                report(
+                    format!(
+                        "Inline export of '{}'; collect exports in a single `export {{ ... }}` block at the bottom of the file",
+                        &cap[1]
+                    ),
+                    cap.get(0).unwrap().start(),
+                );
+            }
+            for offset in blocks.iter().skip(1) {
+                report(
+                    "Multiple `export { ... }` blocks; merge them into a single block at the bottom of the file".to_string(),
+                    *offset,
+                );
+            }
+        }
+        ExportStyle::Inline => {
+            for offset in &blocks {
+                report(
+                    "`export { ... }` block; add `export` to the declarations instead".to_string(),
+                    *offset,
+                );
+            }
+        }
+    }
+}
+
+/// Check that a route segment doesn't pair a client `layout` with a server
+/// `page`, which makes the server page a child of a client boundary
+pub fn check_boundary_consistency(
+    _project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::BTreeMap;
+
+    let rule = &config.rules.boundary_consistency;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let is_client = |file: &Path| {
+        fs::read_to_string(file).is_ok_and(|content| has_top_level_directive(&content, "use client"))
+    };
+
+    // Segment directory -> (layout, page)
+    let mut segments: BTreeMap<&Path, (Option<&Path>, Option<&Path>)> = BTreeMap::new();
+    for file in all_files {
+        let (segment, stem) = match (file.parent(), file.file_stem().and_then(|s| s.to_str())) {
+            (Some(segment), Some(stem)) => (segment, stem),
+            _ => continue,
+        };
+        match stem {
+            "layout" => segments.entry(segment).or_default().0 = Some(file),
+            "page" => segments.entry(segment).or_default().1 = Some(file),
+            _ => {}
+        }
+    }
+
+    for (layout, page) in segments.into_values() {
+        let (layout, page) = match (layout, page) {
+            (Some(layout), Some(page)) => (layout, page),
+            _ => continue,
+        };
+
+        if is_client(layout) && !is_client(page) {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "boundary-consistency".to_string(),
+                message: format!(
+                    "Client layout wraps server page '{}'; keep the layout a server component and move interactive parts into client components",
+                    page.file_name().and_then(|n| n.to_str()).unwrap_or("page")
+                ),
+                file: layout.to_path_buf(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that source files stay below a maximum number of lines
+pub fn check_max_component_lines(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.max_component_lines;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for file in all_files {
+        if utils::is_excluded(file, &rule.options.exclude, project_root) {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        let line_count = content.lines().count();
+        if line_count > rule.options.max_lines {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "max-component-lines".to_string(),
+                message: format!(
+                    "File has {} lines, more than the maximum of {}; consider splitting it",
+                    line_count, rule.options.max_lines
+                ),
+                file: file.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check for files much longer than the other files in their directory,
+/// a hint that they are due for a split
+///
+/// A file is compared with the average line count of its siblings, so an
+/// outlier doesn't raise the average it is measured against.
+pub fn check_outlier_file_size(
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::BTreeMap;
+
+    let rule = &config.rules.outlier_file_size;
+    if !rule.is_enabled() {
+        return;
+    }
+    let options = &rule.options;
+
+    let mut by_dir: BTreeMap<&Path, Vec<(&std::path::PathBuf, usize)>> = BTreeMap::new();
+    for file in all_files {
+        let line_count = match fs::read_to_string(file) {
+            Ok(content) => content.lines().count(),
+            Err(_) => continue,
+        };
+        let dir = file.parent().unwrap_or(Path::new(""));
+        by_dir.entry(dir).or_default().push((file, line_count));
+    }
+
+    for files in by_dir.values() {
+        if files.len() < options.min_files.max(2) {
+            continue;
+        }
+        let total: usize = files.iter().map(|(_, lines)| lines).sum();
+
+        for (file, line_count) in files {
+            if *line_count < options.min_lines {
+                continue;
+            }
+            let average = (total - line_count) as f64 / (files.len() - 1) as f64;
+            if (*line_count as f64) <= average * options.size_multiplier {
+                continue;
+            }
+
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "outlier-file-size".to_string(),
+                message: format!(
+                    "File has {} lines, more than {} times the {:.0}-line average of the other files in its directory; consider splitting it",
+                    line_count, options.size_multiplier, average
+                ),
+                file: (*file).clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Whether the code before `offset` ends with a `/** ... */` block, ignoring
+/// whitespace in between
+fn has_preceding_jsdoc(content: &str, offset: usize) -> bool {
+    let before = content[..offset].trim_end();
+    if !before.ends_with("*/") {
+        return false;
+    }
+    match before.rfind("/*") {
+        Some(start) => before[start..].starts_with("/**"),
+        None => false,
+    }
+}
+
+static EXPORTED_TYPE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?m)^[ \t]*export\s+(?:declare\s+)?(interface|type)\s+([A-Za-z_$][\w$]*)")
+        .unwrap()
+});
+
+/// Check that exported types and interfaces in public API files are documented
+pub fn check_exported_type_docs(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.exported_type_docs;
+    if !rule.is_enabled() {
+        return;
+    }
+    let public_api_glob = match &rule.options.public_api_glob {
+        Some(glob) => glob,
+        None => return,
+    };
+
+    for file in all_files {
+        if !utils::matches_glob(file, public_api_glob, project_root) {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        for cap in EXPORTED_TYPE_RE.captures_iter(&content) {
+            let start = cap.get(0).unwrap().start();
+            if has_preceding_jsdoc(&content, start) {
+                continue;
+            }
+
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "exported-type-docs".to_string(),
+                message: format!(
+                    "Exported {} '{}' has no JSDoc comment; add a /** ... */ block describing it",
+                    &cap[1], &cap[2]
+                ),
+                file: file.clone(),
+                line: Some(utils::line_number_at(&content, start)),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that no `index` barrel files exist where they are forbidden
+pub fn check_no_barrel_files(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.no_barrel_files;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for file in all_files {
+        if file.file_stem().and_then(|s| s.to_str()) != Some("index") {
+            continue;
+        }
+        if !utils::matches_glob(file, &rule.options.forbidden_glob, project_root) {
+            continue;
+        }
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-barrel-files".to_string(),
+            message: format!(
+                "Barrel files are not allowed in '{}'; import modules directly from their files instead",
+                rule.options.forbidden_glob
+            ),
+            file: file.clone(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that relative and alias imports resolve to a file in the project
+///
+/// Package imports are skipped, and so are bare specifiers that only a
+/// tsconfig `baseUrl` would resolve. An import of `./file.js` also resolves
+/// to `./file.ts` and the other configured extensions, as in TypeScript.
+pub fn check_unresolved_imports(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.unresolved_import;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let extensions = &config.files.extensions;
+    let resolves = |specifier: &str, importer: &Path| {
+        utils::resolve_import_candidates(specifier, importer, project_root, aliases)
+            .iter()
+            .any(|candidate| {
+                let without_js = ["js", "jsx", "mjs", "cjs"]
+                    .iter()
+                    .find(|ext| candidate.extension().is_some_and(|e| e == **ext))
+                    .map(|_| candidate.with_extension(""));
+                utils::resolve_to_actual_file(candidate, extensions).is_some()
+                    || without_js.is_some_and(|base| utils::resolve_to_actual_file(&base, extensions).is_some())
+            })
+    };
+
+    for file in all_files {
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        for (specifier, _, offset) in utils::extract_quoted_imports(&content) {
+            // Loader queries such as `./icon.svg?url` aren't part of the path
+            let specifier = specifier.split('?').next().unwrap_or_default();
+            let checked = utils::resolve_import_path(specifier, file, project_root).is_some()
+                || aliases.is_alias(specifier);
+            if !checked || resolves(specifier, file) {
+                continue;
+            }
+
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "unresolved-import".to_string(),
+                message: format!("Import '{}' does not resolve to a file", specifier),
+                file: file.clone(),
+                line: Some(utils::line_number_at(&content, offset)),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check the import graph for cycles, reporting each cycle found by a
+/// depth-first search once, on its first file in path order
+pub fn check_no_circular_imports(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+    use std::collections::{BTreeMap, BTreeSet, HashSet};
+    use std::path::PathBuf;
+
+    let rule = &config.rules.no_circular_imports;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+
+    // Edges between canonical paths, remembering the paths files were found by
+    let mut originals = BTreeMap::new();
+    let mut graph: BTreeMap<PathBuf, BTreeSet<PathBuf>> = BTreeMap::new();
+    for file in all_files {
+        let canonical = file.canonicalize().unwrap_or_else(|_| file.clone());
+        let specifiers = if rule.options.ignore_type_imports {
+            utils::extract_value_imports(file)
+        } else {
+            utils::extract_imports(file)
+        };
+        let targets = specifiers.iter().filter_map(|specifier| {
+            utils::resolve_import(specifier, file, project_root, aliases, &config.files.extensions)
+        });
+        graph.entry(canonical.clone()).or_default().extend(targets);
+        originals.insert(canonical, file.clone());
+    }
+
+    fn visit(
+        node: &Path,
+        graph: &BTreeMap<PathBuf, BTreeSet<PathBuf>>,
+        stack: &mut Vec<PathBuf>,
+        done: &mut HashSet<PathBuf>,
+        cycles: &mut BTreeSet<Vec<PathBuf>>,
+    ) {
+        stack.push(node.to_path_buf());
+        for target in graph.get(node).into_iter().flatten() {
+            if let Some(start) = stack.iter().position(|file| file == target) {
+                // Rotate so the same cycle is always listed from the same file
+                let mut cycle = stack[start..].to_vec();
+                let first = (0..cycle.len()).min_by_key(|&i| &cycle[i]).unwrap_or(0);
+                cycle.rotate_left(first);
+                cycles.insert(cycle);
+            } else if !done.contains(target) {
+                visit(target, graph, stack, done, cycles);
+            }
+        }
+        stack.pop();
+        done.insert(node.to_path_buf());
+    }
+
+    let mut cycles = BTreeSet::new();
+    let mut done = HashSet::new();
+    for node in graph.keys() {
+        if !done.contains(node) {
+            visit(node, &graph, &mut Vec::new(), &mut done, &mut cycles);
+        }
+    }
+
+    for cycle in cycles {
+        let display = |file: &PathBuf| file.strip_prefix(&root).unwrap_or(file).display().to_string();
+        let sequence: Vec<String> = cycle.iter().chain(cycle.first()).map(display).collect();
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "no-circular-imports".to_string(),
+            message: format!("Circular import: {}", sequence.join(" -> ")),
+            file: originals.get(&cycle[0]).cloned().unwrap_or_else(|| cycle[0].clone()),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that no module imports an `index` barrel that re-exports it,
+/// directly or through other barrels, which creates an import cycle
+pub fn check_barrel_self_cycle(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    aliases: &PathAliases,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+    use std::collections::HashSet;
+
+    let rule = &config.rules.barrel_self_cycle;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let root = project_root
+        .canonicalize()
+        .unwrap_or_else(|_| project_root.to_path_buf());
+    let import_index = utils::build_import_index(all_files, project_root, aliases, &config.files.extensions);
+
+    let mut violations = Vec::new();
+    for (barrel, importers) in &import_index {
+        if barrel.file_stem().and_then(|s| s.to_str()) != Some("index") {
+            continue;
+        }
+
+        // Everything the barrel re-exports, following nested barrels
+        let mut reexported = HashSet::new();
+        let mut pending = vec![barrel.clone()];
+        while let Some(module) = pending.pop() {
+            for specifier in utils::extract_reexports(&module) {
+                let target = utils::resolve_import(
+                    &specifier,
+                    &module,
+                    project_root,
+                    aliases,
+                    &config.files.extensions,
+                );
+                if let Some(target) = target {
+                    if reexported.insert(target.clone()) {
+                        pending.push(target);
+                    }
+                }
+            }
+        }
+
+        for importer in importers {
+            let canonical = importer.canonicalize().unwrap_or_else(|_| importer.clone());
+            if canonical == *barrel || !reexported.contains(&canonical) {
+                continue;
+            }
+
+            violations.push((
+                importer.clone(),
+                format!(
+                    "Imports '{}', which re-exports this file; import sibling modules directly to avoid the cycle",
+                    barrel.strip_prefix(&root).unwrap_or(barrel).display()
+                ),
+            ));
+        }
+    }
+
+    violations.sort();
+    for (importer, message) in violations {
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "barrel-self-cycle".to_string(),
+            message,
+            file: importer,
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+static HOOK_EXPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"export\s+(?:const|function)\s+(use[A-Z]\w*)").unwrap());
+static HOOK_LIKE_NAME_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^use(?:[A-Z]|[-_][a-z])").unwrap());
+
+/// Check that files exporting a `useX` hook are named after it and that
+/// files named like a hook export one
+pub fn check_hook_file_naming(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.hook_file_naming;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let mut parts = file_name.split('.');
+    let base = parts.next().unwrap_or("");
+    let suffix = parts.collect::<Vec<_>>().join(".");
+
+    // Barrel files re-export many hooks
+    if base == "index" {
+        return;
+    }
+
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+
+    // Compare names independent of the filename style (useAuth, use-auth, use_auth)
+    let normalize = |name: &str| name.replace(['-', '_'], "").to_lowercase();
+
+    let hooks: Vec<(String, usize)> = HOOK_EXPORT_RE
+        .captures_iter(&content)
+        .map(|cap| (cap[1].to_string(), cap.get(0).unwrap().start()))
+        .collect();
+
+    if let Some((hook, offset)) = hooks.first() {
+        if hooks.iter().any(|(name, _)| normalize(name) == normalize(base)) {
+            return;
+        }
+
+        // Provider and context modules (`AuthProvider` with `useAuth`) export
+        // their hook next to the main export; only hook modules are renamed
+        let is_hook = |name: &str| {
+            name.strip_prefix("use")
+                .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+        };
+        let exports_other = value_exports(&content)
+            .iter()
+            .any(|(_, name)| name != "default" && !is_hook(name))
+            || match find_default_export(&content) {
+                Some((DefaultExport::Named(name), _)) => !is_hook(&name),
+                Some((DefaultExport::Anonymous, _)) => true,
+                None => false,
+            };
+        if exports_other {
+            return;
+        }
+
+        let style = &config.rules.filename_style.options.filename_style;
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "hook-file-naming".to_string(),
+            message: format!(
+                "File exports hook '{}' but is named '{}'; rename it to '{}.{}'",
+                hook,
+                base,
+                convert_to_style(hook, style),
+                suffix
+            ),
+            file: path.to_path_buf(),
+            line: Some(utils::line_number_at(&content, *offset)),
+            project: None,
+            tokens: Vec::new(),
+        });
+        return;
+    }
+
+    // Test, story and other suffixed files (useAuth.test.ts) don't export the hook
+    if HOOK_LIKE_NAME_RE.is_match(base) && suffix.split('.').count() == 1 {
+        let expected = convert_to_style(base, &FilenameStyle::CamelCase);
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "hook-file-naming".to_string(),
+            message: format!(
+                "File '{}' is named like a hook but does not export '{}'",
+                file_name, expected
+            ),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check that catch-all API routes (`app/api/[...path]/route.ts`,
+/// `pages/api/[...path].ts`) are allowlisted and contain the required markers
+pub fn check_catch_all_api_routes(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.catch_all_api_routes;
+    if !rule.is_enabled() {
+        return;
+    }
+
+    for file in all_files {
+        let relative = match file.strip_prefix(project_root) {
+            Ok(rel) => rel,
+            Err(_) => continue,
+        };
+        let segments: Vec<&str> = relative
+            .components()
+            .filter_map(|c| c.as_os_str().to_str())
+            .collect();
+        let segments = match segments.split_first() {
+            Some((&"src", rest)) => rest,
+            _ => &segments[..],
+        };
+
+        let stem = file.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+        let route_segments = match segments {
+            ["app", "api", dirs @ .., _] if stem == "route" => dirs.to_vec(),
+            ["pages", "api", dirs @ .., _] => {
+                let mut route = dirs.to_vec();
+                route.push(stem);
+                route
+            }
+            _ => continue,
+        };
+
+        let catch_all = match route_segments
+            .iter()
+            .find(|segment| segment.starts_with("[...") || segment.starts_with("[[..."))
+        {
+            Some(segment) => *segment,
+            None => continue,
+        };
+
+        let display = segments.join("/");
+        if !utils::is_excluded(file, &rule.options.allowed_catch_all_routes, project_root) {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "catch-all-api-routes".to_string(),
+                message: format!(
+                    "Catch-all API route '{}' ({}) handles every path below it; \
+                     add it to `allowed_catch_all_routes` if this is intentional",
+                    display, catch_all
+                ),
+                file: file.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+            continue;
+        }
+
+        if rule.options.required_content_patterns.is_empty() {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        for pattern in rule.options.required_content_patterns.iter() {
+            if pattern.is_match(&content) {
+                continue;
+            }
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "catch-all-api-routes".to_string(),
+                message: format!(
+                    "Allowlisted catch-all API route '{}' does not match required content pattern '{}'",
+                    display,
+                    pattern.as_str()
+                ),
+                file: file.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+static COMPONENT_FUNCTION_EXPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"export\s+(?:default\s+)?function\s+([A-Z]\w*)").unwrap());
+static COMPONENT_CONST_EXPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"export\s+const\s+([A-Z]\w*)\s*(?::[^=]+)?=\s*").unwrap());
+static COMPONENT_DEFAULT_EXPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?m)export\s+default\s+([A-Z]\w*)\s*;?\s*$").unwrap());
+static MEMO_CALL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(?:React\.)?memo\s*(?:<[^>]*>)?\s*\(").unwrap());
+static COMPONENT_VALUE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:\(|function\b|async\b|(?:React\.)?forwardRef\b|\w+\s*=>)").unwrap()
+});
+
+/// Check that exported components under `memo_glob` are wrapped in `memo`
+/// (best-effort, based on how the export is written)
+pub fn check_require_memo(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.require_memo;
+    if !rule.is_enabled() {
+        return;
+    }
+    let memo_glob = match &rule.options.memo_glob {
+        Some(glob) => glob,
+        None => return,
+    };
+
+    for file in all_files {
+        if !matches!(
+            file.extension().and_then(|e| e.to_str()),
+            Some("tsx") | Some("jsx")
+        ) || !utils::matches_glob(file, memo_glob, project_root)
+        {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        let mut unmemoized: Vec<(String, usize)> = Vec::new();
+
+        for cap in COMPONENT_FUNCTION_EXPORT_RE.captures_iter(&content) {
+            unmemoized.push((cap[1].to_string(), cap.get(0).unwrap().start()));
+        }
+
+        for cap in COMPONENT_CONST_EXPORT_RE.captures_iter(&content) {
+            let value = &content[cap.get(0).unwrap().end()..];
+            if !MEMO_CALL_RE.is_match(value) && COMPONENT_VALUE_RE.is_match(value) {
+                unmemoized.push((cap[1].to_string(), cap.get(0).unwrap().start()));
+            }
+        }
+
+        // `export default Button;` is fine when Button itself was created with memo
+        for cap in COMPONENT_DEFAULT_EXPORT_RE.captures_iter(&content) {
+            let name = &cap[1];
+            let memo_binding = Regex::new(&format!(
+                r"const\s+{}\s*(?::[^=]+)?=\s*(?:React\.)?memo\b",
+                regex::escape(name)
+            ))
+            .unwrap();
+            if !memo_binding.is_match(&content) {
+                unmemoized.push((name.to_string(), cap.get(0).unwrap().start()));
+            }
+        }
+
+        unmemoized.sort_by_key(|(_, offset)| *offset);
+        for (name, offset) in unmemoized {
+            diagnostics.add(Diagnostic {
+                severity: rule.severity,
+                rule: "require-memo".to_string(),
+                message: format!(
+                    "Exported component '{}' must be wrapped in memo() in files matching '{}'",
+                    name, memo_glob
+                ),
+                file: file.clone(),
+                line: Some(utils::line_number_at(&content, offset)),
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum HeaderProblem {
+    Missing,
+    Mismatch,
+}
+
+/// Index of the first line after an optional shebang, `'use client'` /
+/// `'use server'` directives and blank lines
+fn header_start(lines: &[&str]) -> usize {
+    let mut index = 0;
+    if lines.first().is_some_and(|line| line.starts_with("#!")) {
+        index = 1;
+    }
+
+    while let Some(line) = lines.get(index) {
+        let statement = line.trim().trim_end_matches(';').trim();
+        let is_directive = matches!(
+            statement,
+            "'use client'" | "\"use client\"" | "'use server'" | "\"use server\""
+        );
+        if !is_directive && !statement.is_empty() {
+            break;
+        }
+        index += 1;
+    }
+
+    index
+}
+
+fn file_header_problem(content: &str, options: &FileHeaderOptions) -> Option<HeaderProblem> {
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let start = header_start(&lines);
+    let end = (start + options.header_search_lines).min(lines.len());
+    let window = lines[start..end].concat();
+
+    let matches = match (&options.header_pattern, &options.header_template) {
+        (Some(pattern), _) => pattern.is_match(&window),
+        (None, Some(template)) => window.contains(template.trim()),
+        (None, None) => return None,
+    };
+    if matches {
+        return None;
+    }
+
+    let first = lines.get(start).map(|line| line.trim_start()).unwrap_or("");
+    if first.starts_with("//") || first.starts_with("/*") {
+        Some(HeaderProblem::Mismatch)
+    } else {
+        Some(HeaderProblem::Missing)
+    }
+}
+
+/// Check that files matching `header_globs` start with the configured
+/// license/ownership header
+pub fn check_file_header(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use crate::utils;
+
+    let rule = &config.rules.file_header;
+    if !rule.is_enabled() {
+        return;
+    }
+    let options = &rule.options;
+    let expected = match (&options.header_pattern, &options.header_template) {
+        (Some(pattern), _) => format!("a header matching '{}'", pattern.as_str()),
+        (None, Some(template)) => format!(
+            "a header starting with '{}'",
+            template.trim().lines().next().unwrap_or("")
+        ),
+        (None, None) => return,
+    };
+
+    for file in all_files {
+        if !options
+            .header_globs
+            .iter()
+            .any(|glob| utils::matches_glob(file, glob, project_root))
+        {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+
+        let message = match file_header_problem(&content, options) {
+            None => continue,
+            Some(HeaderProblem::Missing) if options.header_template.is_some() => {
+                format!("Missing file header; expected {} (run with --fix to insert it)", expected)
+            }
+            Some(HeaderProblem::Missing) => format!("Missing file header; expected {}", expected),
+            Some(HeaderProblem::Mismatch) => format!("File header does not match; expected {}", expected),
+        };
+
+        diagnostics.add(Diagnostic {
+            severity: rule.severity,
+            rule: "file-header".to_string(),
+            message,
+            file: file.clone(),
+            line: Some(1),
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Insert `header_template` above the first non-directive line of files that
+/// have no header; returns the number of files changed
+pub fn fix_file_header(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+) -> usize {
+    use crate::utils;
+
+    let rule = &config.rules.file_header;
+    let template = match &rule.options.header_template {
+        Some(template) if rule.is_enabled() => template.trim_end(),
+        _ => return 0,
+    };
+
+    let mut fixed = 0;
+    for file in all_files {
+        if !rule
+            .options
+            .header_globs
+            .iter()
+            .any(|glob| utils::matches_glob(file, glob, project_root))
+        {
+            continue;
+        }
+
+        let content = match fs::read_to_string(file) {
+            Ok(c) => c,
+            Err(_) => continue,
+        };
+        if file_header_problem(&content, &rule.options) != Some(HeaderProblem::Missing) {
+            continue;
+        }
+
+        let lines: Vec<&str> = content.split_inclusive('\n').collect();
+        let start = header_start(&lines);
+        let mut updated = lines[..start].concat();
+        if !updated.is_empty() && !updated.ends_with('\n') {
+            updated.push('\n');
+        }
+        updated.push_str(template);
+        updated.push('\n');
+        updated.push_str(&lines[start..].concat());
+
+        if fs::write(file, updated).is_ok() {
+            fixed += 1;
+        }
+    }
+
+    fixed
+}
+
+/// Check whether the file starts with the given directive (e.g. "use server"),
+/// skipping blank lines and comments that precede it
+fn has_top_level_directive(content: &str, directive: &str) -> bool {
+    top_level_directives(content)
+        .iter()
+        .any(|(found, _)| found == directive)
+}
+
+/// The directives of the module's prologue (e.g. `'use client'`) with their
+/// 1-based line, skipping blank lines and comments between them
+fn top_level_directives(content: &str) -> Vec<(String, usize)> {
+    let mut directives = Vec::new();
+    let mut in_block_comment = false;
+    for (index, line) in content.lines().enumerate() {
+        let mut trimmed = line.trim();
+
+        if in_block_comment {
+            match trimmed.find("*/") {
+                Some(end) => {
+                    in_block_comment = false;
+                    trimmed = trimmed[end + 2..].trim();
+                }
+                None => continue,
+            }
+        }
+
+        if trimmed.is_empty() || trimmed.starts_with("//") {
+            continue;
+        }
+
+        if trimmed.starts_with("/*") {
+            match trimmed.find("*/") {
+                Some(end) => trimmed = trimmed[end + 2..].trim(),
+                None => {
+                    in_block_comment = true;
+                    continue;
+                }
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+        }
+
+        // The prologue ends at the first statement that isn't a string
+        let statement = trimmed.trim_end_matches(';').trim();
+        let quote = match statement.chars().next() {
+            Some(quote @ ('\'' | '"')) => quote,
+            _ => break,
+        };
+        match statement[1..].strip_suffix(quote) {
+            Some(directive) if !directive.contains(quote) => {
+                directives.push((directive.to_string(), index + 1));
+            }
+            _ => break,
+        }
+    }
+
+    directives
+}
+
+// ==================== BASSIST PRESET RULES ====================
+
+/// Check that each route group has a proper domain structure with [locale] directory
+pub fn check_bassist_domain_structure(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::HashSet;
+    
+    // Find all route groups in app directory
+    let mut route_groups = HashSet::new();
+    
+    for file in all_files {
+        let path_str = file.to_str().unwrap_or("");
+        
+        // Match app/(route-group)/ pattern
+        if let Some(app_pos) = path_str.find("/app/") {
+            let after_app = &path_str[app_pos + 5..];
+            if after_app.starts_with('(') {
+                if let Some(close_paren) = after_app.find(')') {
+                    let route_group = &after_app[1..close_paren];
+                    let route_group_path = project_root.join("app").join(format!("({})", route_group));
+                    route_groups.insert(route_group_path);
+                }
+            }
+        }
+    }
+    
+    // Check each route group has [locale]/ directory
+    for route_group_path in route_groups {
+        let locale_dir = route_group_path.join("[locale]");
+        if !locale_dir.exists() {
+            diagnostics.add(Diagnostic {
+                severity: config.rules.bassist_domain_structure.severity,
+                rule: "bassist-domain-structure".to_string(),
+                message: format!(
+                    "Route group '{}' must contain a '[locale]/' directory for i18n support",
+                    route_group_path.file_name().unwrap().to_str().unwrap()
+                ),
+                file: route_group_path.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that each route group's [locale] directory has a layout.tsx
+pub fn check_bassist_locale_layout(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::HashSet;
+    
+    // Find all [locale] directories in route groups
+    let mut locale_dirs = HashSet::new();
+    
+    for file in all_files {
+        let path_str = file.to_str().unwrap_or("");
+        
+        // Match app/(route-group)/[locale]/ pattern
+        if let Some(app_pos) = path_str.find("/app/") {
+            let after_app = &path_str[app_pos + 5..];
+            if after_app.starts_with('(') {
+                if let Some(close_paren) = after_app.find(')') {
+                    let after_route_group = &after_app[close_paren + 1..];
+                    if after_route_group.starts_with("/[locale]") {
+                        let route_group = &after_app[1..close_paren];
+                        let locale_path = project_root
+                            .join("app")
+                            .join(format!("({})", route_group))
+                            .join("[locale]");
+                        locale_dirs.insert(locale_path);
+                    }
+                }
+            }
+        }
+    }
+    
+    // Check each [locale] directory has layout.tsx
+    for locale_dir in locale_dirs {
+        let layout_file = locale_dir.join("layout.tsx");
+        let layout_js = locale_dir.join("layout.jsx");
+        
+        if !layout_file.exists() && !layout_js.exists() {
+            diagnostics.add(Diagnostic {
+                severity: config.rules.bassist_locale_layout.severity,
+                rule: "bassist-locale-layout".to_string(),
+                message: format!(
+                    "Locale directory '{}' must contain a layout.tsx file for i18n routing",
+                    locale_dir.display()
+                ),
+                file: locale_dir.clone(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that pages in route groups are inside [locale]/ dynamic segments
+pub fn check_bassist_locale_nesting(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    
+    // Check for special Next.js files in route groups
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    let is_special_file = matches!(file_name, "page.tsx" | "page.jsx" | "layout.tsx" | "layout.jsx" | "loading.tsx" | "loading.jsx" | "error.tsx" | "error.jsx" | "not-found.tsx" | "not-found.jsx");
+    
+    if !is_special_file {
+        return;
+    }
+    
+    // Check if file is in a route group
+    if let Some(app_pos) = path_str.find("/app/") {
+        let after_app = &path_str[app_pos + 5..];
+        if after_app.starts_with('(') {
+            // In a route group - check if it's inside [locale]/
+            if !after_app.contains("/[locale]/") {
+                diagnostics.add(Diagnostic {
+                    severity: config.rules.bassist_locale_nesting.severity,
+                    rule: "bassist-locale-nesting".to_string(),
+                    message: format!(
+                        "Page file '{}' in route group must be inside [locale]/ directory for i18n routing",
+                        file_name
+                    ),
+                    file: path.to_path_buf(),
+                    line: None,
+                    project: None,
+                    tokens: Vec::new(),
+                });
+            }
+        }
+    }
+}
+
+/// Check that route group names match allowed list
+pub fn check_bassist_route_group_names(
+    project_root: &Path,
+    all_files: &[std::path::PathBuf],
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    use std::collections::HashSet;
+    
+    let allowed_groups: HashSet<&str> = config
+        .rules
+        .bassist_route_group_names
+        .options
+        .bassist
+        .allowed_route_groups
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+    
+    let mut found_groups = HashSet::new();
+    
+    // Find all route groups
+    for file in all_files {
+        let path_str = file.to_str().unwrap_or("");
+        
+        if let Some(app_pos) = path_str.find("/app/") {
+            let after_app = &path_str[app_pos + 5..];
+            if after_app.starts_with('(') {
+                if let Some(close_paren) = after_app.find(')') {
+                    let route_group = &after_app[1..close_paren];
+                    found_groups.insert(route_group.to_string());
+                }
+            }
+        }
+    }
+    
+    // Check for unknown route groups
+    for group in found_groups {
+        if !allowed_groups.contains(group.as_str()) {
+            let route_group_path = project_root.join("app").join(format!("({})", group));
+            diagnostics.add(Diagnostic {
+                severity: config.rules.bassist_route_group_names.severity,
+                rule: "bassist-route-group-names".to_string(),
+                message: format!(
+                    "Unknown route group '({})'. Expected one of: {}",
+                    group,
+                    allowed_groups.iter().map(|s| format!("({})", s)).collect::<Vec<_>>().join(", ")
+                ),
+                file: route_group_path,
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check that service client is not used in production code
+pub fn check_bassist_service_client_restriction(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    
+    // Allow service client in test files and seed directories
+    if path_str.contains(".test.") || path_str.contains(".spec.") || path_str.contains("/seed/") {
+        return;
+    }
+    
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    // Check for service client usage
+    let service_client_patterns = [
+        r"createTestServiceClient",
+        r#"from\s+['"]@/lib/supabase/service['"]"#,
+        r#"from\s+['"]@/tests/utils/supabase-service['"]"#,
+    ];
+    
+    for pattern_str in &service_client_patterns {
+        if let Ok(re) = Regex::new(pattern_str) {
+            if re.is_match(&content) {
+                diagnostics.add(Diagnostic {
+                    severity: config.rules.bassist_service_client_restriction.severity,
+                    rule: "bassist-service-client-restriction".to_string(),
+                    message: "Service client (createTestServiceClient) must only be used in test files or seed scripts. This bypasses RLS policies and is a security risk in production code.".to_string(),
+                    file: path.to_path_buf(),
+                    line: None,
+                    project: None,
+                    tokens: Vec::new(),
+                });
+                break;
+            }
+        }
+    }
+}
+
+/// Check correct Supabase client imports based on file type
+pub fn check_bassist_supabase_client_imports(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    // Check for "use client" directive in first 10 lines
+    let has_use_client = content.lines().take(10).any(|line| {
+        let trimmed = line.trim();
+        trimmed == "'use client'" || trimmed == "\"use client\""
+    });
+    
+    // Check for server client import
+    let has_server_import = Regex::new(r#"from\s+['"]@/lib/supabase/server['"]"#)
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    // Check for browser client import
+    let has_client_import = Regex::new(r#"from\s+['"]@/lib/supabase/client['"]"#)
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    if has_use_client && has_server_import {
+        diagnostics.add(Diagnostic {
+            severity: config.rules.bassist_supabase_client_imports.severity,
+            rule: "bassist-supabase-client-imports".to_string(),
+            message: "Client component ('use client') should import from '@/lib/supabase/client', not '@/lib/supabase/server'".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+    
+    if !has_use_client && has_client_import {
+        // Check if this might be a server component (files in app/ that aren't client)
+        let path_str = path.to_str().unwrap_or("");
+        if path_str.contains("/app/") {
+            diagnostics.add(Diagnostic {
+                severity: config.rules.bassist_supabase_client_imports.severity,
+                rule: "bassist-supabase-client-imports".to_string(),
+                message: "Server component should import from '@/lib/supabase/server', not '@/lib/supabase/client'".to_string(),
+                file: path.to_path_buf(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check correct i18n hook usage based on file type
+pub fn check_bassist_i18n_hook_usage(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    // Check for "use client" directive
+    let has_use_client = content.lines().take(10).any(|line| {
+        let trimmed = line.trim();
+        trimmed == "'use client'" || trimmed == "\"use client\""
+    });
+    
+    // Check for getExtracted (server only)
+    let has_get_extracted = Regex::new(r"\bgetExtracted\s*\(")
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    // Check for useExtracted (client only)
+    let has_use_extracted = Regex::new(r"\buseExtracted\s*\(")
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    if has_use_client && has_get_extracted {
+        diagnostics.add(Diagnostic {
+            severity: config.rules.bassist_i18n_hook_usage.severity,
+            rule: "bassist-i18n-hook-usage".to_string(),
+            message: "Client component should use 'useExtracted()' hook, not 'getExtracted()' function".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+    
+    if !has_use_client && has_use_extracted {
+        // Check if this is in app/ directory (likely server component)
+        let path_str = path.to_str().unwrap_or("");
+        if path_str.contains("/app/") {
+            diagnostics.add(Diagnostic {
+                severity: config.rules.bassist_i18n_hook_usage.severity,
+                rule: "bassist-i18n-hook-usage".to_string(),
+                message: "Server component should use 'getExtracted()' function, not 'useExtracted()' React hook".to_string(),
+                file: path.to_path_buf(),
+                line: None,
+                project: None,
+                tokens: Vec::new(),
+            });
+        }
+    }
+}
+
+/// Check test files are colocated, not in root /tests directory
+pub fn check_bassist_test_colocation(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    
+    // Check if this is a test file
+    let is_test_file = path_str.contains(".test.") || path_str.contains(".spec.");
+    
+    if !is_test_file {
+        return;
+    }
+    
+    // Check if it's in a root /tests directory (not domain-specific)
+    if path_str.starts_with("tests/") || path_str.contains("/tests/") && !path_str.contains("/app/") {
+        diagnostics.add(Diagnostic {
+            severity: config.rules.bassist_test_colocation.severity,
+            rule: "bassist-test-colocation".to_string(),
+            message: "Test files should be colocated with their implementation in domain folders (app/), not in a separate /tests directory".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check test file naming conventions based on test type
+pub fn check_bassist_test_naming(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    
+    // Only check test files
+    if !path_str.contains(".test.") && !path_str.contains(".spec.") {
+        return;
+    }
+    
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    // Heuristic checks based on imports
+    let has_playwright = Regex::new(r#"from\s+['"]@playwright/test['"]"#)
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    let has_db_test_utils = Regex::new(r"(createTestServiceClient|ensureTestUser)")
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    let has_mastra = Regex::new(r#"from\s+['"]@/src/mastra"#)
+        .map(|re| re.is_match(&content))
+        .unwrap_or(false);
+    
+    // Suggest appropriate extension
+    if has_playwright && !path_str.ends_with(".spec.ts") {
+        diagnostics.add(Diagnostic {
+            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
+                config.rules.bassist_test_naming.severity
+            } else {
+                crate::config::Severity::Warn
+            },
+            rule: "bassist-test-naming".to_string(),
+            message: "E2E tests using Playwright should use '*.spec.ts' extension".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    } else if has_db_test_utils && !path_str.contains(".test.db.") {
+        diagnostics.add(Diagnostic {
+            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
+                config.rules.bassist_test_naming.severity
+            } else {
+                crate::config::Severity::Warn
+            },
+            rule: "bassist-test-naming".to_string(),
+            message: "Database tests using service client or test users should use '*.test.db.ts' extension".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    } else if has_mastra && !path_str.contains(".test.gen.") {
+        diagnostics.add(Diagnostic {
+            severity: if config.rules.bassist_test_naming.options.bassist.enforce_test_naming {
+                config.rules.bassist_test_naming.severity
+            } else {
+                crate::config::Severity::Warn
+            },
+            rule: "bassist-test-naming".to_string(),
+            message: "AI generation tests should use '*.test.gen.ts' extension".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check API routes are in proper api/ directories
+pub fn check_bassist_api_route_structure(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    
+    // Check if this is a route.ts file (API route)
+    if file_name != "route.ts" && file_name != "route.js" {
+        return;
+    }
+    
+    // Check if it's in an /api/ directory
+    if !path_str.contains("/api/") {
+        diagnostics.add(Diagnostic {
+            severity: config.rules.bassist_api_route_structure.severity,
+            rule: "bassist-api-route-structure".to_string(),
+            message: "API route files (route.ts) should be placed in /api/ directories".to_string(),
+            file: path.to_path_buf(),
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    }
+}
+
+/// Check for cross-domain imports that violate domain boundaries
+pub fn check_bassist_domain_isolation(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let path_str = path.to_str().unwrap_or("");
+    
+    // Extract current file's route group
+    let current_route_group = if let Some(app_pos) = path_str.find("/app/") {
+        let after_app = &path_str[app_pos + 5..];
+        if after_app.starts_with('(') {
+            after_app.find(')').map(|close_paren| &after_app[1..close_paren])
+        } else {
+            None
+        }
+    } else {
+        None
+    };
+    
+    if current_route_group.is_none() {
+        return; // Not in a route group
+    }
+    
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    let allowed_paths: Vec<&str> = config
+        .rules
+        .bassist_domain_isolation
+        .options
+        .bassist
+        .cross_domain_allowed_paths
+        .iter()
+        .map(|s| s.as_str())
+        .collect();
+    
+    // Check for imports from other route groups
+    let import_pattern = r#"from\s+['"]@/app/\(([^)]+)\)/(lib|components|schemas|[^'"]+)['"]"#;
+    if let Ok(re) = Regex::new(import_pattern) {
+        for cap in re.captures_iter(&content) {
+            if let (Some(imported_group), Some(imported_path)) = (cap.get(1), cap.get(2)) {
+                let imported_group_str = imported_group.as_str();
+                let imported_path_str = imported_path.as_str();
+                
+                // Check if importing from a different route group
+                if Some(imported_group_str) != current_route_group {
+                    // Check if the imported path is allowed
+                    let is_allowed = allowed_paths.iter().any(|allowed| {
+                        imported_path_str == *allowed || imported_path_str.starts_with(&format!("{}/", allowed))
+                    });
+                    
+                    if !is_allowed {
+                        diagnostics.add(Diagnostic {
+                            severity: config.rules.bassist_domain_isolation.severity,
+                            rule: "bassist-domain-isolation".to_string(),
+                            message: format!(
+                                "Cross-domain import from '({})' detected. Domains should not import '{}' from sibling domains. Consider moving shared code to root /lib or /components, or configure allowed paths.",
+                                imported_group_str, imported_path_str
+                            ),
+                            file: path.to_path_buf(),
+                            line: None,
+                            project: None,
+                            tokens: Vec::new(),
+                        });
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Check i18n namespace conventions
+pub fn check_bassist_i18n_namespaces(
+    path: &Path,
+    config: &Config,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    // Read file content
+    let content = match fs::read_to_string(path) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    
+    // Check for useExtracted or getExtracted calls
     let pattern = r#"(use|get)Extracted\s*\(\s*['"]([^'"]+)['"]"#;
     if let Ok(re) = Regex::new(pattern) {
         for cap in re.captures_iter(&content) {
@@ -897,347 +5531,3458 @@ pub fn check_bassist_i18n_namespaces(
                         ),
                         file: path.to_path_buf(),
                         line: None,
+                        project: None,
+                        tokens: Vec::new(),
                     });
                 }
             }
         }
     }
-}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{Config, FilenameStyle};
+    use std::fs;
+    use std::io::Write;
+
+    fn create_temp_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    fn get_test_config() -> Config {
+        Config::default()
+    }
+
+    #[test]
+    fn test_is_kebab_case() {
+        assert!(is_kebab_case("my-component"));
+        assert!(is_kebab_case("button"));
+        assert!(is_kebab_case("my-long-component-name"));
+        assert!(is_kebab_case("component123"));
+        assert!(is_kebab_case("component-123"));
+        
+        assert!(!is_kebab_case("MyComponent"));
+        assert!(!is_kebab_case("my_component"));
+        assert!(!is_kebab_case("myComponent"));
+        assert!(!is_kebab_case("My-Component"));
+        assert!(!is_kebab_case("-my-component"));
+        assert!(!is_kebab_case("my-component-"));
+    }
+
+    #[test]
+    fn test_is_camel_case() {
+        assert!(is_camel_case("myComponent"));
+        assert!(is_camel_case("myLongComponentName"));
+        assert!(is_camel_case("component123Name"));
+        
+        assert!(!is_camel_case("MyComponent"));
+        assert!(!is_camel_case("my-component"));
+        assert!(!is_camel_case("my_component"));
+        assert!(!is_camel_case("component"));
+        assert!(!is_camel_case("COMPONENT"));
+    }
+
+    #[test]
+    fn test_is_pascal_case() {
+        assert!(is_pascal_case("MyComponent"));
+        assert!(is_pascal_case("Button"));
+        assert!(is_pascal_case("MyLongComponentName"));
+        assert!(is_pascal_case("Component123"));
+        
+        assert!(!is_pascal_case("myComponent"));
+        assert!(!is_pascal_case("my-component"));
+        assert!(!is_pascal_case("my_component"));
+        assert!(!is_pascal_case("COMPONENT"));
+    }
+
+    #[test]
+    fn test_is_snake_case() {
+        assert!(is_snake_case("my_component"));
+        assert!(is_snake_case("button"));
+        assert!(is_snake_case("my_long_component_name"));
+        assert!(is_snake_case("component_123"));
+        
+        assert!(!is_snake_case("MyComponent"));
+        assert!(!is_snake_case("my-component"));
+        assert!(!is_snake_case("myComponent"));
+        assert!(!is_snake_case("_my_component"));
+        assert!(!is_snake_case("my_component_"));
+    }
+
+    #[test]
+    fn test_server_side_exports_in_client_component() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-server-exports");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("MyComponent.tsx");
+        let content = r#"
+'use client'
+
+export function MyComponent() {
+    return <div>Hello</div>;
+}
+
+export async function getServerSideProps() {
+    return { props: {} };
+}
+"#;
+        create_temp_file(&file_path, content);
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_server_side_exports(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert!(diagnostics.diagnostics[0].message.contains("getServerSideProps"));
+        assert_eq!(diagnostics.diagnostics[0].rule, "server-side-exports");
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_server_side_exports_all_patterns() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-server-exports-all");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("Legacy.tsx");
+        let content = r#"'use client'
+
+export const getServerSideProps = async () => ({ props: {} });
+export function getStaticProps() {}
+export async function getStaticPaths() {}
+export const getInitialProps = () => ({});
+"#;
+        create_temp_file(&file_path, content);
+
+        let config = get_test_config();
+        // Run twice: the shared patterns must not carry state between files
+        for _ in 0..2 {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_server_side_exports(&file_path, &config, &mut diagnostics);
+
+            let messages: Vec<&str> =
+                diagnostics.diagnostics.iter().map(|d| d.message.as_str()).collect();
+            assert_eq!(messages.len(), 4);
+            for export in ["getServerSideProps", "getStaticProps", "getStaticPaths", "getInitialProps"] {
+                assert!(messages.iter().any(|m| m.contains(export)), "missing {}", export);
+            }
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_server_side_exports_without_use_client() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-use-client");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("page.tsx");
+        let content = r#"
+export async function getServerSideProps() {
+    return { props: {} };
+}
+
+export default function Page() {
+    return <div>Hello</div>;
+}
+"#;
+        create_temp_file(&file_path, content);
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_server_side_exports(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_multiple_server_exports_detected() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-multiple-exports");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("Component.tsx");
+        let content = r#"
+"use client"
+
+export async function getServerSideProps() {}
+export const getStaticProps = async () => {};
+export function getStaticPaths() {}
+"#;
+        create_temp_file(&file_path, content);
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_server_side_exports(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 3);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_count_depth_ignores_route_groups_and_slots() {
+        assert_eq!(count_depth("about/page.tsx"), 2);
+        assert_eq!(count_depth("(marketing)/about/page.tsx"), 2);
+        assert_eq!(count_depth("a/b/about/page.tsx"), 4);
+        assert_eq!(count_depth("dashboard/@modal/settings/page.tsx"), 3);
+        assert_eq!(count_depth("(shop)/(checkout)/cart/page.tsx"), 2);
+        assert_eq!(count_depth("feed/(..)photo/[id]/page.tsx"), 3);
+        assert_eq!(count_depth("feed/@modal/(.)photo/[id]/page.tsx"), 3);
+        assert_eq!(count_depth("(...)login/page.tsx"), 1);
+    }
+
+    #[test]
+    fn test_component_nesting_depth_route_groups() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-groups");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("app/(marketing)/(landing)/about/page.tsx");
+        create_temp_file(&file_path, "export default function Page() {}");
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_component_nesting_depth_within_limit() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-ok");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("app/components/Button.tsx");
+        create_temp_file(&file_path, "export function Button() {}");
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_component_nesting_depth_exceeds_limit() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-deep");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("app/components/ui/buttons/primary/Button.tsx");
+        create_temp_file(&file_path, "export function Button() {}");
+        
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        
+        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "component-nesting-depth");
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_component_nesting_depth_custom_limit() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-custom");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let file_path = temp_dir.join("app/components/ui/Button.tsx");
+        create_temp_file(&file_path, "export function Button() {}");
+        
+        let mut config = get_test_config();
+        config.rules.component_nesting_depth.options.max_nesting_depth = 2;
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
+        
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_kebab_case() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-kebab");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let good_file = temp_dir.join("my-component.tsx");
+        create_temp_file(&good_file, "export function MyComponent() {}");
+        
+        let bad_file = temp_dir.join("MyComponent.tsx");
+        create_temp_file(&bad_file, "export function MyComponent() {}");
+        
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::KebabCase;
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &good_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "filename-style");
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_pascal_case() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-pascal");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let good_file = temp_dir.join("MyComponent.tsx");
+        create_temp_file(&good_file, "export function MyComponent() {}");
+        
+        let bad_file = temp_dir.join("my-component.tsx");
+        create_temp_file(&bad_file, "export function MyComponent() {}");
+        
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::PascalCase;
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &good_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_camel_case() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-camel");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let good_file = temp_dir.join("myComponent.tsx");
+        create_temp_file(&good_file, "export function MyComponent() {}");
+        
+        let bad_file = temp_dir.join("MyComponent.tsx");
+        create_temp_file(&bad_file, "export function MyComponent() {}");
+        
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::CamelCase;
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &good_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_snake_case() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-snake");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let good_file = temp_dir.join("my_component.tsx");
+        create_temp_file(&good_file, "export function MyComponent() {}");
+        
+        let bad_file = temp_dir.join("MyComponent.tsx");
+        create_temp_file(&bad_file, "export function MyComponent() {}");
+        
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::SnakeCase;
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &good_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+        
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_special_files_skipped() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-special");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let special_files = vec!["page.tsx", "layout.tsx", "loading.tsx", "error.tsx"];
+        
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::PascalCase;
+        
+        for filename in special_files {
+            let file_path = temp_dir.join(filename);
+            create_temp_file(&file_path, "export default function Page() {}");
+            
+            let mut diagnostics = DiagnosticCollection::new();
+            check_filename_style(&temp_dir, &file_path, &config, &mut diagnostics);
+            assert_eq!(diagnostics.diagnostics.len(), 0, "Special file {} should be skipped", filename);
+        }
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_suggest_asset_name() {
+        assert_eq!(suggest_asset_name("Hero Image Final (2).png", &FilenameStyle::KebabCase), "hero-image-final-2.png");
+        assert_eq!(suggest_asset_name("logo.dark.svg", &FilenameStyle::KebabCase), "logo.dark.svg");
+        assert_eq!(suggest_asset_name("myLogo.PNG", &FilenameStyle::KebabCase), "my-logo.png");
+        assert_eq!(suggest_asset_name("hero image.png", &FilenameStyle::SnakeCase), "hero_image.png");
+        assert_eq!(suggest_asset_name(".well-known", &FilenameStyle::KebabCase), ".well-known");
+    }
+
+    #[test]
+    fn test_public_asset_naming() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-public-assets");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let good_file = temp_dir.join("public/images/hero.2x.png");
+        let bad_file = temp_dir.join("public/images/Hero Image Final (2).png");
+        let bad_dir_file = temp_dir.join("public/My Icons/icon.svg");
+        let verification_file = temp_dir.join("public/google1234ABCD.html");
+        for file in [&good_file, &bad_file, &bad_dir_file, &verification_file] {
+            create_temp_file(file, "");
+        }
+
+        let config = get_test_config();
+        let public_files = vec![good_file, bad_file.clone(), bad_dir_file, verification_file];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_public_asset_naming(&temp_dir, &public_files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "public-asset-naming");
+        assert_eq!(diagnostics.diagnostics[0].file, bad_file);
+        assert!(diagnostics.diagnostics[0].message.contains("spaces, uppercase letters, parentheses"));
+        assert!(diagnostics.diagnostics[0].message.contains("hero-image-final-2.png"));
+        assert!(diagnostics.diagnostics[1].message.contains("directory name 'My Icons'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_fetch_cache_option() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-fetch-cache");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("app/page.tsx");
+        let content = r#"
+export default async function Page() {
+    const cached = await fetch('/api/a', { cache: 'force-cache' });
+    const revalidated = await fetch('/api/b', { next: { revalidate: 60 } });
+    const uncached = await fetch('/api/c');
+    const withHeaders = await fetch('/api/d', { headers: {} });
+    return null;
+}
+"#;
+        create_temp_file(&file_path, content);
+
+        let mut config = get_test_config();
+        config.rules.fetch_cache_option.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_fetch_cache_option(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "fetch-cache-option");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(5));
+        assert_eq!(diagnostics.diagnostics[1].line, Some(6));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_fetch_cache_option_skips_client_components() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-fetch-cache-client");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("app/form.tsx");
+        create_temp_file(&file_path, "'use client'\nexport function Form() { fetch('/api'); }");
+
+        let mut config = get_test_config();
+        config.rules.fetch_cache_option.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_fetch_cache_option(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_server_only_next_apis_in_client() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-server-only-next-apis");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let bad_file = temp_dir.join("app/user-menu.tsx");
+        let content = r#"'use client'
+import Link from 'next/link';
+import { cookies } from 'next/headers';
+import { useRouter, redirect } from 'next/navigation';
+"#;
+        create_temp_file(&bad_file, content);
+
+        let good_file = temp_dir.join("app/nav.tsx");
+        let content = r#"'use client'
+import Link from 'next/link';
+import { useRouter } from 'next/navigation';
+"#;
+        create_temp_file(&good_file, content);
+
+        let config = get_test_config();
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_server_only_next_apis_in_client(&temp_dir, &bad_file, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-server-only-next-apis-in-client");
+        assert!(diagnostics.diagnostics[0].message.contains("next/headers"));
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+        assert!(diagnostics.diagnostics[1].message.contains("'redirect'"));
+        assert_eq!(diagnostics.diagnostics[1].line, Some(4));
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_server_only_next_apis_in_client(&temp_dir, &good_file, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_server_only_next_apis_allowed_in_server_component() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-server-only-next-apis-server");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("app/page.tsx");
+        create_temp_file(&file_path, "import { cookies } from 'next/headers';\n");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_server_only_next_apis_in_client(&temp_dir, &file_path, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_server_only_modules_in_client() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-server-only-modules");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let client = temp_dir.join("app/upload.tsx");
+        let content = r#"'use client'
+import 'server-only';
+import { readFile } from 'node:fs/promises';
+const cp = require('child_process');
+import { db } from '@/lib/db';
+import { getUser } from '../lib/users';
+import { save } from './actions';
+import { join } from 'path-browserify';
+"#;
+        create_temp_file(&client, content);
+        create_temp_file(&temp_dir.join("lib/users.ts"), "import 'server-only';\nexport const getUser = () => null;\n");
+        create_temp_file(&temp_dir.join("lib/db.ts"), "export const db = {};\n");
+        create_temp_file(
+            &temp_dir.join("app/actions.ts"),
+            "'use server'\nimport fs from 'fs';\nexport async function save() {}\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.no_server_only_next_apis_in_client.options.forbidden_client_imports.extend([
+            "child_process".to_string(),
+            "@/lib/db".to_string(),
+        ]);
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_server_only_next_apis_in_client(&temp_dir, &client, &PathAliases::default(), &config, &mut diagnostics);
+        let found: Vec<(Option<usize>, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(2), "Client component imports server-only module 'server-only'"),
+                (Some(3), "Client component imports server-only module 'node:fs/promises'"),
+                (Some(4), "Client component imports server-only module 'child_process'"),
+                (Some(5), "Client component imports server-only module '@/lib/db'"),
+                (
+                    Some(6),
+                    "Client component imports '../lib/users', which imports server-only module 'server-only'"
+                ),
+            ]
+        );
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_legacy_special_files_in_app() {
+        let mut config = get_test_config();
+        config.rules.legacy_special_files_in_app.severity = crate::config::Severity::Error;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_legacy_special_files_in_app(Path::new("/project/pages/_app.tsx"), &config, &mut diagnostics);
+        check_legacy_special_files_in_app(Path::new("/project/app/about/page.tsx"), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        check_legacy_special_files_in_app(Path::new("/project/app/_app.tsx"), &config, &mut diagnostics);
+        check_legacy_special_files_in_app(Path::new("/project/app/_document.jsx"), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "legacy-special-files-in-app");
+    }
+
+    #[test]
+    fn test_router_migration_boundaries() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-router-migration");
+        fs::remove_dir_all(&temp_dir).ok();
+        fs::create_dir_all(&temp_dir).ok();
+
+        let app_page = temp_dir.join("app/dashboard/page.tsx");
+        create_temp_file(&app_page, "import { legacy } from '@/pages/legacy/util';\nimport { db } from '@/lib/db';");
+        let pages_index = temp_dir.join("pages/index.tsx");
+        create_temp_file(&pages_index, "import { Bridge } from '../app/bridge';\nimport { Card } from '../app/dashboard/card';");
+        let pages_util = temp_dir.join("pages/legacy/util.ts");
+        create_temp_file(&pages_util, "export const legacy = 1;");
+        let bridge = temp_dir.join("app/bridge.tsx");
+        create_temp_file(&bridge, "export const Bridge = () => null;");
+        let card = temp_dir.join("app/dashboard/card.tsx");
+        create_temp_file(&card, "export const Card = () => null;");
+        let db = temp_dir.join("lib/db.ts");
+        create_temp_file(&db, "export const db = {};");
+
+        let mut config = get_test_config();
+        config.rules.router_migration_boundaries.options.pages_may_import_from_app = vec!["app/bridge.tsx".to_string()];
+
+        let all_files = vec![app_page.clone(), pages_index.clone(), pages_util, bridge, card, db];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_router_migration_boundaries(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "router-migration-boundaries");
+        assert_eq!(diagnostics.diagnostics[0].file, app_page);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0].message.contains("app_may_import_from_pages"));
+        assert_eq!(diagnostics.diagnostics[1].file, pages_index);
+        assert_eq!(diagnostics.diagnostics[1].line, Some(2));
+        assert!(diagnostics.diagnostics[1].message.contains("app/dashboard/card.tsx"));
+
+        // Only one direction can be enforced
+        config.rules.router_migration_boundaries.options.direction = crate::config::RouterDirection::AppToPages;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_router_migration_boundaries(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, app_page);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_router_migration_boundaries_relative_imports() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-router-migration-relative");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let page = temp_dir.join("app/page.tsx");
+        create_temp_file(
+            &page,
+            "import { Header } from './header';\nimport { foo } from '../pages/foo';\n",
+        );
+        let header = temp_dir.join("app/header.tsx");
+        create_temp_file(&header, "export function Header() {}\n");
+        let foo = temp_dir.join("pages/foo.ts");
+        create_temp_file(&foo, "export const foo = 1;\n");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_router_migration_boundaries(
+            &temp_dir,
+            &[page.clone(), header.clone(), foo],
+            &PathAliases::default(),
+            &config,
+            &mut diagnostics,
+        );
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, page);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+        assert!(diagnostics.diagnostics[0].message.contains("'pages/foo.ts'"));
+
+        // Imports within app/ are fine
+        let mut diagnostics = DiagnosticCollection::new();
+        check_router_migration_boundaries(&temp_dir, &[header], &PathAliases::default(), &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_router_migration_boundaries_without_pages() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-router-migration-done");
+        fs::remove_dir_all(&temp_dir).ok();
+        fs::create_dir_all(&temp_dir).ok();
+
+        let app_page = temp_dir.join("app/page.tsx");
+        create_temp_file(&app_page, "import { legacy } from '@/pages/legacy/util';");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_router_migration_boundaries(&temp_dir, &[app_page], &PathAliases::default(), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_focused_tests() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-focused-tests");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("button.test.tsx");
+        let content = r#"describe('button', () => {
+    it('renders', () => {});
+    it.only('clicks', () => {});
+    xit('hovers', () => {});
+    describe.skip('disabled', () => {});
+});
+"#;
+        create_temp_file(&file_path, content);
+
+        let mut config = get_test_config();
+        config.rules.no_focused_tests.severity = crate::config::Severity::Error;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_focused_tests(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 3);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-focused-tests");
+        assert!(diagnostics.diagnostics[0].message.contains("it.only"));
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+        assert_eq!(diagnostics.diagnostics[1].line, Some(4));
+        assert_eq!(diagnostics.diagnostics[2].line, Some(5));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_focused_tests_ignores_regular_tests() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-focused-tests-ok");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("button.spec.ts");
+        create_temp_file(
+            &file_path,
+            "it('renders', () => { exit(0); });\ntest('works', () => {});\nit('zooms', () => { map.fit(bounds); foo.xit(1); });\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.no_focused_tests.severity = crate::config::Severity::Error;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_focused_tests(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_single_style_file() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-single-style-file");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let button = temp_dir.join("components/Button.tsx");
+        create_temp_file(&button, "export function Button() {}");
+        create_temp_file(&temp_dir.join("components/Button.module.css"), "");
+        create_temp_file(&temp_dir.join("components/Button.module.scss"), "");
+        create_temp_file(&temp_dir.join("components/Button.test.tsx"), "");
+
+        let mut config = get_test_config();
+        config.rules.single_style_file.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_single_style_file(&temp_dir, std::slice::from_ref(&button), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "single-style-file");
+        assert_eq!(diagnostics.diagnostics[0].file, button);
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .contains("Button.module.css, Button.module.scss"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_single_style_file_one_style() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-single-style-file-ok");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let button = temp_dir.join("components/Button.tsx");
+        create_temp_file(&button, "export function Button() {}");
+        create_temp_file(&temp_dir.join("components/Button.module.css"), "");
+        create_temp_file(&temp_dir.join("components/Card.module.scss"), "");
+
+        let mut config = get_test_config();
+        config.rules.single_style_file.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_single_style_file(&temp_dir, &[button], &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_hook_file_naming_mismatch() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-hook-file-naming");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("hooks/auth.ts");
+        create_temp_file(
+            &file_path,
+            "import { useContext } from 'react';\n\nexport function useAuth() {\n  return useContext(AuthContext);\n}\n",
+        );
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_hook_file_naming(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "hook-file-naming");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+        assert!(diagnostics.diagnostics[0].message.contains("'useAuth'"));
+        assert!(diagnostics.diagnostics[0].message.contains("'use-auth.ts'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_hook_file_naming_provider_module() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-hook-file-naming-provider");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let provider = temp_dir.join("components/auth-provider.tsx");
+        create_temp_file(
+            &provider,
+            "'use client'\nconst AuthContext = createContext(null);\n\nexport function AuthProvider({ children }) {\n  return <AuthContext.Provider value={null}>{children}</AuthContext.Provider>;\n}\n\nexport function useAuth() {\n  return useContext(AuthContext);\n}\n",
+        );
+        let default_provider = temp_dir.join("components/theme.tsx");
+        create_temp_file(
+            &default_provider,
+            "export const useTheme = () => useContext(ThemeContext);\nexport default function ThemeProvider({ children }) { return children; }\n",
+        );
+        // Only hooks: still named after the first one
+        let hooks = temp_dir.join("hooks/session.ts");
+        create_temp_file(
+            &hooks,
+            "export function useSession() {}\nexport function useUser() {}\nexport type Session = {};\n",
+        );
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_hook_file_naming(&provider, &config, &mut diagnostics);
+        check_hook_file_naming(&default_provider, &config, &mut diagnostics);
+        check_hook_file_naming(&hooks, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, hooks);
+        assert!(diagnostics.diagnostics[0].message.contains("'use-session.ts'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_hook_file_naming_match() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-hook-file-naming-ok");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let hook = "export const useAuth = () => useContext(AuthContext);\n";
+        let camel = temp_dir.join("hooks/useAuth.ts");
+        let kebab = temp_dir.join("hooks/use-auth.ts");
+        let test = temp_dir.join("hooks/useAuth.test.ts");
+        create_temp_file(&camel, hook);
+        create_temp_file(&kebab, hook);
+        create_temp_file(&test, "test('useAuth', () => {});\n");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_hook_file_naming(&camel, &config, &mut diagnostics);
+        check_hook_file_naming(&kebab, &config, &mut diagnostics);
+        check_hook_file_naming(&test, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_hook_file_naming_missing_export() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-hook-file-naming-export");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("hooks/useAuth.ts");
+        create_temp_file(&file_path, "export function getSession() {}\n");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_hook_file_naming(&file_path, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .contains("does not export 'useAuth'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_catch_all_api_routes() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-catch-all-api-routes");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let proxy = temp_dir.join("app/api/[...path]/route.ts");
+        let optional = temp_dir.join("src/app/api/files/[[...slug]]/route.ts");
+        let pages = temp_dir.join("pages/api/[...all].ts");
+        let regular = temp_dir.join("app/api/users/[id]/route.ts");
+        let page = temp_dir.join("app/docs/[...slug]/page.tsx");
+        // NextAuth's handlers are allowed by default
+        let nextauth = temp_dir.join("app/api/auth/[...nextauth]/route.ts");
+        let src_nextauth = temp_dir.join("src/app/api/auth/[...nextauth]/route.js");
+        let pages_nextauth = temp_dir.join("pages/api/auth/[...nextauth].ts");
+        for file in [&proxy, &optional, &pages, &regular, &page, &nextauth, &src_nextauth, &pages_nextauth] {
+            create_temp_file(file, "export async function GET() {}");
+        }
+
+        let config = get_test_config();
+        let all_files = vec![
+            proxy.clone(),
+            optional.clone(),
+            pages.clone(),
+            regular,
+            page,
+            nextauth,
+            src_nextauth,
+            pages_nextauth,
+        ];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_catch_all_api_routes(&temp_dir, &all_files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 3);
+        assert_eq!(diagnostics.diagnostics[0].rule, "catch-all-api-routes");
+        assert_eq!(diagnostics.diagnostics[0].file, proxy);
+        assert!(diagnostics.diagnostics[0].message.contains("allowed_catch_all_routes"));
+        assert_eq!(diagnostics.diagnostics[1].file, optional);
+        assert!(diagnostics.diagnostics[1].message.contains("app/api/files/[[...slug]]/route.ts"));
+        assert_eq!(diagnostics.diagnostics[2].file, pages);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_catch_all_api_routes_allowlisted() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-catch-all-api-routes-allow");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let guarded = temp_dir.join("app/api/proxy/[...path]/route.ts");
+        let unguarded = temp_dir.join("app/api/webhooks/[...path]/route.ts");
+        create_temp_file(
+            &guarded,
+            "import { requireSession } from '@/lib/auth';\nexport async function GET() { await requireSession(); }",
+        );
+        create_temp_file(&unguarded, "export async function POST() {}");
+
+        let mut config = get_test_config();
+        config.rules.catch_all_api_routes.options.allowed_catch_all_routes =
+            vec!["app/api/proxy/**".to_string(), "app/api/webhooks/**".to_string()];
+        config.rules.catch_all_api_routes.options.required_content_patterns =
+            vec![r"requireSession\(".to_string()].try_into().unwrap();
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_catch_all_api_routes(&temp_dir, &[guarded, unguarded.clone()], &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, unguarded);
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .contains("does not match required content pattern 'requireSession\\('"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_require_memo() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-require-memo");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let memoized = temp_dir.join("components/rows/UserRow.tsx");
+        create_temp_file(
+            &memoized,
+            r#"import { memo } from 'react';
+
+export const UserRow = memo(function UserRow({ user }: Props) {
+  return <tr>{user.name}</tr>;
+});
+
+const Cell = ({ value }: CellProps) => <td>{value}</td>;
+const MemoCell = React.memo(Cell);
+export default MemoCell;
+"#,
+        );
+
+        let plain = temp_dir.join("components/rows/OrderRow.tsx");
+        create_temp_file(
+            &plain,
+            r#"export const OrderRow = ({ order }: Props) => <tr>{order.id}</tr>;
+
+export const OrderContext = createContext(null);
+
+export function OrderTotal() {
+  return <td />;
+}
+"#,
+        );
+
+        let outside = temp_dir.join("components/Header.tsx");
+        create_temp_file(&outside, "export function Header() { return <header />; }");
+
+        let mut config = get_test_config();
+        config.rules.require_memo.severity = crate::config::Severity::Warn;
+        config.rules.require_memo.options.memo_glob = Some("components/rows/**".to_string());
+
+        let all_files = vec![memoized, plain.clone(), outside];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_require_memo(&temp_dir, &all_files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "require-memo");
+        assert_eq!(diagnostics.diagnostics[0].file, plain);
+        assert!(diagnostics.diagnostics[0].message.contains("'OrderRow'"));
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[1].message.contains("'OrderTotal'"));
+        assert_eq!(diagnostics.diagnostics[1].line, Some(5));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_file_header() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-file-header");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let with_header = temp_dir.join("app/page.tsx");
+        create_temp_file(
+            &with_header,
+            "'use client';\n\n// Copyright (c) 2026 Acme Corp. All rights reserved.\nexport default function Page() {}\n",
+        );
+        let missing = temp_dir.join("lib/db.ts");
+        create_temp_file(&missing, "#!/usr/bin/env node\nexport const db = {};\n");
+        let wrong = temp_dir.join("lib/auth.ts");
+        create_temp_file(&wrong, "// TODO: auth\nexport const auth = {};\n");
+        let outside = temp_dir.join("components/Button.tsx");
+        create_temp_file(&outside, "export function Button() {}\n");
+
+        let mut config = get_test_config();
+        config.rules.file_header.severity = crate::config::Severity::Error;
+        config.rules.file_header.options.header_pattern =
+            Some(r"Copyright \(c\) \d{4} Acme Corp".to_string().try_into().unwrap());
+
+        let all_files = vec![with_header, missing.clone(), wrong.clone(), outside];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_header(&temp_dir, &all_files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "file-header");
+        assert_eq!(diagnostics.diagnostics[0].file, missing);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0].message.starts_with("Missing file header"));
+        assert_eq!(diagnostics.diagnostics[1].file, wrong);
+        assert!(diagnostics.diagnostics[1].message.starts_with("File header does not match"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_fix_file_header() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-file-header-fix");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file = temp_dir.join("app/actions.ts");
+        create_temp_file(&file, "'use server';\n\nexport async function save() {}\n");
+
+        let mut config = get_test_config();
+        config.rules.file_header.severity = crate::config::Severity::Error;
+        config.rules.file_header.options.header_template =
+            Some("// SPDX-License-Identifier: MIT\n".to_string());
+
+        let all_files = vec![file.clone()];
+        assert_eq!(fix_file_header(&temp_dir, &all_files, &config), 1);
+        assert_eq!(
+            fs::read_to_string(&file).unwrap(),
+            "'use server';\n\n// SPDX-License-Identifier: MIT\nexport async function save() {}\n"
+        );
+
+        // A second run has nothing left to fix
+        assert_eq!(fix_file_header(&temp_dir, &all_files, &config), 0);
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_header(&temp_dir, &all_files, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_next_head_in_app() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-next-head");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let content = "import Head from 'next/head';\n\nexport default function Page() {}\n";
+        let app_page = temp_dir.join("app/about/page.tsx");
+        let pages_page = temp_dir.join("pages/about.tsx");
+        create_temp_file(&app_page, content);
+        create_temp_file(&pages_page, content);
+
+        let mut config = get_test_config();
+        config.rules.no_next_head_in_app.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_next_head_in_app(&app_page, &config, &mut diagnostics);
+        check_no_next_head_in_app(&pages_page, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-next-head-in-app");
+        assert_eq!(diagnostics.diagnostics[0].file, app_page);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_import_style_prefers_alias() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-import-style-alias");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file = temp_dir.join("app/blog/[slug]/page.tsx");
+        create_temp_file(
+            &file,
+            "import { Card } from '../../components/card';\nimport { getPost } from '../../../lib/x';\n",
+        );
+
+        let config = get_test_config();
+
+        // Without a configured alias there is nothing to suggest
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_style_consistency(&temp_dir, std::slice::from_ref(&file), &PathAliases::default(), &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        create_temp_file(&temp_dir.join("tsconfig.json"), r#"{ "compilerOptions": { "paths": { "@/*": ["./*"] } } }"#);
+        let aliases = PathAliases::load(&temp_dir);
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_style_consistency(&temp_dir, std::slice::from_ref(&file), &aliases, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "import-style-consistency");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+        assert!(diagnostics.diagnostics[0].message.contains("use '@/lib/x'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_import_style_suggests_configured_alias() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-import-style-src-alias");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("tsconfig.json"), r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"] } } }"#);
+        let file = temp_dir.join("src/app/blog/[slug]/page.tsx");
+        create_temp_file(
+            &file,
+            "import { util } from '../../../lib/util';\nimport seed from '../../../../scripts/seed';\n",
+        );
+
+        let config = get_test_config();
+        let aliases = PathAliases::load(&temp_dir);
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_style_consistency(&temp_dir, std::slice::from_ref(&file), &aliases, &config, &mut diagnostics);
+
+        // scripts/ is outside src/, so no alias covers it
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .ends_with("climbs 3 parent directories; use '~/lib/util' instead"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_import_style_prefers_relative() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-import-style-relative");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file = temp_dir.join("app/blog/page.tsx");
+        create_temp_file(
+            &file,
+            "import { Card } from '@/app/blog/card';\nimport { db } from '@/lib/db/client';\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.import_style_consistency.options.prefer = crate::config::ImportStyle::Relative;
+        config.rules.import_style_consistency.options.max_parent_levels = 1;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_style_consistency(&temp_dir, std::slice::from_ref(&file), &PathAliases::default(), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0].message.contains("use './card'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_nested_package_json() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-nested-package-json");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let root = temp_dir.join("package.json");
+        create_temp_file(&root, r#"{ "name": "monorepo", "workspaces": ["lib/*"] }"#);
+        let workspace = temp_dir.join("lib/ui/package.json");
+        create_temp_file(&workspace, r#"{ "name": "@acme/ui" }"#);
+        let in_workspace = temp_dir.join("lib/ui/experiments/package.json");
+        create_temp_file(&in_workspace, r#"{ "type": "module" }"#);
+        let in_app = temp_dir.join("app/legacy/package.json");
+        create_temp_file(&in_app, r#"{ "type": "module" }"#);
+        let tooling = temp_dir.join("scripts/package.json");
+        create_temp_file(&tooling, "{}");
+
+        let config = get_test_config();
+        let files = vec![root, workspace, in_workspace.clone(), in_app.clone(), tooling];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_nested_package_json(&temp_dir, &files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-nested-package-json");
+        assert_eq!(diagnostics.diagnostics[0].file, in_workspace);
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .contains("the workspace package at 'lib/ui'"));
+        assert_eq!(diagnostics.diagnostics[1].file, in_app);
+        assert!(diagnostics.diagnostics[1].message.contains("the root package.json"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_route_explicit_status() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-route-explicit-status");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let route = temp_dir.join("app/api/posts/route.ts");
+        create_temp_file(
+            &route,
+            r#"import { NextResponse } from 'next/server';
+
+export async function GET() {
+  return NextResponse.json({ posts: [] });
+}
+
+export async function POST() {
+  return NextResponse.json({ created: true }, { status: 201 });
+}
+
+export async function DELETE() {
+  return new Response(null, { headers: { 'x-deleted': '1' } });
+}
+
+export async function PUT() {
+  return new Response('ok', init);
+}
+"#,
+        );
+
+        let mut config = get_test_config();
+        config.rules.route_explicit_status.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_route_explicit_status(&route, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "route-explicit-status");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(4));
+        assert!(diagnostics.diagnostics[0].message.starts_with("NextResponse.json"));
+        assert_eq!(diagnostics.diagnostics[1].line, Some(12));
+        assert!(diagnostics.diagnostics[1].message.starts_with("new Response"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_feature_ownership() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-feature-ownership");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join(".github/CODEOWNERS"),
+            "# Owners\n* @acme/everyone\n/app/blog/ @acme/content\n/app/shop/ \n",
+        );
+        let blog = temp_dir.join("app/blog/page.tsx");
+        let shop = temp_dir.join("app/shop/page.tsx");
+        let docs = temp_dir.join("app/docs/page.tsx");
+        let layout = temp_dir.join("app/layout.tsx");
+        create_temp_file(&blog, "export default function Blog() {}");
+        create_temp_file(&shop, "export default function Shop() {}");
+        create_temp_file(&docs, "export default function Docs() {}");
+        create_temp_file(&layout, "export default function Layout() {}");
+        create_temp_file(&temp_dir.join("app/docs/OWNERS"), "@acme/docs\n");
+
+        let mut config = get_test_config();
+        config.rules.feature_ownership.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_feature_ownership(&temp_dir, &[blog, shop, docs, layout], &config, &mut diagnostics);
+
+        // `/app/shop/` without owners removes the catch-all ownership
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "feature-ownership");
+        assert_eq!(diagnostics.diagnostics[0].file, temp_dir.join("app/shop"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_codeowners_covers() {
+        let feature = Path::new("app/blog");
+        assert!(codeowners_covers("/app/ @web", feature));
+        assert!(codeowners_covers("app/* @web", feature));
+        assert!(codeowners_covers("blog/ @content", feature));
+        assert!(codeowners_covers("/app/blog @content", feature));
+        assert!(!codeowners_covers("*.tsx @web", feature));
+        assert!(!codeowners_covers("/app/blog/**/page.tsx @web", feature));
+        assert!(!codeowners_covers("/app/shop/ @shop", feature));
+    }
+
+    #[test]
+    fn test_filename_style_ignored_filenames() {
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::KebabCase;
+        config.rules.filename_style.options.ignored_filenames =
+            vec!["mdx-components".to_string(), "*.d".to_string(), "*.Generated".to_string()];
+
+        let check = |config: &Config, name: &str| {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_filename_style(Path::new(""), Path::new(name), config, &mut diagnostics);
+            diagnostics.diagnostics.len()
+        };
+
+        // Extra entries are added to the built-in list
+        assert_eq!(check(&config, "app/page.tsx"), 0);
+        assert_eq!(check(&config, "env.d.ts"), 0);
+        assert_eq!(check(&config, "lib/Schema.Generated.ts"), 0);
+        assert_eq!(check(&config, "lib/Schema.ts"), 1);
+
+        // With override, only the configured entries are skipped
+        config.rules.filename_style.options.override_ignored_filenames = true;
+        assert_eq!(check(&config, "next.config.mjs"), 1);
+        assert_eq!(check(&config, "env.d.ts"), 0);
+    }
+
+    #[test]
+    fn test_default_export_name_match() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-default-export-name");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let config = get_test_config();
+        let check = |name: &str, content: &str| {
+            let file = temp_dir.join(name).join("Button.tsx");
+            create_temp_file(&file, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_default_export_name_match(&file, &config, &mut diagnostics);
+            diagnostics.diagnostics
+        };
+
+        let mismatch = check("mismatch", "import React from 'react';\n\nexport default function Btn() {}\n");
+        assert_eq!(mismatch.len(), 1);
+        assert_eq!(mismatch[0].rule, "default-export-name-match");
+        assert_eq!(mismatch[0].line, Some(3));
+        assert!(mismatch[0].message.contains("'Btn'") && mismatch[0].message.contains("'Button'"));
+
+        assert!(check("match", "export default function Button() {}\n").is_empty());
+        assert!(check("identifier", "const Button = () => null;\nexport default Button;\n").is_empty());
+        assert_eq!(check("class", "export default class Btn extends Component {}\n").len(), 1);
+        assert!(check("reexport", "function Button() {}\nexport { Button as default };\n").is_empty());
+        assert!(check("wrapped", "export default memo(Btn);\n").is_empty());
+
+        let anonymous = check("anonymous", "export default () => <button />;\n");
+        assert_eq!(anonymous.len(), 1);
+        assert_eq!(
+            anonymous[0].message,
+            "Default export is anonymous; name it 'Button' to match the filename"
+        );
+        assert_eq!(check("anonymous-function", "export default function () {}\n").len(), 1);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_export_placement_bottom() {
+        use crate::config::ExportStyle;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-export-placement-bottom");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file = temp_dir.join("lib/format.ts");
+        create_temp_file(
+            &file,
+            "export const formatDate = () => '';\nconst formatPrice = () => '';\nexport { formatPrice };\nexport { Money } from './money';\nexport { formatDate as format };\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.export_placement.severity = crate::config::Severity::Warn;
+        config.rules.export_placement.options.export_style = ExportStyle::Bottom;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_export_placement(&file, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "export-placement");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0].message.contains("'formatDate'"));
+        // Re-exports don't count as a second block
+        assert_eq!(diagnostics.diagnostics[1].line, Some(5));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_export_placement_inline() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-export-placement-inline");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file = temp_dir.join("lib/format.ts");
+        create_temp_file(
+            &file,
+            "export const formatDate = () => '';\nexport type Money = number;\nconst formatPrice = () => '';\nexport {\n  formatPrice,\n};\nexport * from './money';\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.export_placement.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_export_placement(&file, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(4));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_allowed_exceptions() {
+        use crate::config::RegexList;
+
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::KebabCase;
+        config.rules.filename_style.options.allowed_exceptions =
+            RegexList::try_from(vec!["^API$".to_string(), "Types$".to_string()]).unwrap();
+
+        let check = |name: &str| {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_filename_style(Path::new(""), Path::new(name), &config, &mut diagnostics);
+            diagnostics.diagnostics.len()
+        };
+
+        assert_eq!(check("lib/API.ts"), 0);
+        assert_eq!(check("lib/generated/GraphQLTypes.ts"), 0);
+        // Matched against the stem only
+        assert_eq!(check("Types/UserCard.tsx"), 1);
+    }
+
+    #[test]
+    fn test_client_only_libs() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-client-only-libs");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let server = temp_dir.join("app/page.tsx");
+        create_temp_file(
+            &server,
+            "import { motion } from 'framer-motion';\nimport useMedia from 'react-use/lib/useMedia';\nimport { useState } from 'react-user-state';\nimport type { MotionProps } from 'framer-motion';\n\nexport default function Page() {}\n",
+        );
+        let client = temp_dir.join("app/hero.tsx");
+        create_temp_file(
+            &client,
+            "'use client'\nimport { motion } from 'framer-motion';\n\nexport function Hero() {}\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.client_only_libs.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_only_libs(&server, &config, &mut diagnostics);
+        check_client_only_libs(&client, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "client-only-libs");
+        assert!(diagnostics.diagnostics[0].message.contains("'framer-motion'"));
+        assert!(diagnostics.diagnostics[1].message.contains("'react-use'"));
+        assert!(diagnostics.diagnostics.iter().all(|d| d.file == server));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_client_only_imports() {
+        use crate::config::Severity;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-client-only-imports");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let content = "import 'client-only';\nimport { track } from '@/lib/analytics-browser';\nimport Chart from './chart.client';\nimport { db } from '@/lib/db';\nimport type { Props } from './chart.client';\nimport { type Options } from './table.client';\n";
+        let server = temp_dir.join("app/dashboard/page.tsx");
+        create_temp_file(&server, content);
+        let client = temp_dir.join("app/dashboard/chart.client.tsx");
+        create_temp_file(&client, &format!("'use client'\n{}", content));
+        let pages = temp_dir.join("pages/legacy.tsx");
+        create_temp_file(&pages, content);
+
+        // Only the client-only package by default
+        let mut config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_only_imports(&server, &config, &mut diagnostics);
+        check_client_only_imports(&client, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "client-only-imports");
+        assert_eq!(diagnostics.diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+        assert!(diagnostics.diagnostics[0].message.contains("'client-only'"));
+
+        config.rules.client_only_imports.options.client_only_modules.extend([
+            "@/lib/analytics-browser".to_string(),
+            "*.client".to_string(),
+        ]);
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_only_imports(&server, &config, &mut diagnostics);
+        let found: Vec<(Option<usize>, bool)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.message.contains("'./chart.client'")))
+            .collect();
+        // Type-only imports on lines 5 and 6 are erased at build time
+        assert_eq!(found, vec![(Some(1), false), (Some(2), false), (Some(3), true)]);
+
+        // pages/ has no client directive, so it is only checked on request
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_only_imports(&pages, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.rules.client_only_imports.options.include_pages = true;
+        check_client_only_imports(&pages, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 3);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_by_path() {
+        use crate::config::FilenameStyleByPath;
+
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style = FilenameStyle::KebabCase;
+        config.rules.filename_style.options.by_path = vec![
+            FilenameStyleByPath {
+                glob: "components/**".to_string(),
+                style: FilenameStyle::PascalCase,
+            },
+            FilenameStyleByPath {
+                glob: "hooks/**".to_string(),
+                style: FilenameStyle::CamelCase,
+            },
+            FilenameStyleByPath {
+                glob: "**/*.tsx".to_string(),
+                style: FilenameStyle::SnakeCase,
+            },
+        ];
+
+        let root = Path::new("/project");
+        let check = |name: &str| {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_filename_style(root, &root.join(name), &config, &mut diagnostics);
+            diagnostics.diagnostics
+        };
+
+        assert!(check("components/ui/UserCard.tsx").is_empty());
+        assert!(check("hooks/useAuth.ts").is_empty());
+        assert!(check("lib/format-date.ts").is_empty());
+
+        // First match wins over later entries
+        let diagnostics = check("components/user-card.tsx");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Filename 'user-card' does not match expected style: PascalCase (selected by 'components/**')"
+        );
+
+        // Without a matching entry the global style applies
+        let diagnostics = check("lib/formatDate.ts");
+        assert_eq!(
+            diagnostics[0].message,
+            "Filename 'formatDate' does not match expected style: KebabCase"
+        );
+    }
+
+    #[test]
+    fn test_boundary_consistency() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-boundary-consistency");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let client = "'use client'\n\nexport default function Component() {}\n";
+        let server = "export default function Component() {}\n";
+        let segments = [
+            ("dashboard", client, server),
+            ("settings", server, client),
+            ("editor", client, client),
+            ("blog", server, server),
+        ];
+
+        let mut files = Vec::new();
+        for (segment, layout, page) in segments {
+            let layout_path = temp_dir.join("app").join(segment).join("layout.tsx");
+            let page_path = temp_dir.join("app").join(segment).join("page.tsx");
+            create_temp_file(&layout_path, layout);
+            create_temp_file(&page_path, page);
+            files.push(layout_path);
+            files.push(page_path);
+        }
+
+        let mut config = get_test_config();
+        config.rules.boundary_consistency.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_boundary_consistency(&temp_dir, &files, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "boundary-consistency");
+        assert_eq!(
+            diagnostics.diagnostics[0].file,
+            temp_dir.join("app/dashboard/layout.tsx")
+        );
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_custom_pattern() {
+        let mut config = get_test_config();
+        config.rules.filename_style.options.filename_style =
+            serde_json::from_str(r#"{ "custom": "[a-z]+(\\.[a-z]+)*" }"#).unwrap();
+
+        let check = |name: &str| {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_filename_style(Path::new(""), Path::new(name), &config, &mut diagnostics);
+            diagnostics.diagnostics
+        };
+
+        assert!(check("app/checkout/feature.section.tsx").is_empty());
+        let diagnostics = check("app/checkout/feature-section.tsx");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "Filename 'feature-section' does not match expected style: pattern '[a-z]+(\\.[a-z]+)*'"
+        );
+    }
+
+    #[test]
+    fn test_max_component_lines() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-max-component-lines");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let long = temp_dir.join("components/dashboard.tsx");
+        let short = temp_dir.join("components/card.tsx");
+        let long_test = temp_dir.join("components/dashboard.test.tsx");
+        create_temp_file(&long, &"const x = 1;\n".repeat(400));
+        create_temp_file(&short, &"const x = 1;\n".repeat(50));
+        create_temp_file(&long_test, &"const x = 1;\n".repeat(400));
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_max_component_lines(&temp_dir, &[long.clone(), short, long_test], &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "max-component-lines");
+        assert_eq!(diagnostics.diagnostics[0].file, long);
+        assert!(diagnostics.diagnostics[0].message.contains("400 lines"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_exported_type_docs() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-exported-type-docs");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let api = temp_dir.join("lib/api/types.ts");
+        create_temp_file(
+            &api,
+            r#"/** A user of the application */
+export interface User {
+  id: string;
+}
+
+// Not JSDoc
+export type UserId = string;
+
+/**
+ * Roles a user can have
+ */
+
+export type Role = 'admin' | 'member';
+
+export type { Session } from './session';
+"#,
+        );
+        let internal = temp_dir.join("app/types.ts");
+        create_temp_file(&internal, "export type Internal = string;\n");
+
+        let mut config = get_test_config();
+        config.rules.exported_type_docs.severity = crate::config::Severity::Warn;
+        config.rules.exported_type_docs.options.public_api_glob = Some("lib/api/**".to_string());
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_exported_type_docs(
+            &temp_dir,
+            &[api.clone(), internal],
+            &config,
+            &mut diagnostics,
+        );
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "exported-type-docs");
+        assert_eq!(diagnostics.diagnostics[0].file, api);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(7));
+        assert!(diagnostics.diagnostics[0].message.contains("'UserId'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_dynamic_in_server() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-dynamic-in-server");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let code = r#"import dynamic from 'next/dynamic';
+
+const Map = dynamic(() => import('./map'), { ssr: false });
+const Chart = dynamic(() => import('./chart'));
+"#;
+        let server = temp_dir.join("app/dashboard/page.tsx");
+        create_temp_file(&server, code);
+        let client = temp_dir.join("app/dashboard/widgets.tsx");
+        create_temp_file(&client, &format!("'use client';\n{}", code));
+
+        let mut config = get_test_config();
+        config.rules.dynamic_in_server.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_dynamic_in_server(&server, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "dynamic-in-server");
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_dynamic_in_server(&client, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_barrel_files() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-barrel-files");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let app_barrel = temp_dir.join("app/components/index.ts");
+        create_temp_file(&app_barrel, "export * from './button';\n");
+        let lib_barrel = temp_dir.join("lib/index.ts");
+        create_temp_file(&lib_barrel, "export * from './db';\n");
+        let button = temp_dir.join("app/components/button.tsx");
+        create_temp_file(&button, "export function Button() {}\n");
+
+        let mut config = get_test_config();
+        config.rules.no_barrel_files.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_barrel_files(
+            &temp_dir,
+            &[app_barrel.clone(), lib_barrel, button],
+            &config,
+            &mut diagnostics,
+        );
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-barrel-files");
+        assert_eq!(diagnostics.diagnostics[0].file, app_barrel);
+        assert!(diagnostics.diagnostics[0].message.contains("import modules directly"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_hydration_risk() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-hydration-risk");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let file_path = temp_dir.join("app/clock.tsx");
+        create_temp_file(
+            &file_path,
+            r#"'use client';
+
+export function Clock() {
+  const createdAt = new Date();
+  return (
+    <div>
+      <span>{new Date().toLocaleTimeString()}</span>
+      <time suppressHydrationWarning>{Date.now()}</time>
+      <div data-seed={Math.random()} />
+    </div>
+  );
+}
+"#,
+        );
+
+        let mut config = get_test_config();
+        config.rules.hydration_risk.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_hydration_risk(&file_path, &config, &mut diagnostics);
+
+        let lines: Vec<Option<usize>> = diagnostics.diagnostics.iter().map(|d| d.line).collect();
+        assert_eq!(lines, vec![Some(7), Some(9)]);
+        assert_eq!(diagnostics.diagnostics[0].rule, "hydration-risk");
+        assert!(diagnostics.diagnostics[0].message.starts_with("new Date()"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_client_env_prefix() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-client-env-prefix");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let code = r#"const url = process.env.NEXT_PUBLIC_URL;
+const secret = process.env.SECRET;
+const mode = process.env.NODE_ENV;
+const key = process.env['API_KEY'];
+"#;
+        let client = temp_dir.join("app/settings.tsx");
+        create_temp_file(&client, &format!("'use client';\n\n{}", code));
+        let server = temp_dir.join("app/page.tsx");
+        create_temp_file(&server, code);
+
+        let config = get_test_config();
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_env_prefix(&client, &config, &mut diagnostics);
+        let found: Vec<(Option<usize>, bool)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.message.contains("SECRET")))
+            .collect();
+        assert_eq!(found, vec![(Some(4), true), (Some(6), false)]);
+        assert!(diagnostics.diagnostics[1].message.contains("API_KEY"));
+        assert_eq!(diagnostics.diagnostics[0].rule, "client-env-prefix");
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_env_prefix(&server, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_client_env_prefix_server_only() {
+        use crate::config::Severity;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-client-env-prefix-server");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let code = r#"const url = process.env.NEXT_PUBLIC_URL;
+const secret = process.env.SECRET;
+const env = process.env.VERCEL_ENV;
+"#;
+        let server = temp_dir.join("lib/db.ts");
+        create_temp_file(&server, &format!("import 'server-only';\n{}", code));
+        let action = temp_dir.join("app/actions.ts");
+        create_temp_file(&action, &format!("'use server';\n{}", code));
+        let client = temp_dir.join("app/settings.tsx");
+        create_temp_file(&client, &format!("'use client';\n{}", code));
+
+        let mut config = get_test_config();
+        config.rules.client_env_prefix.options.allowed_env_vars =
+            vec!["NODE_ENV".to_string(), "VERCEL_ENV".to_string()];
+
+        // Off by default
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_env_prefix(&server, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.rules.client_env_prefix.options.public_in_server_severity = Severity::Warn;
+        for file in [&server, &action] {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_client_env_prefix(file, &config, &mut diagnostics);
+            assert_eq!(diagnostics.diagnostics.len(), 1);
+            assert_eq!(diagnostics.diagnostics[0].severity, Severity::Warn);
+            assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+            assert!(diagnostics.diagnostics[0].message.contains("NEXT_PUBLIC_URL"));
+        }
+
+        // The severity of that check is configured on its own
+        config.rules.client_env_prefix.options.public_in_server_severity = Severity::Error;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_env_prefix(&server, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics[0].severity, Severity::Error);
+
+        // Client components still only report private variables
+        let mut diagnostics = DiagnosticCollection::new();
+        check_client_env_prefix(&client, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_max_url_depth() {
+        let mut config = get_test_config();
+        config.rules.max_url_depth.severity = crate::config::Severity::Warn;
+        config.rules.max_url_depth.options.max_url_depth = 2;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        // Groups, slots and private folders don't count
+        check_max_url_depth(
+            Path::new("/project/app/(shop)/products/[id]/@modal/page.tsx"),
+            &config,
+            &mut diagnostics,
+        );
+        check_max_url_depth(
+            Path::new("/project/app/_internal/blog/[slug]/page.tsx"),
+            &config,
+            &mut diagnostics,
+        );
+        assert!(diagnostics.diagnostics.is_empty());
+
+        let deep = Path::new("/project/app/(shop)/products/[id]/reviews/[reviewId]/page.tsx");
+        check_max_url_depth(deep, &config, &mut diagnostics);
+        check_max_url_depth(
+            Path::new("/project/app/products/[id]/reviews/layout.tsx"),
+            &config,
+            &mut diagnostics,
+        );
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "max-url-depth");
+        assert_eq!(diagnostics.diagnostics[0].file, deep);
+        assert!(diagnostics.diagnostics[0].message.contains("4 URL segments"));
+    }
+
+    #[test]
+    fn test_canonical_rule_id() {
+        assert_eq!(canonical_rule_id("filename-style-consistency"), "filename-style");
+        assert_eq!(canonical_rule_id("filename-style"), "filename-style");
+        assert_eq!(
+            rule_description("filename-style-consistency"),
+            rule_description("filename-style")
+        );
+    }
+
+    #[test]
+    fn test_explain_rule() {
+        let explanation = explain_rule("server-side-exports").unwrap();
+        assert!(explanation.starts_with(
+            "server-side-exports: Client components must not export server-side data fetching functions\n"
+        ));
+        assert!(explanation.contains("Server-Side Exports (`server-side-exports`)"));
+        assert!(explanation.contains("'use client'"));
+        // The next rule's section isn't included
+        assert!(!explanation.contains("component-nesting-depth"));
+
+        // Renamed rules explain their current id
+        assert!(explain_rule("filename-style-consistency")
+            .unwrap()
+            .starts_with("filename-style: "));
+        // Rules without a README section still get their description
+        assert_eq!(
+            explain_rule("structure-snapshot").unwrap(),
+            "structure-snapshot: The route tree must match naechste-structure.lock\n"
+        );
+        assert!(explain_rule("no-such-rule").is_none());
+    }
+
+    #[test]
+    fn test_barrel_self_cycle() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-barrel-self-cycle");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("components/index.ts"),
+            "export * from './card';\nexport * from './forms';\nexport { Button } from './button';\n",
+        );
+        create_temp_file(&temp_dir.join("components/button.tsx"), "export function Button() {}\n");
+        // Imports its sibling directly: fine
+        let direct = temp_dir.join("components/card.tsx");
+        create_temp_file(
+            &direct,
+            "import { Button } from './button';\nexport function Card() {}\n",
+        );
+        // Imports the barrel that re-exports it, through a nested barrel
+        create_temp_file(&temp_dir.join("components/forms/index.ts"), "export * from './input';\n");
+        let cyclic = temp_dir.join("components/forms/input.tsx");
+        create_temp_file(
+            &cyclic,
+            "import { Button } from '..';\nexport function Input() {}\n",
+        );
+
+        let files = vec![
+            temp_dir.join("components/index.ts"),
+            temp_dir.join("components/button.tsx"),
+            direct,
+            temp_dir.join("components/forms/index.ts"),
+            cyclic.clone(),
+        ];
+        let mut config = get_test_config();
+        config.rules.barrel_self_cycle.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_barrel_self_cycle(&temp_dir, &files, &PathAliases::default(), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "barrel-self-cycle");
+        assert_eq!(diagnostics.diagnostics[0].file, cyclic);
+        assert!(diagnostics.diagnostics[0].message.contains("components/index.ts"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_import_quote_consistency() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-import-quotes");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let mixed = temp_dir.join("mixed.tsx");
+        create_temp_file(
+            &mixed,
+            "import React from 'react';\nimport { Button } from \"./button\";\nimport { cn } from './cn';\n",
+        );
+        let consistent = temp_dir.join("consistent.tsx");
+        create_temp_file(
+            &consistent,
+            "import React from 'react';\nexport { Button } from './button';\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.import_quote_consistency.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_quote_consistency(&mixed, &config, &mut diagnostics);
+        check_import_quote_consistency(&consistent, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "import-quote-consistency");
+        assert_eq!(diagnostics.diagnostics[0].file, mixed);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+        assert!(diagnostics.diagnostics[0].message.contains("'./button' uses double quotes"));
+
+        // A preferred style flags consistent files too
+        config.rules.import_quote_consistency.options.prefer = Some(crate::config::QuoteStyle::Double);
+        let mut diagnostics = DiagnosticCollection::new();
+        check_import_quote_consistency(&consistent, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_page_file_focus() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-page-file-focus");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let focused = temp_dir.join("app/blog/page.tsx");
+        create_temp_file(
+            &focused,
+            r#"export const metadata = { title: 'Blog' };
+export const revalidate = 60;
+export type BlogProps = { page: number };
+
+export async function generateStaticParams() { return []; }
+
+export default function BlogPage() { return null; }
+"#,
+        );
+        let cluttered = temp_dir.join("app/shop/page.tsx");
+        create_temp_file(
+            &cluttered,
+            r#"export const dynamic = 'force-dynamic';
+export function formatPrice(cents: number) { return `${cents / 100}`; }
+export const TAX_RATE = 0.2;
+
+function ShopPage() { return null; }
+export { ShopPage as default, type Cart };
+"#,
+        );
+
+        let mut config = get_test_config();
+        config.rules.page_file_focus.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_page_file_focus(&focused, &config, &mut diagnostics);
+        check_page_file_focus(&cluttered, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "page-file-focus");
+        assert_eq!(diagnostics.diagnostics[0].file, cluttered);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+        assert!(diagnostics.diagnostics[0].message.contains("(formatPrice, TAX_RATE)"));
+
+        // The limit is configurable
+        config.rules.page_file_focus.options.max_extra_exports = 2;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_page_file_focus(&cluttered, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_promise_style() {
+        use crate::config::PromiseStyle;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-promise-style");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let awaiting = temp_dir.join("app/posts/page.tsx");
+        create_temp_file(
+            &awaiting,
+            "export default async function Page() {\n  const posts = await getPosts();\n  return null;\n}\n",
+        );
+        let chaining = temp_dir.join("app/users/page.tsx");
+        create_temp_file(
+            &chaining,
+            "export default function Page() {\n  return getUsers().then((users) => null);\n}\n",
+        );
+        let client = temp_dir.join("app/users/list.tsx");
+        create_temp_file(
+            &client,
+            "'use client';\nexport function List() {\n  load().then(() => {});\n}\n",
+        );
+        let files = [&awaiting, &chaining, &client];
+
+        let mut config = get_test_config();
+        config.rules.promise_style.severity = crate::config::Severity::Warn;
+
+        // async-await policy: .then() is flagged, in server components only
+        let mut diagnostics = DiagnosticCollection::new();
+        for file in files {
+            check_promise_style(file, &config, &mut diagnostics);
+        }
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "promise-style");
+        assert_eq!(diagnostics.diagnostics[0].file, chaining);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(2));
+
+        // then policy: await is flagged
+        config.rules.promise_style.options.promise_style = PromiseStyle::Then;
+        let mut diagnostics = DiagnosticCollection::new();
+        for file in files {
+            check_promise_style(file, &config, &mut diagnostics);
+        }
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, awaiting);
+        assert!(diagnostics.diagnostics[0].message.contains("instead of await"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_page_default_export() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-page-default-export");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let named_only = temp_dir.join("app/about/page.tsx");
+        create_temp_file(&named_only, "export const metadata = {};\nexport function AboutPage() {}\n");
+        let with_default = temp_dir.join("app/page.tsx");
+        create_temp_file(&with_default, "export default async function Home() {}\n");
+        let reexported = temp_dir.join("app/blog/page.jsx");
+        create_temp_file(&reexported, "function Blog() {}\nexport { Blog as default };\n");
+        let arrow = temp_dir.join("app/shop/page.ts");
+        create_temp_file(&arrow, "const Shop = () => null;\nexport default Shop;\n");
+        let layout = temp_dir.join("app/layout.tsx");
+        create_temp_file(&layout, "export function RootLayout() {}\n");
+        let forwarded = temp_dir.join("app/home/page.tsx");
+        create_temp_file(&forwarded, "export { default } from \"./other\";\n");
+        let forwarded_with_metadata = temp_dir.join("app/(marketing)/page.tsx");
+        create_temp_file(
+            &forwarded_with_metadata,
+            "export {\n  default,\n  metadata,\n} from \"@/features/home/page\";\n",
+        );
+        let renamed = temp_dir.join("app/docs/page.tsx");
+        create_temp_file(&renamed, "export { default as Docs } from './docs';\n");
+
+        let mut config = get_test_config();
+        let files = [
+            &named_only,
+            &with_default,
+            &reexported,
+            &arrow,
+            &layout,
+            &forwarded,
+            &forwarded_with_metadata,
+            &renamed,
+        ];
+        let mut diagnostics = DiagnosticCollection::new();
+        for file in files {
+            check_page_default_export(file, &config, &mut diagnostics);
+        }
+
+        let reported: Vec<&std::path::PathBuf> = diagnostics.diagnostics.iter().map(|d| &d.file).collect();
+        assert_eq!(reported, vec![&named_only, &renamed]);
+        assert_eq!(diagnostics.diagnostics[0].rule, "page-default-export");
+        assert_eq!(diagnostics.diagnostics[0].severity, crate::config::Severity::Error);
+
+        // Other special files are covered when listed
+        config.rules.page_default_export.options.special_files =
+            vec!["page".to_string(), "layout".to_string()];
+        let mut diagnostics = DiagnosticCollection::new();
+        for file in files {
+            check_page_default_export(file, &config, &mut diagnostics);
+        }
+        assert_eq!(diagnostics.diagnostics.len(), 3);
+        assert_eq!(diagnostics.diagnostics[1].file, layout);
+        assert!(diagnostics.diagnostics[1].message.starts_with("'layout' file"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_top_level_await_client() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-top-level-await-client");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let top_level = temp_dir.join("app/settings/form.tsx");
+        create_temp_file(
+            &top_level,
+            r#"'use client';
+
+const flags = await loadFlags();
+
+export function Form() {
+  return null;
+}
+"#,
+        );
+        let inside_functions = temp_dir.join("app/settings/panel.tsx");
+        create_temp_file(
+            &inside_functions,
+            r#"'use client';
+
+const load = async () => await fetch('/api/settings');
+
+export function Panel() {
+  useEffect(() => {
+    (async () => {
+      await load();
+    })();
+  }, []);
+  return null;
+}
+"#,
+        );
+        // Server components may await at the top level
+        let server = temp_dir.join("app/settings/page.tsx");
+        create_temp_file(&server, "const flags = await loadFlags();\nexport default function Page() {}\n");
+
+        let mut config = get_test_config();
+        config.rules.no_top_level_await_client.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        for file in [&top_level, &inside_functions, &server] {
+            check_no_top_level_await_client(file, &config, &mut diagnostics);
+        }
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-top-level-await-client");
+        assert_eq!(diagnostics.diagnostics[0].file, top_level);
+        assert_eq!(diagnostics.diagnostics[0].line, Some(3));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_filename_style_message_template() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-message-template");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let bad_file = temp_dir.join("MyComponent.tsx");
+        create_temp_file(&bad_file, "export function MyComponent() {}");
+
+        let mut config = get_test_config();
+        config.rules.filename_style.message_template =
+            Some("{filename} should be {expected_style} ({rule})".to_string());
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        diagnostics.apply_message_templates(&config.rules.message_templates(), &temp_dir);
+        assert_eq!(
+            diagnostics.diagnostics[0].message,
+            "MyComponent should be KebabCase (filename-style)"
+        );
+
+        // Without a template the default message is kept
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_filename_style(&temp_dir, &bad_file, &config, &mut diagnostics);
+        diagnostics.apply_message_templates(&config.rules.message_templates(), &temp_dir);
+        assert!(diagnostics.diagnostics[0].message.starts_with("Filename 'MyComponent'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_layout_renders_children_spread() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-layout-children-spread");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            (
+                "app/dashboard/layout.tsx",
+                "export default function DashboardLayout(props) { return <Shell {...props} />; }",
+                0,
+            ),
+            (
+                "app/settings/layout.tsx",
+                "export default function Layout({ params, ...rest }) {\n  return <Shell id={params.id} {...rest} />;\n}",
+                0,
+            ),
+            (
+                "app/admin/layout.tsx",
+                "export default function Layout(props: LayoutProps) {\n  return <Shell title={props.title} />;\n}",
+                1,
+            ),
+        ];
+
+        let config = get_test_config();
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_layout_renders_children(&path, &config, &mut diagnostics);
+            assert_eq!(diagnostics.diagnostics.len(), expected, "{}", file);
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_layout_renders_children() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-layout-children");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            (
+                "app/layout.tsx",
+                "export default function RootLayout({ children }: { children: React.ReactNode }) {\n  return <html><body>{children}</body></html>;\n}",
+                0,
+            ),
+            (
+                "app/blog/layout.tsx",
+                "export default function Layout(props: LayoutProps) {\n  return <main>{props.children}</main>;\n}",
+                0,
+            ),
+            (
+                "app/docs/layout.tsx",
+                "const DocsLayout = ({ children }) => <section>{children}</section>;\nexport default DocsLayout;",
+                0,
+            ),
+            (
+                "app/shop/layout.tsx",
+                "export default function Layout({ params }) {\n  return <main>{params.id}</main>;\n}",
+                1,
+            ),
+            (
+                "app/admin/layout.tsx",
+                "export default function Layout({ children }: { children: React.ReactNode }) {\n  return <main />;\n}",
+                1,
+            ),
+            (
+                "app/about/layout.tsx",
+                "export default function Layout() {\n  return <main />;\n}",
+                1,
+            ),
+            ("components/layout.tsx", "export default function Layout() { return null; }", 0),
+        ];
+
+        let config = get_test_config();
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_layout_renders_children(&path, &config, &mut diagnostics);
+            assert_eq!(diagnostics.diagnostics.len(), expected, "{}", file);
+            if expected > 0 {
+                assert_eq!(diagnostics.diagnostics[0].rule, "layout-renders-children");
+                assert_eq!(diagnostics.diagnostics[0].severity, crate::config::Severity::Warn);
+                assert_eq!(diagnostics.diagnostics[0].line, Some(1));
+            }
+        }
+
+        // The root layout must render <html> and <body> when required
+        let root = temp_dir.join("app/layout.tsx");
+        create_temp_file(
+            &root,
+            "export default function RootLayout({ children }) {\n  return <main>{children}</main>;\n}",
+        );
+        let mut config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_layout_renders_children(&root, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.rules.layout_renders_children.options.require_html_body = true;
+        check_layout_renders_children(&root, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert!(diagnostics.diagnostics[0].message.contains("<html>"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_required_directories() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-required-dirs");
+        fs::remove_dir_all(&temp_dir).ok();
+        create_temp_file(&temp_dir.join("app/api/health/route.ts"), "export function GET() {}");
+        fs::create_dir_all(temp_dir.join("lib")).unwrap();
+
+        let mut config = get_test_config();
+        config.rules.required_directories.severity = crate::config::Severity::Warn;
+        config.rules.required_directories.options.required_dirs = vec!["app/api".to_string()];
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_required_directories(&temp_dir, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.rules.required_directories.options.required_dirs =
+            vec!["app/api/".to_string(), "lib".to_string(), "hooks".to_string()];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_required_directories(&temp_dir, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "required-directories");
+        assert!(diagnostics.diagnostics[0].message.contains("'lib' is empty"));
+        assert!(diagnostics.diagnostics[1].message.contains("'hooks' is missing"));
+        assert_eq!(diagnostics.diagnostics[1].file, temp_dir.join("hooks"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_circular_imports() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-circular-imports");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        // a -> b -> a
+        let a = temp_dir.join("lib/a.ts");
+        create_temp_file(&a, "import { b } from './b';\nexport const a = 1;\n");
+        let b = temp_dir.join("lib/b.ts");
+        create_temp_file(&b, "import { a } from './a';\nexport const b = 2;\n");
+        // x -> y -> z, plus a type-only import back to x
+        let x = temp_dir.join("utils/x.ts");
+        create_temp_file(&x, "import { y } from './y';\nexport type X = string;\n");
+        let y = temp_dir.join("utils/y.ts");
+        create_temp_file(&y, "export { z } from './z';\nexport const y = 1;\n");
+        let z = temp_dir.join("utils/z.ts");
+        create_temp_file(&z, "import type { X } from './x';\nexport const z = 1;\n");
+
+        let mut config = get_test_config();
+        config.rules.no_circular_imports.severity = crate::config::Severity::Warn;
+
+        // Acyclic chain: clean
+        let chain = vec![x.clone(), y.clone(), z.clone()];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_circular_imports(&temp_dir, &chain, &PathAliases::default(), &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        // Two-file cycle: flagged once
+        let files = vec![b.clone(), a.clone(), x, y, z];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_circular_imports(&temp_dir, &files, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "no-circular-imports");
+        assert_eq!(diagnostics.diagnostics[0].file, a);
+        assert_eq!(
+            diagnostics.diagnostics[0].message,
+            "Circular import: lib/a.ts -> lib/b.ts -> lib/a.ts"
+        );
+
+        // Counting type-only imports closes the x -> y -> z cycle
+        config.rules.no_circular_imports.options.ignore_type_imports = false;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_no_circular_imports(&temp_dir, &files, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert!(diagnostics.diagnostics[1]
+            .message
+            .ends_with("utils/x.ts -> utils/y.ts -> utils/z.ts -> utils/x.ts"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_redundant_use_strict() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-use-strict");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            ("strict.ts", "'use strict';\nexport const a = 1;\n", Some(1)),
+            ("after-client.tsx", "// Button\n\"use client\"\n\"use strict\"\nexport function Button() {}\n", Some(3)),
+            ("module.mjs", "'use strict'\nexport default 1;\n", Some(1)),
+            ("clean.ts", "export const a = 1;\n", None),
+            ("nested.ts", "export function f() {\n  'use strict';\n}\n", None),
+            ("script.js", "'use strict';\nmodule.exports = {};\n", None),
+        ];
+
+        let mut config = get_test_config();
+        config.rules.no_redundant_use_strict.severity = crate::config::Severity::Warn;
+
+        for (file, content, expected_line) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_no_redundant_use_strict(&path, &config, &mut diagnostics);
+            let lines: Vec<_> = diagnostics.diagnostics.iter().map(|d| d.line).collect();
+            assert_eq!(lines, expected_line.map(Some).into_iter().collect::<Vec<_>>(), "{}", file);
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_conflicting_directives() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-conflicting-directives");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            ("single.ts", "'use client';\n'use server';\nexport async function a() {}\n", Some(2)),
+            ("double.ts", "\"use server\"\n\"use client\"\nexport async function a() {}\n", Some(2)),
+            (
+                "comments.tsx",
+                "// Form\n/* shared\n   by both */\n'use client'\n\n// oops\n\"use server\";\n",
+                Some(7),
+            ),
+            (
+                "inline-action.tsx",
+                "'use client'\nexport function Form() {\n  async function save() {\n    'use server'\n  }\n}\n",
+                None,
+            ),
+            ("client.tsx", "'use client'\nexport function Button() {}\n", None),
+        ];
+
+        let config = get_test_config();
+        for (file, content, expected_line) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_conflicting_directives(&path, &config, &mut diagnostics);
+            let lines: Vec<_> = diagnostics.diagnostics.iter().map(|d| d.line).collect();
+            assert_eq!(lines, expected_line.map(Some).into_iter().collect::<Vec<_>>(), "{}", file);
+            if let Some(diagnostic) = diagnostics.diagnostics.first() {
+                assert_eq!(diagnostic.rule, "conflicting-directives");
+                assert_eq!(diagnostic.severity, crate::config::Severity::Error);
+            }
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_outlier_file_size() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-outlier-file-size");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let lines = |count: usize| "const line = 1;\n".repeat(count);
+        let mut files = Vec::new();
+        for (file, count) in [
+            ("components/button.tsx", 40),
+            ("components/card.tsx", 60),
+            ("components/input.tsx", 50),
+            ("components/data-table.tsx", 400),
+            // Too few siblings for an average
+            ("lib/db.ts", 20),
+            ("lib/schema.ts", 500),
+        ] {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, &lines(count));
+            files.push(path);
+        }
+
+        let mut config = get_test_config();
+        config.rules.outlier_file_size.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_outlier_file_size(&files, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "outlier-file-size");
+        assert_eq!(diagnostics.diagnostics[0].file, temp_dir.join("components/data-table.tsx"));
+        assert!(diagnostics.diagnostics[0].message.contains("400 lines"));
+        assert!(diagnostics.diagnostics[0].message.contains("50-line average"));
+
+        config.rules.outlier_file_size.options.size_multiplier = 10.0;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_outlier_file_size(&files, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_no_client_hooks_in_server() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-client-hooks-in-server");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let body = "export default function Counter() {\n  const [count, setCount] = useState(0);\n  return <p>{count}</p>;\n}\n";
+        let server = temp_dir.join("app/counter/page.tsx");
+        create_temp_file(&server, &format!("import {{ useState }} from 'react';\n\n{}", body));
+        let direct = temp_dir.join("app/direct/page.tsx");
+        create_temp_file(&direct, &format!("import * as React from 'react';\n\n{}", body.replace("useState", "React.useState")));
+        let client = temp_dir.join("app/client/counter.tsx");
+        create_temp_file(&client, &format!("'use client'\nimport {{ useState }} from 'react';\n\n{}", body));
+        let outside_app = temp_dir.join("hooks/use-counter.ts");
+        create_temp_file(&outside_app, &format!("import {{ useState }} from 'react';\n{}", body));
+
+        let mut config = get_test_config();
+        config.rules.no_client_hooks_in_server.severity = crate::config::Severity::Warn;
+
+        let check = |file: &Path, config: &Config| {
+            let mut diagnostics = DiagnosticCollection::new();
+            check_no_client_hooks_in_server(file, config, &mut diagnostics);
+            diagnostics.diagnostics
+        };
+
+        let found = check(&server, &config);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].rule, "no-client-hooks-in-server");
+        assert_eq!(found[0].line, Some(1));
+        assert!(found[0].message.starts_with("'useState'"));
+        assert_eq!(check(&direct, &config)[0].line, Some(4));
+        assert!(check(&client, &config).is_empty());
+        assert!(check(&outside_app, &config).is_empty());
+
+        config.rules.no_client_hooks_in_server.options.client_hooks = vec!["useEffect".to_string()];
+        assert!(check(&server, &config).is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_require_metadata() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-require-metadata");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let component = "export default function Page() { return null; }\n";
+        let cases = [
+            ("app/page.tsx", format!("export const metadata = {{ title: 'Home' }};\n{}", component), None),
+            (
+                "app/blog/[slug]/page.tsx",
+                format!("export async function generateMetadata({{ params }}) {{ return {{}}; }}\n{}", component),
+                None,
+            ),
+            (
+                "app/docs/page.tsx",
+                format!("export {{ docsMetadata as metadata }} from './meta';\n{}", component),
+                None,
+            ),
+            ("app/about/page.tsx", component.to_string(), Some("'page' exports neither")),
+            ("app/layout.tsx", component.to_string(), Some("'layout' exports neither")),
+            // Only the root layout is checked by default
+            ("app/blog/layout.tsx", component.to_string(), None),
+            (
+                "app/settings/page.tsx",
+                format!("'use client'\n{}", component),
+                Some("'page' is a client component"),
+            ),
+            ("components/page.tsx", component.to_string(), None),
+        ];
+
+        let mut config = get_test_config();
+        config.rules.require_metadata.severity = crate::config::Severity::Warn;
+
+        for (file, content, expected) in &cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_require_metadata(&path, &config, &mut diagnostics);
+            match expected {
+                Some(message) => {
+                    assert_eq!(diagnostics.diagnostics.len(), 1, "{}", file);
+                    assert_eq!(diagnostics.diagnostics[0].rule, "require-metadata");
+                    assert!(diagnostics.diagnostics[0].message.starts_with(message), "{}", file);
+                }
+                None => assert!(diagnostics.diagnostics.is_empty(), "{}", file),
+            }
+        }
+
+        config.rules.require_metadata.options.root_layout_only = false;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_require_metadata(&temp_dir.join("app/blog/layout.tsx"), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::{Config, FilenameStyle};
-    use std::fs;
-    use std::io::Write;
+    #[test]
+    fn test_unique_page_names() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-unique-page-names");
+        fs::remove_dir_all(&temp_dir).ok();
 
-    fn create_temp_file(path: &Path, content: &str) {
-        if let Some(parent) = path.parent() {
-            fs::create_dir_all(parent).ok();
+        let mut files = Vec::new();
+        for (file, content) in [
+            ("app/page.tsx", "export default function HomePage() {}\n"),
+            ("app/about/page.tsx", "export default function Page() {}\n"),
+            ("app/blog/page.tsx", "const Page = () => null;\n\nexport default Page;\n"),
+            ("app/contact/page.tsx", "export default async function ContactPage() {}\n"),
+            ("components/page.tsx", "export default function Pager() {}\n"),
+        ] {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            files.push(path);
         }
-        let mut file = fs::File::create(path).unwrap();
-        file.write_all(content.as_bytes()).unwrap();
-    }
 
-    fn get_test_config() -> Config {
-        Config::default()
+        let mut config = get_test_config();
+        config.rules.unique_page_names.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_unique_page_names(&temp_dir, &files, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 2);
+        assert_eq!(diagnostics.diagnostics[0].rule, "unique-page-names");
+        assert_eq!(diagnostics.diagnostics[0].file, files[1]);
+        assert!(diagnostics.diagnostics[0].message.ends_with("app/blog/page.tsx"));
+        assert_eq!(diagnostics.diagnostics[1].file, files[2]);
+        assert_eq!(diagnostics.diagnostics[1].line, Some(3));
+
+        // Uniquely named pages are clean
+        let unique = vec![files[0].clone(), files[3].clone(), files[1].clone()];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_unique_page_names(&temp_dir, &unique, &config, &mut diagnostics);
+        assert!(diagnostics.diagnostics.is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_is_kebab_case() {
-        assert!(is_kebab_case("my-component"));
-        assert!(is_kebab_case("button"));
-        assert!(is_kebab_case("my-long-component-name"));
-        assert!(is_kebab_case("component123"));
-        assert!(is_kebab_case("component-123"));
-        
-        assert!(!is_kebab_case("MyComponent"));
-        assert!(!is_kebab_case("my_component"));
-        assert!(!is_kebab_case("myComponent"));
-        assert!(!is_kebab_case("My-Component"));
-        assert!(!is_kebab_case("-my-component"));
-        assert!(!is_kebab_case("my-component-"));
+    fn test_conflicting_metadata_exports() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-conflicting-metadata");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            (
+                "app/page.tsx",
+                "export const metadata = { title: 'Home' };\n\nexport async function generateMetadata() {\n  return {};\n}\n",
+                Some((1, 3)),
+            ),
+            (
+                "app/blog/layout.tsx",
+                "export function generateMetadata() {\n  return {};\n}\nexport let metadata = {};\n",
+                Some((4, 1)),
+            ),
+            (
+                "app/docs/page.tsx",
+                "const metadata = {};\nexport const generateMetadata = async () => ({});\nexport { metadata };\n",
+                Some((3, 2)),
+            ),
+            ("app/about/page.tsx", "export const metadata = {};\n", None),
+            (
+                "lib/seo.ts",
+                "export const metadata = {};\nexport function generateMetadata() {}\n",
+                None,
+            ),
+        ];
+
+        let config = get_test_config();
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_conflicting_metadata_exports(&path, &config, &mut diagnostics);
+            match expected {
+                Some((metadata, generate)) => {
+                    assert_eq!(diagnostics.diagnostics.len(), 1, "{}", file);
+                    let diagnostic = &diagnostics.diagnostics[0];
+                    assert_eq!(diagnostic.rule, "conflicting-metadata-exports");
+                    assert_eq!(diagnostic.severity, crate::config::Severity::Error);
+                    assert!(diagnostic
+                        .message
+                        .starts_with(&format!("Exports both metadata (line {}) and generateMetadata (line {})", metadata, generate)));
+                    assert_eq!(diagnostic.line, Some(metadata.max(generate)));
+                }
+                None => assert!(diagnostics.diagnostics.is_empty(), "{}", file),
+            }
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_is_camel_case() {
-        assert!(is_camel_case("myComponent"));
-        assert!(is_camel_case("myLongComponentName"));
-        assert!(is_camel_case("component123Name"));
-        
-        assert!(!is_camel_case("MyComponent"));
-        assert!(!is_camel_case("my-component"));
-        assert!(!is_camel_case("my_component"));
-        assert!(!is_camel_case("component"));
-        assert!(!is_camel_case("COMPONENT"));
+    fn test_unresolved_imports() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-unresolved-imports");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("components/button.tsx"), "export function Button() {}\n");
+        create_temp_file(&temp_dir.join("components/forms/index.ts"), "export {};\n");
+        create_temp_file(&temp_dir.join("styles/card.module.css"), ".card {}\n");
+        let card = temp_dir.join("components/card.tsx");
+        create_temp_file(
+            &card,
+            "import React from 'react';\n\
+             import { Button } from './button';\n\
+             import { Input } from './forms';\n\
+             import { Esm } from './button.js';\n\
+             import styles from '../styles/card.module.css';\n\
+             import { Badge } from './bagde';\n\
+             import { db } from '@/lib/db';\n",
+        );
+
+        let mut config = get_test_config();
+        config.rules.unresolved_import.severity = crate::config::Severity::Warn;
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_unresolved_imports(&temp_dir, &[card], &PathAliases::default(), &config, &mut diagnostics);
+
+        let found: Vec<(Option<usize>, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.line, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (Some(6), "Import './bagde' does not resolve to a file"),
+                (Some(7), "Import '@/lib/db' does not resolve to a file"),
+            ]
+        );
+        assert_eq!(diagnostics.diagnostics[0].rule, "unresolved-import");
+
+        fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_is_pascal_case() {
-        assert!(is_pascal_case("MyComponent"));
-        assert!(is_pascal_case("Button"));
-        assert!(is_pascal_case("MyLongComponentName"));
-        assert!(is_pascal_case("Component123"));
-        
-        assert!(!is_pascal_case("myComponent"));
-        assert!(!is_pascal_case("my-component"));
-        assert!(!is_pascal_case("my_component"));
-        assert!(!is_pascal_case("COMPONENT"));
+    fn test_no_client_metadata() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-client-metadata");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let cases = [
+            (
+                "app/pricing/page.tsx",
+                "'use client';\n\nexport const metadata = { title: 'Pricing' };\n\nexport async function generateMetadata() {\n  return {};\n}\n",
+                vec![3, 5],
+            ),
+            (
+                "app/blog/layout.tsx",
+                "// Marketing layout\n\"use client\"\nconst metadata = {};\nexport { metadata };\n",
+                vec![4],
+            ),
+            // Server components may export metadata
+            ("app/about/page.tsx", "export const metadata = {};\n", vec![]),
+            // A directive after other statements isn't one
+            (
+                "app/docs/page.tsx",
+                "import x from 'x';\n'use client';\nexport const metadata = {};\n",
+                vec![],
+            ),
+            ("components/seo.tsx", "'use client';\nexport const metadata = {};\n", vec![]),
+        ];
+
+        let config = get_test_config();
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_no_client_metadata(&path, &config, &mut diagnostics);
+            let lines: Vec<usize> = diagnostics.diagnostics.iter().filter_map(|d| d.line).collect();
+            assert_eq!(lines, expected, "{}", file);
+            for diagnostic in &diagnostics.diagnostics {
+                assert_eq!(diagnostic.rule, "no-client-metadata");
+                assert_eq!(diagnostic.severity, crate::config::Severity::Error);
+                assert!(diagnostic.message.contains("only work in server components"));
+            }
+        }
+
+        fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_is_snake_case() {
-        assert!(is_snake_case("my_component"));
-        assert!(is_snake_case("button"));
-        assert!(is_snake_case("my_long_component_name"));
-        assert!(is_snake_case("component_123"));
-        
-        assert!(!is_snake_case("MyComponent"));
-        assert!(!is_snake_case("my-component"));
-        assert!(!is_snake_case("myComponent"));
-        assert!(!is_snake_case("_my_component"));
-        assert!(!is_snake_case("my_component_"));
+    fn test_parse_dynamic_segment() {
+        assert_eq!(parse_dynamic_segment("[id]"), Ok((DynamicSegment::Single, "id")));
+        assert_eq!(parse_dynamic_segment("[...slug]"), Ok((DynamicSegment::CatchAll, "slug")));
+        assert_eq!(
+            parse_dynamic_segment("[[...slug]]"),
+            Ok((DynamicSegment::OptionalCatchAll, "slug"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("[..slug]"),
+            Err(("catch-all dots other than '...'", DynamicSegment::CatchAll, "slug"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("[[slug]]"),
+            Err(("optional segments that aren't catch-all", DynamicSegment::OptionalCatchAll, "slug"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("[slug"),
+            Err(("unmatched brackets", DynamicSegment::Single, "slug"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("post-[id]"),
+            Err(("text outside the brackets", DynamicSegment::Single, "id"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("[a][b]"),
+            Err(("more than one parameter", DynamicSegment::Single, "a][b"))
+        );
+        assert_eq!(
+            parse_dynamic_segment("[]"),
+            Err(("an empty parameter name", DynamicSegment::Single, ""))
+        );
     }
 
     #[test]
-    fn test_server_side_exports_in_client_component() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-server-exports");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("MyComponent.tsx");
-        let content = r#"
-'use client'
+    fn test_dynamic_segment_naming() {
+        use std::path::PathBuf;
 
-export function MyComponent() {
-    return <div>Hello</div>;
-}
+        let temp_dir = std::env::temp_dir().join("naechste-tests-dynamic-segment-naming");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let files: Vec<PathBuf> = [
+            "app/users/[User_ID]/page.tsx",
+            "app/users/[User_ID]/settings/page.tsx",
+            "app/shop/[id ]/page.tsx",
+            "app/blog/[..slug]/page.tsx",
+            "app/docs/[[...slug]]/page.tsx",
+            "app/teams/[teamId]/page.tsx",
+            "app/feed/(..)[photoId]/page.tsx",
+            "src/pages/posts/[post_id].tsx",
+            "src/pages/api/[...nextauth].ts",
+            "components/[legacy]/card.tsx",
+        ]
+        .iter()
+        .map(|file| temp_dir.join(file))
+        .collect();
 
-export async function getServerSideProps() {
-    return { props: {} };
-}
-"#;
-        create_temp_file(&file_path, content);
-        
         let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        
-        check_server_side_exports(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 1);
-        assert!(diagnostics.diagnostics[0].message.contains("getServerSideProps"));
-        assert_eq!(diagnostics.diagnostics[0].rule, "server-side-exports");
-        
+        check_dynamic_segment_naming(&temp_dir, &files, &config, &mut diagnostics);
+
+        let found: Vec<(PathBuf, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.file.strip_prefix(&temp_dir).unwrap().to_path_buf(), d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    PathBuf::from("app/users/[User_ID]"),
+                    "Dynamic segment directory '[User_ID]' has parameter 'User_ID' that does not match expected style: CamelCase; rename to '[userId]'"
+                ),
+                (
+                    PathBuf::from("app/shop/[id ]"),
+                    "Dynamic segment directory '[id ]' has parameter 'id ' that does not match expected style: CamelCase; rename to '[id]'"
+                ),
+                (
+                    PathBuf::from("app/blog/[..slug]"),
+                    "Dynamic segment directory '[..slug]' has catch-all dots other than '...'; rename to '[...slug]'"
+                ),
+                (
+                    PathBuf::from("src/pages/posts/[post_id].tsx"),
+                    "Dynamic segment file '[post_id].tsx' has parameter 'post_id' that does not match expected style: CamelCase; rename to '[postId].tsx'"
+                ),
+            ]
+        );
+        assert!(diagnostics
+            .diagnostics
+            .iter()
+            .all(|d| d.rule == "dynamic-segment-naming" && d.severity == crate::config::Severity::Warn));
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_server_side_exports_without_use_client() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-no-use-client");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("page.tsx");
-        let content = r#"
-export async function getServerSideProps() {
-    return { props: {} };
-}
+    fn test_no_duplicate_routes() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-duplicate-routes");
+
+        let files: Vec<std::path::PathBuf> = [
+            "app/(marketing)/about/page.tsx",
+            "app/(shop)/about/page.tsx",
+            "app/(shop)/cart/page.tsx",
+            "app/page.tsx",
+            "app/(marketing)/page.tsx",
+            "app/dashboard/@team/page.tsx",
+            "app/dashboard/@analytics/page.tsx",
+            "app/_drafts/cart/page.tsx",
+            "app/pricing/page.tsx",
+            "pages/pricing.tsx",
+            "pages/blog/index.tsx",
+            "app/blog/page.tsx",
+            "app/(a)/posts/[id]/page.tsx",
+            "app/(b)/posts/[slug]/page.tsx",
+            "pages/_app.tsx",
+            "pages/docs/[...slug].tsx",
+        ]
+        .iter()
+        .map(|file| temp_dir.join(file))
+        .collect();
 
-export default function Page() {
-    return <div>Hello</div>;
-}
-"#;
-        create_temp_file(&file_path, content);
-        
         let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        
-        check_server_side_exports(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 0);
-        
-        fs::remove_dir_all(&temp_dir).ok();
+        check_no_duplicate_routes(&temp_dir, &files, &config, &mut diagnostics);
+
+        let found: Vec<(&std::path::PathBuf, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (&d.file, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    &files[3],
+                    "Pages 'app/(marketing)/page.tsx' and 'app/page.tsx' both resolve to the route '/'"
+                ),
+                (
+                    &files[1],
+                    "Pages 'app/(marketing)/about/page.tsx' and 'app/(shop)/about/page.tsx' both resolve to the route '/about'"
+                ),
+                (
+                    &files[10],
+                    "Pages 'app/blog/page.tsx' and 'pages/blog/index.tsx' both resolve to the route '/blog'"
+                ),
+                (
+                    &files[13],
+                    "Pages 'app/(a)/posts/[id]/page.tsx' and 'app/(b)/posts/[slug]/page.tsx' both resolve to the route '/posts/[id]'"
+                ),
+                (
+                    &files[9],
+                    "Pages 'app/pricing/page.tsx' and 'pages/pricing.tsx' both resolve to the route '/pricing'"
+                ),
+            ]
+        );
+        assert!(diagnostics
+            .diagnostics
+            .iter()
+            .all(|d| d.rule == "no-duplicate-routes" && d.severity == crate::config::Severity::Error));
     }
 
     #[test]
-    fn test_multiple_server_exports_detected() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-multiple-exports");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("Component.tsx");
-        let content = r#"
-"use client"
+    fn test_route_group_naming() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-route-group-naming");
+
+        let directories: Vec<std::path::PathBuf> = [
+            "app/(marketing)",
+            "app/(Marketing)",
+            "app/(auth_pages)",
+            "app/()",
+            "app/(shop)/(shop)",
+            "app/(shop)",
+            "app/feed/(..)photo",
+            "app/blog",
+            "components/(Legacy)",
+        ]
+        .iter()
+        .map(|dir| temp_dir.join(dir))
+        .collect();
 
-export async function getServerSideProps() {}
-export const getStaticProps = async () => {};
-export function getStaticPaths() {}
-"#;
-        create_temp_file(&file_path, content);
-        
         let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        
-        check_server_side_exports(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 3);
-        
-        fs::remove_dir_all(&temp_dir).ok();
+        check_route_group_naming(&temp_dir, &directories, &config, &mut diagnostics);
+
+        let found: Vec<(std::path::PathBuf, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.file.strip_prefix(&temp_dir).unwrap().to_path_buf(), d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "app/()".into(),
+                    "Route group '()' is empty; give it a name"
+                ),
+                (
+                    "app/(Marketing)".into(),
+                    "Route group '(Marketing)' does not match expected style: KebabCase; rename to '(marketing)'"
+                ),
+                (
+                    "app/(auth_pages)".into(),
+                    "Route group '(auth_pages)' does not match expected style: KebabCase; rename to '(auth-pages)'"
+                ),
+                (
+                    "app/(shop)/(shop)".into(),
+                    "Route group '(shop)' is nested in a group with the same name '(shop)'"
+                ),
+                (
+                    "app/(marketing)".into(),
+                    "Route group '(marketing)' has the same name as its sibling '(Marketing)'"
+                ),
+            ]
+        );
     }
 
     #[test]
-    fn test_component_nesting_depth_within_limit() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-ok");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("app/components/Button.tsx");
-        create_temp_file(&file_path, "export function Button() {}");
-        
-        let config = get_test_config();
-        let mut diagnostics = DiagnosticCollection::new();
-        
-        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 0);
-        
-        fs::remove_dir_all(&temp_dir).ok();
+    fn test_parse_interception_marker() {
+        assert_eq!(parse_interception_marker("photo"), None);
+        assert_eq!(parse_interception_marker("(marketing)"), None);
+        assert_eq!(parse_interception_marker("(.)photo"), Some(Ok((Some(0), "photo"))));
+        assert_eq!(parse_interception_marker("(..)photo"), Some(Ok((Some(1), "photo"))));
+        assert_eq!(parse_interception_marker("(..)(..)photo"), Some(Ok((Some(2), "photo"))));
+        assert_eq!(parse_interception_marker("(...)photo"), Some(Ok((None, "photo"))));
+        assert_eq!(
+            parse_interception_marker("(....)photo"),
+            Some(Err(
+                "Malformed interception marker '(....)' in '(....)photo'; use (.), (..), (..)(..) or (...)"
+                    .to_string()
+            ))
+        );
+        assert!(matches!(parse_interception_marker("(.)(..)photo"), Some(Err(_))));
+        assert_eq!(
+            parse_interception_marker("(.).modal"),
+            Some(Err(
+                "Interception marker '(.)' in '(.).modal' must be followed by a segment name".to_string()
+            ))
+        );
+        assert!(matches!(parse_interception_marker("(..)"), Some(Err(_))));
     }
 
-    #[test]
-    fn test_component_nesting_depth_exceeds_limit() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-deep");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("app/components/ui/buttons/primary/Button.tsx");
-        create_temp_file(&file_path, "export function Button() {}");
-        
+    #[test]
+    fn test_intercepting_routes() {
+        use crate::config::Severity;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-intercepting-routes");
+
+        let directories: Vec<std::path::PathBuf> = [
+            "app/feed",
+            "app/feed/photo",
+            "app/feed/@modal",
+            "app/feed/@modal/(.)photo",
+            "app/(shop)/cart",
+            "app/(shop)/checkout",
+            "app/(shop)/checkout/(..)cart",
+            "app/(shop)/checkout/(...)feed",
+            "app/feed/(.)video",
+            "app/feed/(....)photo",
+            "app/feed/(..)(..)photo",
+        ]
+        .iter()
+        .map(|dir| temp_dir.join(dir))
+        .collect();
+
         let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        
-        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 1);
-        assert_eq!(diagnostics.diagnostics[0].rule, "component-nesting-depth");
-        
-        fs::remove_dir_all(&temp_dir).ok();
+        check_intercepting_routes(&temp_dir, &directories, &config, &mut diagnostics);
+
+        let found: Vec<(std::path::PathBuf, Severity, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (d.file.strip_prefix(&temp_dir).unwrap().to_path_buf(), d.severity, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "app/feed/(.)video".into(),
+                    Severity::Warn,
+                    "Intercepting route '(.)video' doesn't match an existing route '/feed/video'"
+                ),
+                (
+                    "app/feed/(..)(..)photo".into(),
+                    Severity::Warn,
+                    "Intercepting route '(..)(..)photo' climbs above the app root"
+                ),
+                (
+                    "app/feed/(....)photo".into(),
+                    Severity::Error,
+                    "Malformed interception marker '(....)' in '(....)photo'; use (.), (..), (..)(..) or (...)"
+                ),
+            ]
+        );
     }
 
     #[test]
-    fn test_component_nesting_depth_custom_limit() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-nesting-custom");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let file_path = temp_dir.join("app/components/ui/Button.tsx");
-        create_temp_file(&file_path, "export function Button() {}");
-        
+    fn test_no_console() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-no-console");
+        fs::remove_dir_all(&temp_dir).ok();
+
         let mut config = get_test_config();
-        config.rules.component_nesting_depth.options.max_nesting_depth = 2;
-        
-        let mut diagnostics = DiagnosticCollection::new();
-        check_component_nesting_depth(&file_path, &config, &mut diagnostics);
-        
-        assert_eq!(diagnostics.diagnostics.len(), 1);
-        
+        config.rules.no_console.severity = crate::config::Severity::Warn;
+        config.rules.no_console.options.exclude_glob = vec!["scripts/**".to_string()];
+
+        let cases = [
+            (
+                "components/card.tsx",
+                "export function Card() {\n  console.log('render');\n  console.debug ('props');\n  return null;\n}\n",
+                vec![2, 3],
+            ),
+            (
+                "components/form.tsx",
+                "export function Form() {\n  try {} catch (e) { console.error(e); console.warn('retry'); }\n}\n",
+                vec![],
+            ),
+            ("components/card.test.tsx", "console.log('debugging a test');\n", vec![]),
+            ("components/card.stories.tsx", "console.log('story');\n", vec![]),
+            ("scripts/seed.ts", "console.log('seeded');\n", vec![]),
+        ];
+
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_no_console(&temp_dir, &path, &config, &mut diagnostics);
+            let lines: Vec<usize> = diagnostics.diagnostics.iter().filter_map(|d| d.line).collect();
+            assert_eq!(lines, expected, "{}", file);
+            if let Some(diagnostic) = diagnostics.diagnostics.first() {
+                assert_eq!(diagnostic.rule, "no-console");
+                assert_eq!(diagnostic.message, "console.log() call left in production code");
+            }
+        }
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_filename_style_kebab_case() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-kebab");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let good_file = temp_dir.join("my-component.tsx");
-        create_temp_file(&good_file, "export function MyComponent() {}");
-        
-        let bad_file = temp_dir.join("MyComponent.tsx");
-        create_temp_file(&bad_file, "export function MyComponent() {}");
-        
+    fn test_api_location() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-api-location");
+        fs::remove_dir_all(&temp_dir).ok();
+
         let mut config = get_test_config();
-        config.rules.filename_style_consistency.options.filename_style = FilenameStyle::KebabCase;
-        
-        let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&good_file, &config, &mut diagnostics);
-        assert_eq!(diagnostics.diagnostics.len(), 0);
-        
-        let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&bad_file, &config, &mut diagnostics);
-        assert_eq!(diagnostics.diagnostics.len(), 1);
-        assert_eq!(diagnostics.diagnostics[0].rule, "filename-style-consistency");
-        
+        config.rules.api_location.severity = crate::config::Severity::Warn;
+
+        let handler = "export async function GET() {}\nexport const POST = async () => {};\n";
+        let cases = [
+            (
+                "lib/users/route.ts",
+                handler,
+                Some((None, "Route handler 'lib/users/route.ts' is outside app/ and is never served; move it to app/api/users/route.ts")),
+            ),
+            (
+                "components/users/handlers.ts",
+                "import x from 'x';\n\nexport async function GET() {}\nexport const POST = async () => {};\n",
+                Some((Some(3), "'components/users/handlers.ts' exports route handlers (GET, POST) outside app/api/**, pages/api/**; move them to app/api/handlers/route.ts")),
+            ),
+            ("app/api/users/route.ts", handler, None),
+            // Route handlers outside app/api/ are still served
+            ("app/feed/route.ts", handler, None),
+            ("app/api/users/handlers.ts", handler, None),
+            ("components/button.tsx", "export function Button() {}\n", None),
+        ];
+
+        for (file, content, expected) in cases {
+            let path = temp_dir.join(file);
+            create_temp_file(&path, content);
+            let mut diagnostics = DiagnosticCollection::new();
+            check_api_location(&temp_dir, &path, &config, &mut diagnostics);
+            match expected {
+                Some((line, message)) => {
+                    assert_eq!(diagnostics.diagnostics.len(), 1, "{}", file);
+                    assert_eq!(diagnostics.diagnostics[0].rule, "api-location");
+                    assert_eq!(diagnostics.diagnostics[0].line, line, "{}", file);
+                    assert_eq!(diagnostics.diagnostics[0].message, message);
+                }
+                None => assert!(diagnostics.diagnostics.is_empty(), "{}", file),
+            }
+        }
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_filename_style_pascal_case() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-pascal");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let good_file = temp_dir.join("MyComponent.tsx");
-        create_temp_file(&good_file, "export function MyComponent() {}");
-        
-        let bad_file = temp_dir.join("my-component.tsx");
-        create_temp_file(&bad_file, "export function MyComponent() {}");
-        
-        let mut config = get_test_config();
-        config.rules.filename_style_consistency.options.filename_style = FilenameStyle::PascalCase;
-        
+    fn test_no_conflicting_dynamic_segments() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-conflicting-dynamic-segments");
+        let paths = |paths: &[&str]| -> Vec<std::path::PathBuf> {
+            paths.iter().map(|path| temp_dir.join(path)).collect()
+        };
+
+        let directories = paths(&[
+            "app/posts",
+            "app/posts/[id]",
+            "app/posts/[slug]",
+            "app/docs",
+            "app/docs/[...slug]",
+            "app/docs/[slug]",
+            "app/users",
+            "app/users/[userId]",
+            "app/users/settings",
+            "pages/shop",
+        ]);
+        let files = paths(&[
+            "app/posts/[id]/page.tsx",
+            "app/posts/[slug]/page.tsx",
+            "app/users/[userId]/page.tsx",
+            "pages/shop/[id].tsx",
+            "pages/shop/[...path].tsx",
+            "components/[legacy]/card.tsx",
+        ]);
+
+        let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&good_file, &config, &mut diagnostics);
-        assert_eq!(diagnostics.diagnostics.len(), 0);
-        
+        check_no_conflicting_dynamic_segments(&temp_dir, &files, &directories, &config, &mut diagnostics);
+
+        let found: Vec<(&std::path::PathBuf, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (&d.file, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    &directories[3],
+                    "'app/docs' has conflicting dynamic segments [...slug], [slug]; use a single parameter name and kind"
+                ),
+                (
+                    &directories[0],
+                    "'app/posts' has conflicting dynamic segments [id], [slug]; use a single parameter name and kind"
+                ),
+                (
+                    &directories[9],
+                    "'pages/shop' has conflicting dynamic segments [...path], [id]; use a single parameter name and kind"
+                ),
+            ]
+        );
+        assert!(diagnostics
+            .diagnostics
+            .iter()
+            .all(|d| d.rule == "no-conflicting-dynamic-segments" && d.severity == crate::config::Severity::Error));
+
+        // A single dynamic segment is fine
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&bad_file, &config, &mut diagnostics);
-        assert_eq!(diagnostics.diagnostics.len(), 1);
-        
-        fs::remove_dir_all(&temp_dir).ok();
+        check_no_conflicting_dynamic_segments(
+            &temp_dir,
+            &files[2..3],
+            &directories[6..9],
+            &config,
+            &mut diagnostics,
+        );
+        assert!(diagnostics.diagnostics.is_empty());
     }
 
     #[test]
-    fn test_filename_style_camel_case() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-camel");
-        fs::create_dir_all(&temp_dir).ok();
-        
-        let good_file = temp_dir.join("myComponent.tsx");
-        create_temp_file(&good_file, "export function MyComponent() {}");
-        
-        let bad_file = temp_dir.join("MyComponent.tsx");
-        create_temp_file(&bad_file, "export function MyComponent() {}");
-        
-        let mut config = get_test_config();
-        config.rules.filename_style_consistency.options.filename_style = FilenameStyle::CamelCase;
-        
+    fn test_middleware_location() {
+        use crate::config::Severity;
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-middleware-location");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let root = temp_dir.join("middleware.ts");
+        create_temp_file(&root, "export function middleware(request) {}\n");
+        let src = temp_dir.join("src/middleware.ts");
+        create_temp_file(&src, "export default auth((request) => {});\n");
+        let misplaced = temp_dir.join("app/middleware.ts");
+        create_temp_file(&misplaced, "export function middleware(request) {}\n");
+
+        let config = get_test_config();
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&good_file, &config, &mut diagnostics);
-        assert_eq!(diagnostics.diagnostics.len(), 0);
-        
+        check_middleware_location(&temp_dir, &[src.clone(), misplaced.clone(), root.clone()], &config, &mut diagnostics);
+
+        let found: Vec<(&std::path::PathBuf, Severity, &str)> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| (&d.file, d.severity, d.message.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (
+                    &misplaced,
+                    Severity::Error,
+                    "Middleware 'app/middleware.ts' is ignored by Next.js; move it to the project root or src/"
+                ),
+                (
+                    &src,
+                    Severity::Warn,
+                    "More than one middleware file: 'middleware.ts' and 'src/middleware.ts'; Next.js only runs one"
+                ),
+            ]
+        );
+
+        create_temp_file(&src, "export const config = { matcher: '/admin' };\n");
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&bad_file, &config, &mut diagnostics);
+        check_middleware_location(&temp_dir, &[src], &config, &mut diagnostics);
         assert_eq!(diagnostics.diagnostics.len(), 1);
-        
+        assert_eq!(
+            diagnostics.diagnostics[0].message,
+            "Middleware 'src/middleware.ts' exports neither a middleware function nor a default export"
+        );
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_filename_style_snake_case() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-snake");
+    fn test_has_top_level_directive() {
+        assert!(has_top_level_directive("'use server'\nexport async function a() {}", "use server"));
+        assert!(has_top_level_directive("// actions\n\n\"use server\";\n", "use server"));
+        assert!(has_top_level_directive("/* header\n */\n'use server'\n", "use server"));
+        assert!(!has_top_level_directive("export async function a() {\n  'use server'\n}", "use server"));
+        assert!(!has_top_level_directive("'use client'\n", "use server"));
+    }
+
+    #[test]
+    fn test_actions_filename() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-actions-filename");
         fs::create_dir_all(&temp_dir).ok();
-        
-        let good_file = temp_dir.join("my_component.tsx");
-        create_temp_file(&good_file, "export function MyComponent() {}");
-        
-        let bad_file = temp_dir.join("MyComponent.tsx");
-        create_temp_file(&bad_file, "export function MyComponent() {}");
-        
+
+        let good_file = temp_dir.join("user.actions.ts");
+        create_temp_file(&good_file, "'use server'\nexport async function save() {}");
+
+        let bad_file = temp_dir.join("user-server.ts");
+        create_temp_file(&bad_file, "'use server'\nexport async function save() {}");
+
         let mut config = get_test_config();
-        config.rules.filename_style_consistency.options.filename_style = FilenameStyle::SnakeCase;
-        
+        config.rules.actions_filename.severity = crate::config::Severity::Error;
+
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&good_file, &config, &mut diagnostics);
+        check_actions_filename(&good_file, &config, &mut diagnostics);
         assert_eq!(diagnostics.diagnostics.len(), 0);
-        
+
         let mut diagnostics = DiagnosticCollection::new();
-        check_filename_style(&bad_file, &config, &mut diagnostics);
+        check_actions_filename(&bad_file, &config, &mut diagnostics);
         assert_eq!(diagnostics.diagnostics.len(), 1);
-        
+        assert_eq!(diagnostics.diagnostics[0].rule, "actions-filename");
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
     #[test]
-    fn test_filename_special_files_skipped() {
-        let temp_dir = std::env::temp_dir().join("naechste-tests-special");
+    fn test_actions_filename_disabled_by_default() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-actions-filename-off");
         fs::create_dir_all(&temp_dir).ok();
-        
-        let special_files = vec!["page.tsx", "layout.tsx", "loading.tsx", "error.tsx"];
-        
-        let mut config = get_test_config();
-        config.rules.filename_style_consistency.options.filename_style = FilenameStyle::PascalCase;
-        
-        for filename in special_files {
-            let file_path = temp_dir.join(filename);
-            create_temp_file(&file_path, "export default function Page() {}");
-            
-            let mut diagnostics = DiagnosticCollection::new();
-            check_filename_style(&file_path, &config, &mut diagnostics);
-            assert_eq!(diagnostics.diagnostics.len(), 0, "Special file {} should be skipped", filename);
-        }
-        
+
+        let bad_file = temp_dir.join("user-server.ts");
+        create_temp_file(&bad_file, "'use server'\nexport async function save() {}");
+
+        let config = get_test_config();
+        let mut diagnostics = DiagnosticCollection::new();
+        check_actions_filename(&bad_file, &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
         fs::remove_dir_all(&temp_dir).ok();
     }
 
@@ -1265,13 +9010,14 @@ export function getStaticPaths() {}
                     RequireKind::SiblingExact { name: "User-Story.us.md".to_string() }
                 ],
                 when_imported_by: None,
+                strict_companion_stem: false,
                 enforce_location: None,
             }
         ];
         
         let all_files = vec![page_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         assert_eq!(diagnostics.diagnostics.len(), 1);
         assert!(diagnostics.diagnostics[0].message.contains("User-Story.us.md"));
@@ -1306,13 +9052,14 @@ export function getStaticPaths() {}
                     RequireKind::SiblingExact { name: "User-Story.us.md".to_string() }
                 ],
                 when_imported_by: None,
+                strict_companion_stem: false,
                 enforce_location: None,
             }
         ];
         
         let all_files = vec![page_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         assert_eq!(diagnostics.diagnostics.len(), 0);
         
@@ -1342,13 +9089,14 @@ export function getStaticPaths() {}
                     RequireKind::SiblingGlob { glob: "*.stories.tsx".to_string() }
                 ],
                 when_imported_by: None,
+                strict_companion_stem: false,
                 enforce_location: None,
             }
         ];
         
         let all_files = vec![button_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         assert_eq!(diagnostics.diagnostics.len(), 1);
         assert!(diagnostics.diagnostics[0].message.contains("*.stories.tsx"));
@@ -1382,13 +9130,14 @@ export function getStaticPaths() {}
                     RequireKind::SiblingGlob { glob: "*.stories.tsx".to_string() }
                 ],
                 when_imported_by: None,
+                strict_companion_stem: false,
                 enforce_location: None,
             }
         ];
         
         let all_files = vec![button_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         assert_eq!(diagnostics.diagnostics.len(), 0);
         
@@ -1421,13 +9170,14 @@ export function getStaticPaths() {}
                     RequireKind::SiblingGlob { glob: "*.stories.tsx".to_string() }
                 ],
                 when_imported_by: None,
+                strict_companion_stem: false,
                 enforce_location: None,
             }
         ];
         
         let all_files = vec![page_file.clone(), button_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         // Only Button.tsx should be checked (page.tsx is excluded)
         assert_eq!(diagnostics.diagnostics.len(), 1);
@@ -1436,6 +9186,98 @@ export function getStaticPaths() {}
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_file_organization_match_glob_limits_companion_check() {
+        use crate::config::{OrganizationCheck, MatchPattern, RequireKind};
+        
+        let temp_dir = std::env::temp_dir().join("naechste-tests-file-org-match-glob");
+        fs::create_dir_all(&temp_dir).ok();
+        
+        let button_file = temp_dir.join("components/ui/Button.tsx");
+        create_temp_file(&button_file, "export const Button = () => {}");
+        
+        let util_file = temp_dir.join("lib/util.ts");
+        create_temp_file(&util_file, "export const util = () => {}");
+        
+        let mut config = get_test_config();
+        config.rules.file_organization.options.file_organization_checks = vec![
+            OrganizationCheck {
+                id: "require-test-files".to_string(),
+                description: None,
+                r#match: MatchPattern {
+                    glob: "components/**/*.tsx".to_string(),
+                    exclude_glob: vec![],
+                },
+                require: vec![
+                    RequireKind::SiblingGlob { glob: "*.test.tsx".to_string() }
+                ],
+                when_imported_by: None,
+                strict_companion_stem: false,
+                enforce_location: None,
+            }
+        ];
+        
+        let all_files = vec![button_file.clone(), util_file.clone()];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+        
+        // Only the component is checked; lib/util.ts is outside the glob
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, button_file);
+        
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_file_organization_strict_companion_stem() {
+        use crate::config::{OrganizationCheck, MatchPattern, RequireKind};
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-file-org-strict-stem");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let button_file = temp_dir.join("components/Button.tsx");
+        create_temp_file(&button_file, "export const Button = () => {}");
+        create_temp_file(&temp_dir.join("components/OldButton.stories.tsx"), "export default {}");
+        let card_file = temp_dir.join("components/Card.tsx");
+        create_temp_file(&card_file, "export const Card = () => {}");
+        create_temp_file(&temp_dir.join("components/Card.stories.tsx"), "export default {}");
+
+        let mut config = get_test_config();
+        config.rules.file_organization.options.file_organization_checks = vec![
+            OrganizationCheck {
+                id: "component-needs-stories".to_string(),
+                description: None,
+                r#match: MatchPattern {
+                    glob: "components/*.tsx".to_string(),
+                    exclude_glob: vec!["**/*.stories.tsx".to_string()],
+                },
+                require: vec![
+                    RequireKind::SiblingGlob { glob: "*.stories.tsx".to_string() }
+                ],
+                strict_companion_stem: false,
+                when_imported_by: None,
+                enforce_location: None,
+            }
+        ];
+
+        let all_files = vec![button_file.clone(), card_file.clone()];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+        // Any story satisfies the glob
+        assert!(diagnostics.diagnostics.is_empty());
+
+        config.rules.file_organization.options.file_organization_checks[0].strict_companion_stem = true;
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, button_file);
+        assert!(diagnostics.diagnostics[0]
+            .message
+            .starts_with("Missing required companion file matching '*.stories.tsx' and named 'Button.*'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_file_organization_location_enforcement() {
         use crate::config::{OrganizationCheck, MatchPattern, WhenImportedBy, EnforceLocation};
@@ -1469,6 +9311,7 @@ export function getStaticPaths() {}
                     importer_glob: "app/**".to_string(),
                     import_path_matches: vec!["^@/lib/".to_string()],
                 }),
+                strict_companion_stem: false,
                 enforce_location: Some(EnforceLocation {
                     must_be_under: vec!["components".to_string()],
                     message: Some("UI components must live under components/".to_string()),
@@ -1478,7 +9321,7 @@ export function getStaticPaths() {}
         
         let all_files = vec![button_file.clone(), page_file.clone()];
         let mut diagnostics = DiagnosticCollection::new();
-        check_file_organization(&temp_dir, &all_files, &config, &mut diagnostics);
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
         
         // Should report that Button.tsx is in the wrong location
         assert_eq!(diagnostics.diagnostics.len(), 1);
@@ -1486,5 +9329,93 @@ export function getStaticPaths() {}
         
         fs::remove_dir_all(&temp_dir).ok();
     }
+
+    #[test]
+    fn test_file_organization_location_glob() {
+        use crate::config::{EnforceLocation, MatchPattern, OrganizationCheck, WhenImportedBy};
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-file-org-location-glob");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        let nested = temp_dir.join("components/foo/ui/X.tsx");
+        create_temp_file(&nested, "export const X = () => null;");
+        let flat = temp_dir.join("components/X.tsx");
+        create_temp_file(&flat, "export const X = () => null;");
+        let page = temp_dir.join("app/page.tsx");
+        create_temp_file(
+            &page,
+            "import { X } from '../components/foo/ui/X';\nimport { X as Y } from '../components/X';",
+        );
+
+        let mut config = get_test_config();
+        config.rules.file_organization.options.file_organization_checks = vec![OrganizationCheck {
+            id: "ui-location".to_string(),
+            description: None,
+            r#match: MatchPattern {
+                glob: "components/**/*.tsx".to_string(),
+                exclude_glob: vec![],
+            },
+            require: vec![],
+            when_imported_by: Some(WhenImportedBy {
+                importer_glob: "app/**".to_string(),
+                import_path_matches: vec!["components/".to_string()],
+            }),
+            strict_companion_stem: false,
+            enforce_location: Some(EnforceLocation {
+                must_be_under: vec!["**/ui".to_string()],
+                message: None,
+            }),
+        }];
+
+        let all_files = vec![nested, flat.clone(), page];
+        let mut diagnostics = DiagnosticCollection::new();
+        check_file_organization(&temp_dir, &all_files, &PathAliases::default(), &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].file, flat);
+        assert!(diagnostics.diagnostics[0].message.ends_with("not located under any of: **/ui"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_enforce_location_missing_dir() {
+        use crate::config::{EnforceLocation, MatchPattern, OrganizationCheck};
+
+        let temp_dir = std::env::temp_dir().join("naechste-tests-enforce-location-dirs");
+        fs::remove_dir_all(&temp_dir).ok();
+        fs::create_dir_all(temp_dir.join("components/ui")).unwrap();
+
+        let mut config = get_test_config();
+        config.rules.file_organization.options.file_organization_checks = vec![OrganizationCheck {
+            id: "ui-location".to_string(),
+            description: None,
+            r#match: MatchPattern {
+                glob: "**/*.tsx".to_string(),
+                exclude_glob: vec![],
+            },
+            require: vec![],
+            when_imported_by: None,
+            strict_companion_stem: false,
+            enforce_location: Some(EnforceLocation {
+                must_be_under: vec![
+                    "components/ui/".to_string(),
+                    "app/components/ui".to_string(),
+                    "**/ui".to_string(),
+                ],
+                message: None,
+            }),
+        }];
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check_enforce_location_dirs(&temp_dir, &config, &mut diagnostics);
+
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert_eq!(diagnostics.diagnostics[0].rule, "enforce-location-missing-dir");
+        assert_eq!(diagnostics.diagnostics[0].file, temp_dir.join("app/components/ui"));
+        assert!(diagnostics.diagnostics[0].message.contains("'ui-location'"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
 }
 
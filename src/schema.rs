@@ -0,0 +1,142 @@
+use crate::config::Config;
+use serde_json::Value;
+
+const SCHEMA_BASE_URL: &str = "https://zeropaper.github.io/naechste/schemas";
+
+/// Generate the JSON Schema for the configuration file from the `Config` types
+///
+/// With `version_tag`, the `$id` points at the versioned schema URL
+/// (e.g. `naechste-0.1.2.json`) instead of the latest one.
+pub fn generate(version_tag: bool) -> Value {
+    let mut schema = schemars::schema_for!(Config);
+
+    let id = if version_tag {
+        format!("{}/naechste-{}.json", SCHEMA_BASE_URL, env!("CARGO_PKG_VERSION"))
+    } else {
+        format!("{}/naechste.json", SCHEMA_BASE_URL)
+    };
+
+    schema.insert("$id".to_string(), Value::String(id));
+    schema.insert(
+        "title".to_string(),
+        Value::String("naechste configuration".to_string()),
+    );
+
+    // Allow configs to reference the schema for editor validation
+    if let Some(Value::Object(properties)) = schema.get_mut("properties") {
+        properties.insert(
+            "$schema".to_string(),
+            serde_json::json!({
+                "type": "string",
+                "description": "Reference to the naechste configuration schema URL"
+            }),
+        );
+    }
+
+    schema.to_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{
+        EnforceLocation, MatchPattern, OrganizationCheck, PresetName, RequireKind, WhenImportedBy,
+    };
+    use std::collections::BTreeSet;
+
+    fn collect_keys(value: &Value, keys: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(map) => {
+                for (key, child) in map {
+                    keys.insert(key.clone());
+                    collect_keys(child, keys);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    collect_keys(item, keys);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_schema_properties(value: &Value, properties: &mut BTreeSet<String>) {
+        match value {
+            Value::Object(map) => {
+                if let Some(Value::Object(props)) = map.get("properties") {
+                    properties.extend(props.keys().cloned());
+                }
+                for child in map.values() {
+                    collect_schema_properties(child, properties);
+                }
+            }
+            Value::Array(items) => {
+                for item in items {
+                    collect_schema_properties(item, properties);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// A config with every optional and nested structure populated
+    fn fully_populated_config() -> Config {
+        let mut config = Config {
+            preset: Some(PresetName::Bassist),
+            ..Config::default()
+        };
+        config.rules.file_organization.options.file_organization_checks = vec![OrganizationCheck {
+            id: "check".to_string(),
+            description: Some("description".to_string()),
+            r#match: MatchPattern {
+                glob: "**/*.tsx".to_string(),
+                exclude_glob: vec![],
+            },
+            require: vec![RequireKind::SiblingExact {
+                name: "README.md".to_string(),
+            }],
+            when_imported_by: Some(WhenImportedBy {
+                importer_glob: "app/**".to_string(),
+                import_path_matches: vec![],
+            }),
+            strict_companion_stem: false,
+            enforce_location: Some(EnforceLocation {
+                must_be_under: vec![],
+                message: Some("message".to_string()),
+            }),
+        }];
+        config
+    }
+
+    #[test]
+    fn test_schema_covers_every_config_field() {
+        let config = serde_json::to_value(fully_populated_config()).unwrap();
+        let mut fields = BTreeSet::new();
+        collect_keys(&config, &mut fields);
+
+        let mut properties = BTreeSet::new();
+        collect_schema_properties(&generate(false), &mut properties);
+
+        let missing: Vec<_> = fields.difference(&properties).collect();
+        assert!(missing.is_empty(), "Fields missing from schema: {:?}", missing);
+    }
+
+    #[test]
+    fn test_schema_includes_enum_values() {
+        let schema = serde_json::to_string(&generate(false)).unwrap();
+        for value in ["off", "warn", "error", "kebab-case", "pascal-case", "bassist"] {
+            assert!(schema.contains(&format!("\"{}\"", value)), "missing {}", value);
+        }
+    }
+
+    #[test]
+    fn test_schema_version_tag() {
+        let latest = generate(false);
+        assert_eq!(latest["$id"], format!("{}/naechste.json", SCHEMA_BASE_URL));
+
+        let tagged = generate(true);
+        let id = tagged["$id"].as_str().unwrap();
+        assert!(id.ends_with(&format!("naechste-{}.json", env!("CARGO_PKG_VERSION"))));
+    }
+}
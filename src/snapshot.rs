@@ -0,0 +1,391 @@
+use crate::config::SnapshotConfig;
+use crate::diagnostics::{Diagnostic, DiagnosticCollection};
+use crate::linter;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Name of the lock file written next to the project config
+pub const LOCK_FILE_NAME: &str = "naechste-structure.lock";
+
+const SNAPSHOT_VERSION: u32 = 1;
+
+/// App Router directories whose contents make up the route tree
+const ROUTE_ROOTS: [&str; 2] = ["app", "src/app"];
+
+/// File stems that define a route segment's behaviour
+const SPECIAL_FILES: [&str; 9] = [
+    "page",
+    "layout",
+    "template",
+    "loading",
+    "error",
+    "global-error",
+    "not-found",
+    "route",
+    "default",
+];
+
+/// Recorded route tree; keys are `/`-separated paths relative to the project root
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructureSnapshot {
+    pub version: u32,
+    pub routes: BTreeMap<String, RouteEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RouteEntry {
+    /// Special files present in the segment (by stem, sorted)
+    pub files: Vec<String>,
+
+    /// Dynamic params along the path; catch-alls are prefixed with `...`
+    /// and optional catch-alls suffixed with `?`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+}
+
+impl StructureSnapshot {
+    /// Capture the current route tree of the project
+    pub fn capture(project_root: &Path) -> Self {
+        let mut routes: BTreeMap<String, RouteEntry> = BTreeMap::new();
+
+        for root in ROUTE_ROOTS {
+            let route_root = project_root.join(root);
+            if !route_root.is_dir() {
+                continue;
+            }
+
+            for entry in WalkDir::new(&route_root)
+                .into_iter()
+                .filter_entry(|e| !linter::is_ignored(e.path()))
+                .flatten()
+            {
+                let path = entry.path();
+                if !path.is_file() {
+                    continue;
+                }
+
+                let stem = match path.file_stem().and_then(|s| s.to_str()) {
+                    Some(stem) if SPECIAL_FILES.contains(&stem) => stem,
+                    _ => continue,
+                };
+
+                let dir = match path.parent().and_then(|p| p.strip_prefix(project_root).ok()) {
+                    Some(dir) => dir,
+                    None => continue,
+                };
+                let key = normalize_path(dir);
+
+                let route = routes.entry(key).or_insert_with(|| RouteEntry {
+                    files: Vec::new(),
+                    params: dynamic_params(dir),
+                });
+                if !route.files.iter().any(|f| f == stem) {
+                    route.files.push(stem.to_string());
+                }
+            }
+        }
+
+        for route in routes.values_mut() {
+            route.files.sort();
+        }
+
+        StructureSnapshot {
+            version: SNAPSHOT_VERSION,
+            routes,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        let mut contents = serde_json::to_string_pretty(self)?;
+        contents.push('\n');
+        fs::write(path, contents)
+    }
+}
+
+/// Join path components with `/` regardless of platform
+fn normalize_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn dynamic_params(dir: &Path) -> Vec<String> {
+    dir.components()
+        .filter_map(|c| c.as_os_str().to_str())
+        .filter_map(|segment| {
+            if let Some(name) = segment.strip_prefix("[[...").and_then(|s| s.strip_suffix("]]")) {
+                Some(format!("...{}?", name))
+            } else if let Some(name) = segment.strip_prefix("[...").and_then(|s| s.strip_suffix(']')) {
+                Some(format!("...{}", name))
+            } else {
+                segment
+                    .strip_prefix('[')
+                    .and_then(|s| s.strip_suffix(']'))
+                    .map(|name| name.to_string())
+            }
+        })
+        .collect()
+}
+
+fn parent_of(route: &str) -> &str {
+    route.rsplit_once('/').map(|(parent, _)| parent).unwrap_or("")
+}
+
+/// Compare the live route tree against the committed lock file
+pub fn check(
+    project_root: &Path,
+    snapshot_config: &SnapshotConfig,
+    diagnostics: &mut DiagnosticCollection,
+) {
+    let lock_path = project_root.join(LOCK_FILE_NAME);
+    let mut report = |file: PathBuf, message: String| {
+        diagnostics.add(Diagnostic {
+            severity: snapshot_config.severity,
+            rule: "structure-snapshot".to_string(),
+            message,
+            file,
+            line: None,
+            project: None,
+            tokens: Vec::new(),
+        });
+    };
+
+    let locked = match StructureSnapshot::load(&lock_path) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            report(
+                lock_path,
+                format!(
+                    "Could not read structure snapshot ({}); run `naechste snapshot write` to create it",
+                    e
+                ),
+            );
+            return;
+        }
+    };
+    let live = StructureSnapshot::capture(project_root);
+
+    let mut removed: Vec<&String> = locked
+        .routes
+        .keys()
+        .filter(|route| !live.routes.contains_key(*route))
+        .collect();
+    let mut added: Vec<&String> = live
+        .routes
+        .keys()
+        .filter(|route| !locked.routes.contains_key(*route))
+        .collect();
+
+    // A removal and an addition under the same parent with the same files is a rename
+    let mut renames = Vec::new();
+    removed.retain(|old| {
+        let position = added.iter().position(|new| {
+            parent_of(old) == parent_of(new) && locked.routes[*old].files == live.routes[*new].files
+        });
+        match position {
+            Some(index) => {
+                renames.push((*old, added.remove(index)));
+                false
+            }
+            None => true,
+        }
+    });
+
+    for (old, new) in renames {
+        report(
+            project_root.join(new),
+            format!(
+                "Route '{}' was renamed to '{}' but the structure snapshot was not updated",
+                old, new
+            ),
+        );
+    }
+    for route in removed {
+        report(
+            project_root.join(route),
+            format!(
+                "Route '{}' was removed but is still recorded in the structure snapshot",
+                route
+            ),
+        );
+    }
+    for route in added {
+        report(
+            project_root.join(route),
+            format!("Route '{}' is not recorded in the structure snapshot", route),
+        );
+    }
+
+    for (route, live_entry) in &live.routes {
+        let locked_entry = match locked.routes.get(route) {
+            Some(entry) => entry,
+            None => continue,
+        };
+        if locked_entry.files == live_entry.files {
+            continue;
+        }
+
+        let added_files: Vec<&str> = live_entry
+            .files
+            .iter()
+            .filter(|f| !locked_entry.files.contains(f))
+            .map(|f| f.as_str())
+            .collect();
+        let removed_files: Vec<&str> = locked_entry
+            .files
+            .iter()
+            .filter(|f| !live_entry.files.contains(f))
+            .map(|f| f.as_str())
+            .collect();
+
+        let mut changes = Vec::new();
+        if !added_files.is_empty() {
+            changes.push(format!("added {}", added_files.join(", ")));
+        }
+        if !removed_files.is_empty() {
+            changes.push(format!("removed {}", removed_files.join(", ")));
+        }
+
+        report(
+            project_root.join(route),
+            format!(
+                "Special files of route '{}' changed ({}) but the structure snapshot was not updated",
+                route,
+                changes.join("; ")
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Severity;
+    use std::io::Write;
+
+    fn create_temp_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    fn enforced() -> SnapshotConfig {
+        SnapshotConfig {
+            enforce: true,
+            severity: Severity::Error,
+        }
+    }
+
+    #[test]
+    fn test_dynamic_params() {
+        let dir = Path::new("app/shop/[category]/[...slug]");
+        assert_eq!(dynamic_params(dir), vec!["category", "...slug"]);
+
+        let dir = Path::new("app/docs/[[...path]]");
+        assert_eq!(dynamic_params(dir), vec!["...path?"]);
+    }
+
+    #[test]
+    fn test_capture_is_sorted_and_normalized() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-snapshot-capture");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("app/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/layout.tsx"), "");
+        create_temp_file(&temp_dir.join("app/blog/[slug]/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/blog/[slug]/card.tsx"), "");
+        create_temp_file(&temp_dir.join("app/(marketing)/about/page.mdx"), "");
+
+        let snapshot = StructureSnapshot::capture(&temp_dir);
+        let routes: Vec<&String> = snapshot.routes.keys().collect();
+        assert_eq!(routes, vec!["app", "app/(marketing)/about", "app/blog/[slug]"]);
+        assert_eq!(snapshot.routes["app"].files, vec!["layout", "page"]);
+        assert_eq!(snapshot.routes["app/blog/[slug]"].params, vec!["slug"]);
+
+        let json = serde_json::to_string_pretty(&snapshot).unwrap();
+        let parsed: StructureSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, snapshot);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_check_matching_snapshot() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-snapshot-match");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("app/page.tsx"), "");
+        StructureSnapshot::capture(&temp_dir)
+            .write(&temp_dir.join(LOCK_FILE_NAME))
+            .unwrap();
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check(&temp_dir, &enforced(), &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 0);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_check_reports_changes() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-snapshot-changes");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("app/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/blog/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/about/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/about/layout.tsx"), "");
+        StructureSnapshot::capture(&temp_dir)
+            .write(&temp_dir.join(LOCK_FILE_NAME))
+            .unwrap();
+
+        // Rename blog -> news, remove about, add contact, add a loading file to app/
+        fs::rename(temp_dir.join("app/blog"), temp_dir.join("app/news")).unwrap();
+        fs::remove_dir_all(temp_dir.join("app/about")).unwrap();
+        create_temp_file(&temp_dir.join("app/contact/page.tsx"), "");
+        create_temp_file(&temp_dir.join("app/contact/error.tsx"), "");
+        create_temp_file(&temp_dir.join("app/loading.tsx"), "");
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check(&temp_dir, &enforced(), &mut diagnostics);
+
+        let messages: Vec<&str> = diagnostics
+            .diagnostics
+            .iter()
+            .map(|d| d.message.as_str())
+            .collect();
+        assert_eq!(messages.len(), 4, "{:?}", messages);
+        assert!(messages[0].contains("'app/blog' was renamed to 'app/news'"));
+        assert!(messages.iter().any(|m| m.contains("'app/about' was removed")));
+        assert!(messages.iter().any(|m| m.contains("'app/contact' is not recorded")));
+        assert!(messages.iter().any(|m| m.contains("added loading")));
+        assert!(diagnostics.diagnostics.iter().all(|d| d.rule == "structure-snapshot"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_check_missing_lock() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-snapshot-missing");
+        fs::remove_dir_all(&temp_dir).ok();
+        create_temp_file(&temp_dir.join("app/page.tsx"), "");
+
+        let mut diagnostics = DiagnosticCollection::new();
+        check(&temp_dir, &enforced(), &mut diagnostics);
+        assert_eq!(diagnostics.diagnostics.len(), 1);
+        assert!(diagnostics.diagnostics[0].message.contains("naechste snapshot write"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -0,0 +1,385 @@
+use crate::utils::normalize_path_lexically;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Guard against `extends` cycles
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+/// Import aliases from `compilerOptions.paths` and `baseUrl` of the project's
+/// tsconfig.json (or jsconfig.json), following `extends` chains
+#[derive(Debug, Clone, Default)]
+pub struct PathAliases {
+    base_url: Option<PathBuf>,
+    paths: Vec<(String, Vec<String>)>,
+    /// Directory of the config that defined `paths`, used when there is no `baseUrl`
+    paths_dir: PathBuf,
+    /// Prefixes from the naechste `import_aliases` config, longest first
+    import_aliases: Vec<(String, PathBuf)>,
+}
+
+impl PathAliases {
+    /// Load aliases for a project; empty when it has no tsconfig
+    pub fn load(project_root: &Path) -> Self {
+        let mut aliases = PathAliases::default();
+
+        for name in ["tsconfig.json", "jsconfig.json"] {
+            let path = project_root.join(name);
+            if path.is_file() {
+                aliases.read(&path, 0);
+                break;
+            }
+        }
+
+        aliases
+    }
+
+    /// Add the `import_aliases` prefixes of the naechste config, whose targets
+    /// are relative to the project root
+    pub fn with_import_aliases(
+        mut self,
+        project_root: &Path,
+        import_aliases: &BTreeMap<String, String>,
+    ) -> Self {
+        self.import_aliases = import_aliases
+            .iter()
+            .map(|(prefix, target)| (prefix.clone(), project_root.join(target)))
+            .collect();
+        // The longest matching prefix wins
+        self.import_aliases
+            .sort_by_key(|(prefix, _)| std::cmp::Reverse(prefix.len()));
+        self
+    }
+
+    /// Read a config after its parents so that its own options override theirs
+    fn read(&mut self, path: &Path, depth: usize) {
+        if depth > MAX_EXTENDS_DEPTH {
+            return;
+        }
+
+        // tsconfig files allow comments and trailing commas
+        let value: Value = match fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| json5::from_str(&contents).ok())
+        {
+            Some(value) => value,
+            None => return,
+        };
+        let dir = path.parent().unwrap_or(Path::new("."));
+
+        let parents: Vec<&str> = match value.get("extends") {
+            Some(Value::String(parent)) => vec![parent.as_str()],
+            Some(Value::Array(parents)) => parents.iter().filter_map(Value::as_str).collect(),
+            _ => Vec::new(),
+        };
+        for parent in parents {
+            if let Some(parent_path) = resolve_extends(dir, parent) {
+                self.read(&parent_path, depth + 1);
+            }
+        }
+
+        let options = match value.get("compilerOptions") {
+            Some(options) => options,
+            None => return,
+        };
+
+        if let Some(base_url) = options.get("baseUrl").and_then(Value::as_str) {
+            self.base_url = Some(dir.join(base_url));
+        }
+
+        if let Some(paths) = options.get("paths").and_then(Value::as_object) {
+            self.paths = paths
+                .iter()
+                .map(|(pattern, targets)| {
+                    let targets = targets
+                        .as_array()
+                        .map(|targets| {
+                            targets
+                                .iter()
+                                .filter_map(Value::as_str)
+                                .map(String::from)
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    (pattern.clone(), targets)
+                })
+                .collect();
+            self.paths_dir = dir.to_path_buf();
+        }
+    }
+
+    /// Whether a specifier matches a configured `import_aliases` prefix or a
+    /// tsconfig `paths` pattern, as opposed to a package or a `baseUrl` path
+    pub fn is_alias(&self, specifier: &str) -> bool {
+        self.import_aliases
+            .iter()
+            .any(|(prefix, _)| specifier.starts_with(prefix.as_str()))
+            || self.paths.iter().any(|(pattern, _)| match pattern.split_once('*') {
+                Some((prefix, suffix)) => {
+                    specifier.len() >= prefix.len() + suffix.len()
+                        && specifier.starts_with(prefix)
+                        && specifier.ends_with(suffix)
+                }
+                None => pattern == specifier,
+            })
+    }
+
+    /// Candidate paths for a non-relative import specifier, in the order they
+    /// should be tried
+    ///
+    /// Configured `import_aliases` come first. Then, like TypeScript, an exact
+    /// `paths` key wins over wildcard patterns and among wildcards the one
+    /// with the longest prefix is used.
+    pub fn resolve(&self, specifier: &str) -> Vec<PathBuf> {
+        let mut candidates = Vec::new();
+        if specifier.starts_with('.') {
+            return candidates;
+        }
+
+        let configured = self.import_aliases.iter().find_map(|(prefix, target)| {
+            specifier.strip_prefix(prefix.as_str()).map(|rest| target.join(rest))
+        });
+        candidates.extend(configured);
+
+        let base = self.base_url.as_deref().unwrap_or(&self.paths_dir);
+
+        let mut best: Option<(&Vec<String>, &str, usize)> = None;
+        for (pattern, targets) in &self.paths {
+            if pattern == specifier {
+                best = Some((targets, "", usize::MAX));
+                break;
+            }
+
+            if let Some((prefix, suffix)) = pattern.split_once('*') {
+                let matched = specifier
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_suffix(suffix));
+                if let Some(matched) = matched {
+                    if best.is_none_or(|(_, _, len)| prefix.len() > len) {
+                        best = Some((targets, matched, prefix.len()));
+                    }
+                }
+            }
+        }
+
+        if let Some((targets, matched, _)) = best {
+            for target in targets {
+                candidates.push(base.join(target.replacen('*', matched, 1)));
+            }
+        }
+
+        // With a baseUrl, bare specifiers also resolve relative to it
+        if let Some(base_url) = &self.base_url {
+            candidates.push(base_url.join(specifier));
+        }
+
+        candidates
+    }
+
+    /// The shortest specifier from the configured `import_aliases` or a
+    /// tsconfig `paths` wildcard that resolves to `target`, a path without
+    /// extension like an import; `None` when no alias covers it
+    pub fn alias_for(&self, target: &Path) -> Option<String> {
+        let target = normalize_path_lexically(target);
+        let to_slashes = |path: &Path| {
+            path.components()
+                .filter_map(|c| c.as_os_str().to_str())
+                .collect::<Vec<_>>()
+                .join("/")
+        };
+
+        let mut candidates = Vec::new();
+        for (prefix, dir) in &self.import_aliases {
+            if let Ok(rest) = target.strip_prefix(normalize_path_lexically(dir)) {
+                if rest.as_os_str().is_empty() {
+                    continue;
+                }
+                let separator = if prefix.ends_with('/') { "" } else { "/" };
+                candidates.push(format!("{}{}{}", prefix, separator, to_slashes(rest)));
+            }
+        }
+
+        let base = self.base_url.as_deref().unwrap_or(&self.paths_dir);
+        for (pattern, targets) in &self.paths {
+            let (prefix, suffix) = match pattern.split_once('*') {
+                Some(parts) => parts,
+                None => continue,
+            };
+            // Only targets ending in the wildcard map a whole subtree
+            for dir in targets.iter().filter_map(|t| t.strip_suffix('*')) {
+                if let Ok(rest) = target.strip_prefix(normalize_path_lexically(&base.join(dir))) {
+                    if !rest.as_os_str().is_empty() {
+                        candidates.push(format!("{}{}{}", prefix, to_slashes(rest), suffix));
+                    }
+                }
+            }
+        }
+
+        // An earlier target of the same pattern may shadow this one
+        candidates
+            .into_iter()
+            .filter(|alias| {
+                self.resolve(alias)
+                    .first()
+                    .is_some_and(|first| normalize_path_lexically(first) == target)
+            })
+            .min_by_key(|alias| alias.len())
+    }
+}
+
+/// Locate the config referenced by `extends`: a relative path or a package
+fn resolve_extends(dir: &Path, extends: &str) -> Option<PathBuf> {
+    let candidates = if extends.starts_with('.') || Path::new(extends).is_absolute() {
+        let path = dir.join(extends);
+        let mut with_extension = path.clone().into_os_string();
+        with_extension.push(".json");
+        vec![path, PathBuf::from(with_extension)]
+    } else {
+        // Package configs, e.g. "@tsconfig/next" or "@acme/config/tsconfig.base.json"
+        dir.ancestors()
+            .map(|ancestor| ancestor.join("node_modules").join(extends))
+            .flat_map(|path| [path.clone(), path.join("tsconfig.json")])
+            .collect()
+    };
+
+    candidates.into_iter().find(|path| path.is_file())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn create_temp_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_resolve_paths_with_extends() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-tsconfig-extends");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("config/tsconfig.base.json"),
+            r##"{
+  // shared settings
+  "compilerOptions": {
+    "baseUrl": "..",
+    "paths": { "#lib/*": ["lib/*"] },
+  },
+}"##,
+        );
+        create_temp_file(
+            &temp_dir.join("tsconfig.json"),
+            r##"{
+  "extends": "./config/tsconfig.base",
+  "compilerOptions": {
+    "paths": {
+      "~/*": ["src/*", "generated/*"],
+      "~/components/*": ["ui/*"],
+      "#lib/*": ["lib/*"]
+    }
+  }
+}"##,
+        );
+
+        let aliases = PathAliases::load(&temp_dir);
+        let base = temp_dir.join("config/..");
+
+        assert_eq!(
+            aliases.resolve("~/utils/date"),
+            vec![
+                base.join("src/utils/date"),
+                base.join("generated/utils/date"),
+                base.join("~/utils/date"),
+            ]
+        );
+        // The longest matching prefix wins
+        assert_eq!(aliases.resolve("~/components/Button")[0], base.join("ui/Button"));
+        assert_eq!(aliases.resolve("#lib/db")[0], base.join("lib/db"));
+        assert!(aliases.resolve("./local").is_empty());
+        // The baseUrl fallback doesn't make every package an alias
+        assert!(aliases.is_alias("#lib/db"));
+        assert!(!aliases.is_alias("react"));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_paths_without_base_url() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-tsconfig-no-base-url");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "@ui": ["./packages/ui/index.ts"] } } }"#,
+        );
+
+        let aliases = PathAliases::load(&temp_dir);
+        assert_eq!(
+            aliases.resolve("@ui"),
+            vec![temp_dir.join("./packages/ui/index.ts")]
+        );
+        assert!(aliases.resolve("react").is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_configured_import_aliases() {
+        let root = Path::new("/project");
+        let import_aliases = BTreeMap::from([
+            ("~/".to_string(), "src/".to_string()),
+            ("~/ui/".to_string(), "packages/ui/".to_string()),
+            ("#lib/".to_string(), "lib".to_string()),
+        ]);
+        let aliases = PathAliases::default().with_import_aliases(root, &import_aliases);
+
+        assert_eq!(aliases.resolve("~/utils/date"), vec![root.join("src/utils/date")]);
+        // The longest matching prefix wins
+        assert_eq!(aliases.resolve("~/ui/Button"), vec![root.join("packages/ui/Button")]);
+        assert_eq!(aliases.resolve("#lib/db"), vec![root.join("lib/db")]);
+        assert!(aliases.resolve("react").is_empty());
+        assert!(aliases.is_alias("~/ui/Button"));
+        assert!(!aliases.is_alias("react"));
+    }
+
+    #[test]
+    fn test_alias_for() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-tsconfig-alias-for");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*", "./generated/*"], "~ui/*": ["./src/components/ui/*"], "@ui": ["./packages/ui/index.ts"] } } }"#,
+        );
+        let import_aliases = BTreeMap::from([("#lib/".to_string(), "lib".to_string())]);
+        let aliases = PathAliases::load(&temp_dir).with_import_aliases(&temp_dir, &import_aliases);
+
+        assert_eq!(aliases.alias_for(&temp_dir.join("src/lib/util")).as_deref(), Some("~/lib/util"));
+        // The shortest alias wins
+        assert_eq!(aliases.alias_for(&temp_dir.join("src/components/ui/button")).as_deref(), Some("~ui/button"));
+        assert_eq!(aliases.alias_for(&temp_dir.join("app/../lib/db")).as_deref(), Some("#lib/db"));
+        // `~/x` resolves to src/x first
+        assert_eq!(aliases.alias_for(&temp_dir.join("generated/client")), None);
+        assert_eq!(aliases.alias_for(&temp_dir.join("scripts/seed")), None);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_missing_tsconfig() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-tsconfig-missing");
+        fs::create_dir_all(&temp_dir).ok();
+
+        let aliases = PathAliases::load(&temp_dir);
+        assert!(aliases.resolve("~/components/Button").is_empty());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -1,8 +1,10 @@
+use crate::tsconfig::PathAliases;
 use glob::Pattern;
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
 
 /// Check if a file path matches a glob pattern
 pub fn matches_glob(path: &Path, pattern: &str, base_path: &Path) -> bool {
@@ -47,6 +49,14 @@ pub fn find_sibling_by_glob(dir: &Path, glob_pattern: &str) -> Vec<PathBuf> {
     matches
 }
 
+// Group 1 is the opening quote, group 2 the specifier
+static IMPORT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"import\s+.*?\s+from\s+(['"])([^'"]+)['"]"#).unwrap());
+static REQUIRE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"require\s*\(\s*(['"])([^'"]+)['"]\s*\)"#).unwrap());
+static EXPORT_FROM_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r#"export\s+.*?\s+from\s+(['"])([^'"]+)['"]"#).unwrap());
+
 /// Extract import specifiers from a file
 /// Returns a list of import paths found in the file
 pub fn extract_imports(file_path: &Path) -> Vec<String> {
@@ -58,26 +68,70 @@ pub fn extract_imports(file_path: &Path) -> Vec<String> {
     let mut imports = Vec::new();
     
     // Match: import ... from '...' or import ... from "..."
-    let import_re = Regex::new(r#"import\s+.*?\s+from\s+['"]([^'"]+)['"]"#).unwrap();
-    for cap in import_re.captures_iter(&content) {
-        imports.push(cap[1].to_string());
+    for cap in IMPORT_RE.captures_iter(&content) {
+        imports.push(cap[2].to_string());
     }
     
     // Match: require('...') or require("...")
-    let require_re = Regex::new(r#"require\s*\(\s*['"]([^'"]+)['"]\s*\)"#).unwrap();
-    for cap in require_re.captures_iter(&content) {
-        imports.push(cap[1].to_string());
+    for cap in REQUIRE_RE.captures_iter(&content) {
+        imports.push(cap[2].to_string());
     }
     
     // Match: export ... from '...' or export ... from "..."
-    let export_re = Regex::new(r#"export\s+.*?\s+from\s+['"]([^'"]+)['"]"#).unwrap();
-    for cap in export_re.captures_iter(&content) {
-        imports.push(cap[1].to_string());
+    for cap in EXPORT_FROM_RE.captures_iter(&content) {
+        imports.push(cap[2].to_string());
     }
     
     imports
 }
 
+/// Like `extract_imports`, but without `import type` and `export type`
+/// statements, which are erased at compile time
+pub fn extract_value_imports(file_path: &Path) -> Vec<String> {
+    let content = match fs::read_to_string(file_path) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    [&*IMPORT_RE, &*REQUIRE_RE, &*EXPORT_FROM_RE]
+        .iter()
+        .flat_map(|re| re.captures_iter(&content))
+        .filter(|cap| {
+            let statement = &cap[0];
+            !(statement.starts_with("import type ") || statement.starts_with("export type "))
+        })
+        .map(|cap| cap[2].to_string())
+        .collect()
+}
+
+/// Extract the specifiers of `export ... from '...'` re-exports from a file
+pub fn extract_reexports(file_path: &Path) -> Vec<String> {
+    let content = match fs::read_to_string(file_path) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+
+    EXPORT_FROM_RE
+        .captures_iter(&content)
+        .map(|cap| cap[2].to_string())
+        .collect()
+}
+
+/// Import specifiers with the quote character they are written with and
+/// the byte offset of the statement, in source order
+pub fn extract_quoted_imports(content: &str) -> Vec<(String, char, usize)> {
+    let mut imports: Vec<(String, char, usize)> = [&*IMPORT_RE, &*REQUIRE_RE, &*EXPORT_FROM_RE]
+        .iter()
+        .flat_map(|re| re.captures_iter(content))
+        .map(|cap| {
+            let quote = cap[1].chars().next().unwrap_or('\'');
+            (cap[2].to_string(), quote, cap.get(0).unwrap().start())
+        })
+        .collect();
+    imports.sort_by_key(|(_, _, offset)| *offset);
+    imports
+}
+
 /// Resolve an import specifier to a potential file path
 /// Handles relative imports (./foo, ../bar) and alias imports (@/foo)
 pub fn resolve_import_path(
@@ -91,8 +145,11 @@ pub fn resolve_import_path(
         return Some(project_root.join(relative_path));
     }
     
-    // Handle relative imports (./ or ../)
-    if import_specifier.starts_with("./") || import_specifier.starts_with("../") {
+    // Handle relative imports (./ or ../), including the `.` and `..` directories
+    let is_relative = matches!(import_specifier, "." | "..")
+        || import_specifier.starts_with("./")
+        || import_specifier.starts_with("../");
+    if is_relative {
         let importer_dir = importer_file.parent()?;
         let target = importer_dir.join(import_specifier);
         return Some(target);
@@ -102,14 +159,38 @@ pub fn resolve_import_path(
     None
 }
 
+/// Candidate paths for an import specifier, in the order they should be tried
+///
+/// Relative imports resolve against the importer; anything else goes through
+/// the tsconfig aliases first and falls back to `@/` mapped to the project root.
+pub fn resolve_import_candidates(
+    import_specifier: &str,
+    importer_file: &Path,
+    project_root: &Path,
+    aliases: &PathAliases,
+) -> Vec<PathBuf> {
+    let mut candidates = aliases.resolve(import_specifier);
+    if let Some(fallback) = resolve_import_path(import_specifier, importer_file, project_root) {
+        candidates.push(fallback);
+    }
+    candidates
+}
+
 /// Try to find the actual file for an import path (handles extensions and index files)
-pub fn resolve_to_actual_file(base_path: &Path) -> Option<PathBuf> {
-    // Extensions to try
-    let extensions = ["", ".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs"];
-    
-    // First try with extensions directly
+///
+/// `extensions` are tried in order, without the leading dot.
+pub fn resolve_to_actual_file(base_path: &Path, extensions: &[String]) -> Option<PathBuf> {
+    let extensions: Vec<&str> = extensions
+        .iter()
+        .map(|ext| ext.trim_start_matches('.'))
+        .collect();
+
+    // First try the path as written, then with extensions
+    if base_path.is_file() {
+        return Some(base_path.to_path_buf());
+    }
     for ext in &extensions {
-        let candidate = PathBuf::from(format!("{}{}", base_path.display(), ext));
+        let candidate = PathBuf::from(format!("{}.{}", base_path.display(), ext));
         if candidate.exists() && candidate.is_file() {
             return Some(candidate);
         }
@@ -117,8 +198,8 @@ pub fn resolve_to_actual_file(base_path: &Path) -> Option<PathBuf> {
     
     // Try as directory with index files
     if base_path.is_dir() {
-        for ext in &[".ts", ".tsx", ".js", ".jsx", ".mjs", ".cjs"] {
-            let candidate = base_path.join(format!("index{}", ext));
+        for ext in &extensions {
+            let candidate = base_path.join(format!("index.{}", ext));
             if candidate.exists() && candidate.is_file() {
                 return Some(candidate);
             }
@@ -128,10 +209,26 @@ pub fn resolve_to_actual_file(base_path: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Resolve an import specifier to the canonical path of the file it imports
+pub fn resolve_import(
+    import_specifier: &str,
+    importer_file: &Path,
+    project_root: &Path,
+    aliases: &PathAliases,
+    extensions: &[String],
+) -> Option<PathBuf> {
+    let candidates = resolve_import_candidates(import_specifier, importer_file, project_root, aliases);
+    let actual_file = candidates.iter().find_map(|c| resolve_to_actual_file(c, extensions))?;
+    // Normalize paths for comparison
+    Some(actual_file.canonicalize().unwrap_or(actual_file))
+}
+
 /// Build an import index: maps target files to list of importer files
 pub fn build_import_index(
     files: &[PathBuf],
     project_root: &Path,
+    aliases: &PathAliases,
+    extensions: &[String],
 ) -> HashMap<PathBuf, Vec<PathBuf>> {
     let mut index: HashMap<PathBuf, Vec<PathBuf>> = HashMap::new();
     
@@ -139,12 +236,8 @@ pub fn build_import_index(
         let imports = extract_imports(importer);
         
         for import_spec in imports {
-            if let Some(resolved) = resolve_import_path(&import_spec, importer, project_root) {
-                if let Some(actual_file) = resolve_to_actual_file(&resolved) {
-                    // Normalize paths for comparison
-                    let normalized = actual_file.canonicalize().unwrap_or(actual_file);
-                    index.entry(normalized).or_insert_with(Vec::new).push(importer.clone());
-                }
+            if let Some(target) = resolve_import(&import_spec, importer, project_root, aliases, extensions) {
+                index.entry(target).or_default().push(importer.clone());
             }
         }
     }
@@ -152,7 +245,8 @@ pub fn build_import_index(
     index
 }
 
-/// Check if a path is under any of the allowed prefixes
+/// Check if a path is under any of the allowed prefixes; entries with a `*`
+/// are globs that one of the path's parent directories must match
 pub fn is_under_any_prefix(path: &Path, prefixes: &[String], base_path: &Path) -> bool {
     let relative_path = if let Ok(rel) = path.strip_prefix(base_path) {
         rel
@@ -164,13 +258,120 @@ pub fn is_under_any_prefix(path: &Path, prefixes: &[String], base_path: &Path) -
     
     prefixes.iter().any(|prefix| {
         let normalized_prefix = prefix.trim_start_matches('/').trim_end_matches('/');
+        if normalized_prefix.contains('*') {
+            return relative_path
+                .ancestors()
+                .skip(1)
+                .filter(|dir| !dir.as_os_str().is_empty())
+                .any(|dir| matches_glob(dir, normalized_prefix, base_path));
+        }
         path_str.starts_with(normalized_prefix) || path_str.starts_with(&format!("{}/", normalized_prefix))
     })
 }
 
+/// Resolve `.` and `..` components without touching the filesystem
+pub fn normalize_path_lexically(path: &Path) -> PathBuf {
+    use std::path::Component;
+
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other.as_os_str()),
+        }
+    }
+    normalized
+}
+
+/// Import specifier for `target` relative to `from_dir`, always starting
+/// with `./` or `../` and using `/` separators
+pub fn relative_import_path(from_dir: &Path, target: &Path) -> String {
+    let from_dir = normalize_path_lexically(from_dir);
+    let target = normalize_path_lexically(target);
+    let from: Vec<_> = from_dir.components().collect();
+    let to: Vec<_> = target.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+
+    let mut parts: Vec<String> = vec!["..".to_string(); from.len() - common];
+    parts.extend(
+        to[common..]
+            .iter()
+            .filter_map(|c| c.as_os_str().to_str())
+            .map(String::from),
+    );
+
+    if parts.first().map(String::as_str) == Some("..") {
+        parts.join("/")
+    } else {
+        format!("./{}", parts.join("/"))
+    }
+}
+
+/// 1-based line number of a byte offset in `content`
+pub fn line_number_at(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+/// Split the arguments of a call whose opening parenthesis is at `open_paren`
+/// Returns `None` when the parenthesis is never closed
+pub fn split_call_arguments(content: &str, open_paren: usize) -> Option<Vec<String>> {
+    let bytes = content.as_bytes();
+    if bytes.get(open_paren) != Some(&b'(') {
+        return None;
+    }
+
+    let mut args = Vec::new();
+    let mut depth = 0usize;
+    let mut quote: Option<u8> = None;
+    let mut start = open_paren + 1;
+    let mut index = open_paren;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if let Some(q) = quote {
+            if byte == b'\\' {
+                index += 2;
+                continue;
+            }
+            if byte == q {
+                quote = None;
+            }
+        } else {
+            match byte {
+                b'\'' | b'"' | b'`' => quote = Some(byte),
+                b'(' | b'{' | b'[' => depth += 1,
+                b')' | b'}' | b']' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        let last = content[start..index].trim();
+                        if !last.is_empty() {
+                            args.push(last.to_string());
+                        }
+                        return Some(args);
+                    }
+                }
+                b',' if depth == 1 => {
+                    args.push(content[start..index].trim().to_string());
+                    start = index + 1;
+                }
+                _ => {}
+            }
+        }
+        index += 1;
+    }
+
+    None
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::FilesConfig;
     use std::fs::{self, File};
     use std::io::Write;
 
@@ -231,6 +432,19 @@ mod tests {
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_extract_quoted_imports() {
+        let content = "import React from 'react';\nconst fs = require(\"fs\");\nexport { x } from \"./x\";\n";
+        assert_eq!(
+            extract_quoted_imports(content),
+            vec![
+                ("react".to_string(), '\'', 0),
+                ("fs".to_string(), '"', 38),
+                ("./x".to_string(), '"', 53),
+            ]
+        );
+    }
+
     #[test]
     fn test_extract_imports_from_statements() {
         let temp_dir = std::env::temp_dir().join("naechste-test-imports");
@@ -269,6 +483,9 @@ export { Helper } from '../helpers/helper';
         let resolved2 = resolve_import_path("../components/Header", importer, root);
         assert!(resolved2.is_some());
         assert!(resolved2.unwrap().to_str().unwrap().contains("components"));
+
+        assert_eq!(resolve_import_path("..", importer, root), Some(PathBuf::from("/project/app/..")));
+        assert_eq!(resolve_import_path(".", importer, root), Some(PathBuf::from("/project/app/.")));
     }
 
     #[test]
@@ -289,6 +506,81 @@ export { Helper } from '../helpers/helper';
         assert_eq!(resolved, None);
     }
 
+    #[test]
+    fn test_relative_import_path() {
+        let root = Path::new("/project");
+        assert_eq!(
+            relative_import_path(&root.join("app/blog/[slug]"), &root.join("lib/posts")),
+            "../../../lib/posts"
+        );
+        assert_eq!(
+            relative_import_path(&root.join("app"), &root.join("app/components/Button")),
+            "./components/Button"
+        );
+        assert_eq!(
+            normalize_path_lexically(Path::new("/project/app/blog/../../lib/x")),
+            PathBuf::from("/project/lib/x")
+        );
+    }
+
+    #[test]
+    fn test_build_import_index_with_tsconfig_aliases() {
+        let temp_dir = std::env::temp_dir().join("naechste-test-import-index-aliases");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("tsconfig.json"),
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "~/*": ["generated/*", "src/*"] } } }"#,
+        );
+        create_temp_file(&temp_dir.join("src/components/Button.tsx"), "export const Button = () => {}");
+        create_temp_file(&temp_dir.join("lib/db.ts"), "export const db = {}");
+        let importer = temp_dir.join("app/page.tsx");
+        create_temp_file(
+            &importer,
+            "import { Button } from '~/components/Button';\nimport { db } from 'lib/db';\n",
+        );
+
+        let aliases = PathAliases::load(&temp_dir);
+        let index = build_import_index(
+            std::slice::from_ref(&importer),
+            &temp_dir,
+            &aliases,
+            &FilesConfig::default().extensions,
+        );
+
+        let button = temp_dir.join("src/components/Button.tsx").canonicalize().unwrap();
+        let db = temp_dir.join("lib/db.ts").canonicalize().unwrap();
+        assert_eq!(index.get(&button), Some(&vec![importer.clone()]));
+        assert_eq!(index.get(&db), Some(&vec![importer.clone()]));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_build_import_index_with_configured_aliases() {
+        let temp_dir = std::env::temp_dir().join("naechste-test-import-index-configured");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("src/hooks/useUser.ts"), "export function useUser() {}");
+        let importer = temp_dir.join("app/page.tsx");
+        create_temp_file(&importer, "import { useUser } from '#hooks/useUser';\n");
+
+        let import_aliases =
+            std::collections::BTreeMap::from([("#hooks/".to_string(), "src/hooks/".to_string())]);
+        let aliases = PathAliases::load(&temp_dir).with_import_aliases(&temp_dir, &import_aliases);
+        let index = build_import_index(
+            std::slice::from_ref(&importer),
+            &temp_dir,
+            &aliases,
+            &FilesConfig::default().extensions,
+        );
+
+        let hook = temp_dir.join("src/hooks/useUser.ts").canonicalize().unwrap();
+        assert_eq!(index.get(&hook), Some(&vec![importer.clone()]));
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_resolve_to_actual_file() {
         let temp_dir = std::env::temp_dir().join("naechste-test-resolve");
@@ -296,13 +588,28 @@ export { Helper } from '../helpers/helper';
         
         create_temp_file(&temp_dir.join("Button.tsx"), "export const Button = () => {}");
         
-        let resolved = resolve_to_actual_file(&temp_dir.join("Button"));
+        let resolved = resolve_to_actual_file(&temp_dir.join("Button"), &FilesConfig::default().extensions);
         assert!(resolved.is_some());
         assert!(resolved.unwrap().ends_with("Button.tsx"));
         
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_resolve_to_actual_file_with_configured_extensions() {
+        let temp_dir = std::env::temp_dir().join("naechste-test-resolve-extensions");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(&temp_dir.join("db.mts"), "export const db = {}");
+
+        let extensions = vec!["ts".to_string(), "mts".to_string()];
+        let resolved = resolve_to_actual_file(&temp_dir.join("db"), &extensions);
+        assert_eq!(resolved, Some(temp_dir.join("db.mts")));
+        assert!(resolve_to_actual_file(&temp_dir.join("db"), &FilesConfig::default().extensions).is_none());
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
     #[test]
     fn test_resolve_to_actual_file_with_index() {
         let temp_dir = std::env::temp_dir().join("naechste-test-resolve-index");
@@ -311,13 +618,34 @@ export { Helper } from '../helpers/helper';
         
         create_temp_file(&components_dir.join("index.tsx"), "export * from './Button'");
         
-        let resolved = resolve_to_actual_file(&components_dir);
+        let resolved = resolve_to_actual_file(&components_dir, &FilesConfig::default().extensions);
         assert!(resolved.is_some());
         assert!(resolved.unwrap().ends_with("index.tsx"));
         
         fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_line_number_at() {
+        let content = "first\nsecond\nthird";
+        assert_eq!(line_number_at(content, 0), 1);
+        assert_eq!(line_number_at(content, 7), 2);
+        assert_eq!(line_number_at(content, content.len()), 3);
+    }
+
+    #[test]
+    fn test_split_call_arguments() {
+        let content = "fetch(url, { next: { revalidate: 60 } })";
+        let args = split_call_arguments(content, 5).unwrap();
+        assert_eq!(args, vec!["url", "{ next: { revalidate: 60 } }"]);
+
+        let content = "fetch(`${base}/a,b`)";
+        assert_eq!(split_call_arguments(content, 5).unwrap().len(), 1);
+
+        assert_eq!(split_call_arguments("fetch()", 5).unwrap().len(), 0);
+        assert!(split_call_arguments("fetch(url", 5).is_none());
+    }
+
     #[test]
     fn test_is_under_any_prefix() {
         let base = Path::new("/project");
@@ -338,4 +666,18 @@ export { Helper } from '../helpers/helper';
         let prefixes = vec!["/app/".to_string()];
         assert!(is_under_any_prefix(path, &prefixes, base));
     }
+
+    #[test]
+    fn test_is_under_any_prefix_with_globs() {
+        let base = Path::new("/project");
+        let prefixes = vec!["**/ui".to_string(), "src/*/hooks/".to_string()];
+
+        assert!(is_under_any_prefix(Path::new("/project/components/foo/ui/Button.tsx"), &prefixes, base));
+        assert!(is_under_any_prefix(Path::new("/project/ui/Button.tsx"), &prefixes, base));
+        assert!(is_under_any_prefix(Path::new("/project/src/cart/hooks/use-cart.ts"), &prefixes, base));
+        // The glob matches directories, not the file itself
+        assert!(!is_under_any_prefix(Path::new("/project/components/ui"), &prefixes, base));
+        assert!(!is_under_any_prefix(Path::new("/project/components/Button.tsx"), &prefixes, base));
+        assert!(!is_under_any_prefix(Path::new("/project/components/ui-kit/Button.tsx"), &prefixes, base));
+    }
 }
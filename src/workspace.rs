@@ -0,0 +1,184 @@
+use glob::{MatchOptions, Pattern};
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Package globs of a workspace, read from `pnpm-workspace.yaml` or else the
+/// `workspaces` field of the root package.json (an array or
+/// `{ "packages": [...] }`)
+///
+/// Globs are normalised without a leading `./` or trailing `/`; exclusions
+/// keep their `!` prefix.
+pub fn package_globs(root: &Path) -> Vec<String> {
+    let globs = match fs::read_to_string(root.join("pnpm-workspace.yaml")) {
+        Ok(contents) => serde_yaml::from_str::<Value>(&contents)
+            .ok()
+            .and_then(|value| string_array(&value["packages"])),
+        Err(_) => fs::read_to_string(root.join("package.json"))
+            .ok()
+            .and_then(|contents| serde_json::from_str::<Value>(&contents).ok())
+            .and_then(|value| {
+                let workspaces = &value["workspaces"];
+                string_array(workspaces).or_else(|| string_array(&workspaces["packages"]))
+            }),
+    };
+
+    globs
+        .unwrap_or_default()
+        .into_iter()
+        .map(|glob| {
+            let (negated, glob) = match glob.strip_prefix('!') {
+                Some(glob) => (true, glob),
+                None => (false, glob.as_str()),
+            };
+            let glob = glob.trim_start_matches("./").trim_end_matches('/');
+            if negated {
+                format!("!{}", glob)
+            } else {
+                glob.to_string()
+            }
+        })
+        .collect()
+}
+
+fn string_array(value: &Value) -> Option<Vec<String>> {
+    value.as_array().map(|values| {
+        values
+            .iter()
+            .filter_map(Value::as_str)
+            .map(String::from)
+            .collect()
+    })
+}
+
+/// Workspace packages whose package.json depends on `next`, sorted by path
+pub fn find_next_projects(root: &Path) -> Vec<PathBuf> {
+    let globs = package_globs(root);
+    let match_options = MatchOptions {
+        require_literal_separator: true,
+        ..Default::default()
+    };
+    let exclusions: Vec<Pattern> = globs
+        .iter()
+        .filter_map(|glob| glob.strip_prefix('!'))
+        .filter_map(|glob| Pattern::new(glob).ok())
+        .collect();
+
+    let mut projects = Vec::new();
+    for glob in globs.iter().filter(|glob| !glob.starts_with('!')) {
+        let pattern = root.join(glob).join("package.json");
+        let entries = match glob::glob(&pattern.to_string_lossy()) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for package_json in entries.flatten() {
+            let dir = match package_json.parent() {
+                Some(dir) => dir.to_path_buf(),
+                None => continue,
+            };
+            let relative = dir.strip_prefix(root).unwrap_or(&dir);
+            if relative.components().any(|c| c.as_os_str() == "node_modules") {
+                continue;
+            }
+            let relative = relative.to_string_lossy();
+            if exclusions
+                .iter()
+                .any(|pattern| pattern.matches_with(&relative, match_options))
+            {
+                continue;
+            }
+            if depends_on_next(&package_json) && !projects.contains(&dir) {
+                projects.push(dir);
+            }
+        }
+    }
+
+    projects.sort();
+    projects
+}
+
+fn depends_on_next(package_json: &Path) -> bool {
+    let value: Value = match fs::read_to_string(package_json)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok())
+    {
+        Some(value) => value,
+        None => return false,
+    };
+
+    ["dependencies", "devDependencies", "peerDependencies"]
+        .iter()
+        .any(|field| value[field].get("next").is_some())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn create_temp_file(path: &Path, content: &str) {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).ok();
+        }
+        let mut file = fs::File::create(path).unwrap();
+        file.write_all(content.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_find_next_projects_pnpm() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-workspace-pnpm");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("pnpm-workspace.yaml"),
+            "packages:\n  - 'apps/*'\n  - './packages/**'\n  - '!apps/legacy'\n",
+        );
+        create_temp_file(
+            &temp_dir.join("apps/web/package.json"),
+            r#"{ "dependencies": { "next": "15.0.0" } }"#,
+        );
+        create_temp_file(
+            &temp_dir.join("apps/docs/package.json"),
+            r#"{ "devDependencies": { "next": "15.0.0" } }"#,
+        );
+        create_temp_file(
+            &temp_dir.join("apps/legacy/package.json"),
+            r#"{ "dependencies": { "next": "12.0.0" } }"#,
+        );
+        create_temp_file(
+            &temp_dir.join("apps/api/package.json"),
+            r#"{ "dependencies": { "express": "4.0.0" } }"#,
+        );
+        create_temp_file(
+            &temp_dir.join("packages/ui/package.json"),
+            r#"{ "peerDependencies": { "next": "*" } }"#,
+        );
+
+        assert_eq!(
+            find_next_projects(&temp_dir),
+            vec![
+                temp_dir.join("apps/docs"),
+                temp_dir.join("apps/web"),
+                temp_dir.join("packages/ui"),
+            ]
+        );
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_package_globs_from_package_json() {
+        let temp_dir = std::env::temp_dir().join("naechste-tests-workspace-package-json");
+        fs::remove_dir_all(&temp_dir).ok();
+
+        create_temp_file(
+            &temp_dir.join("package.json"),
+            r#"{ "workspaces": { "packages": ["./apps/*/", "!apps/old"] } }"#,
+        );
+
+        assert_eq!(package_globs(&temp_dir), vec!["apps/*", "!apps/old"]);
+
+        fs::remove_dir_all(&temp_dir).ok();
+    }
+}
@@ -44,6 +44,24 @@ fn test_cli_version() {
     assert!(output.status.success());
 }
 
+#[test]
+fn test_cli_schema_subcommand() {
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("schema")
+        .arg("--version-tag")
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let schema: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert!(schema["$id"]
+        .as_str()
+        .unwrap()
+        .ends_with(&format!("naechste-{}.json", env!("CARGO_PKG_VERSION"))));
+    assert!(schema["properties"]["rules"].is_object());
+}
+
 #[test]
 fn test_cli_clean_project_exits_zero() {
     let project_dir = create_temp_project("clean");
@@ -128,84 +146,888 @@ rules:
 }
 
 #[test]
-fn test_cli_json_output() {
-    let project_dir = create_temp_project("json");
+fn test_cli_config_discovered_in_parent_directory() {
+    let project_dir = create_temp_project("config-parent");
 
     create_file(
         &project_dir,
         "app/MyComponent.tsx",
-        "'use client'\nexport const getServerSideProps = () => {}",
+        "'use client'\nexport async function getServerSideProps() {}",
+    );
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{"rules":{"server_side_exports":{"severity":"error"}}}"#,
     );
 
     let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
-        .arg(&project_dir)
+        .arg(project_dir.join("app"))
         .arg("--format")
         .arg("json")
         .output()
         .expect("Failed to execute command");
 
+    assert_eq!(output.status.code(), Some(1));
     let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(stdout.contains("\"diagnostics\""));
-    assert!(stdout.contains("\"severity\""));
-    assert!(stdout.contains("\"rule\""));
-    assert!(stdout.contains("\"message\""));
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let config_path = report["metadata"]["config"].as_str().unwrap();
+    assert!(config_path.ends_with("naechste.json"));
 
     fs::remove_dir_all(project_dir).ok();
 }
 
 #[test]
-fn test_cli_human_output() {
-    let project_dir = create_temp_project("human");
+fn test_cli_nearest_config_wins() {
+    let project_dir = create_temp_project("config-nearest");
 
     create_file(
         &project_dir,
-        "app/BadName.tsx",
-        "export function Component() {}",
+        "packages/web/app/MyComponent.tsx",
+        "'use client'\nexport async function getServerSideProps() {}",
     );
-
     create_file(
         &project_dir,
         "naechste.json",
-        r#"{"rules":{"filename_style_consistency":{"severity":"warn","options":{"filename_style":"kebab-case"}}}}"#,
+        r#"{"rules":{"server_side_exports":{"severity":"error"}}}"#,
+    );
+    create_file(
+        &project_dir,
+        "packages/web/naechste.yaml",
+        "rules:\n  server_side_exports:\n    severity: warn\n",
     );
 
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(project_dir.join("packages/web"))
+        .arg("--verbose")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Using config file:"));
+    assert!(stderr.contains("naechste.yaml"));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_json_output_has_no_progress() {
+    let project_dir = create_temp_project("json-no-progress");
+    for i in 0..20 {
+        create_file(&project_dir, &format!("components/widget-{}.tsx", i), "export function Widget() {}");
+    }
+
     let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
         .arg(&project_dir)
         .arg("--format")
-        .arg("human")
+        .arg("json")
         .output()
         .expect("Failed to execute command");
 
+    // stdout is exactly the JSON report
     let stdout = String::from_utf8_lossy(&output.stdout);
-    assert!(stdout.contains("warn") || stdout.contains("error"));
-    assert!(stdout.contains("filename-style-consistency") || stdout.contains("BadName"));
+    assert!(serde_json::from_str::<serde_json::Value>(&stdout).is_ok(), "{}", stdout);
+    assert!(!stdout.contains('\r'));
+    assert!(!stdout.contains('\u{1b}'));
 
     fs::remove_dir_all(project_dir).ok();
 }
 
 #[test]
-fn test_cli_custom_config_file() {
-    let project_dir = create_temp_project("custom-config");
+fn test_cli_output_file() {
+    let project_dir = create_temp_project("output-file");
+    create_file(&project_dir, "components/MyWidget.tsx", "export function MyWidget() {}");
+    let report_path = project_dir.join("reports/lint/out.json");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("json")
+        .arg("--output")
+        .arg(&report_path)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+    let report: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&report_path).unwrap()).unwrap();
+    assert_eq!(report["diagnostics"][0]["rule"], "filename-style");
+
+    fs::remove_dir_all(project_dir).ok();
+}
 
+#[test]
+fn test_cli_explain() {
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("--explain")
+        .arg("server-side-exports")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.starts_with("server-side-exports: "));
+    assert!(stdout.contains("'use client'"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("--explain")
+        .arg("server-side-export")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stdout.is_empty());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Unknown rule 'server-side-export'"));
+    assert!(stderr.contains("server-side-exports, component-nesting-depth"));
+}
+
+#[test]
+fn test_cli_color() {
+    let project_dir = create_temp_project("color");
+    create_file(&project_dir, "components/MyWidget.tsx", "export function MyWidget() {}");
     create_file(
         &project_dir,
-        "app/Component.tsx",
-        "export function Component() {}",
+        "naechste.json",
+        r#"{"rules":{"filename_style":{"severity":"warn","options":{"filename_style":"kebab-case"}}}}"#,
     );
+
+    let run = |color: &str| {
+        Command::new(env!("CARGO_BIN_EXE_naechste"))
+            .arg(&project_dir)
+            .arg("--color")
+            .arg(color)
+            .output()
+            .expect("Failed to execute command")
+    };
+
+    let output = run("never");
+    assert_eq!(output.status.code(), Some(0));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("filename-style"));
+    assert!(!stdout.contains('\x1b'));
+
+    // Piped output is colored when asked for
+    let output = run("always");
+    assert!(String::from_utf8_lossy(&output.stdout).contains("\x1b["));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_multiple_configs() {
+    let project_dir = create_temp_project("multiple-configs");
+    create_file(&project_dir, "components/MyWidget.tsx", "export function MyWidget() {}");
     create_file(
         &project_dir,
-        "custom.json",
-        r#"{"rules":{"filename_style_consistency":{"severity":"error","options":{"filename_style":"kebab-case"}}}}"#,
+        "base.json",
+        r#"{"rules":{"filename_style":{"severity":"error","options":{"filename_style":"kebab-case"}}}}"#,
+    );
+    create_file(
+        &project_dir,
+        "local.jsonc",
+        "// Local tweaks\n{\"rules\":{\"filename_style\":{\"options\":{\"filename_style\":\"pascal-case\"}}}}",
     );
 
     let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
         .arg(&project_dir)
         .arg("--config")
-        .arg(project_dir.join("custom.json"))
+        .arg(project_dir.join("base.json"))
+        .arg("--config")
+        .arg(project_dir.join("local.jsonc"))
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    // The local style wins, the base severity is kept
+    assert_eq!(output.status.code(), Some(0));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let files: Vec<&str> = report["metadata"]["config_files"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|file| file.as_str().unwrap())
+        .collect();
+    assert_eq!(files.len(), 2);
+    assert!(files[0].ends_with("local.jsonc"));
+    assert!(files[1].ends_with("base.json"));
+
+    // Switching the order makes the base style win again
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--config")
+        .arg(project_dir.join("local.jsonc"))
+        .arg("--config")
+        .arg(project_dir.join("base.json"))
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_env_overrides() {
+    let project_dir = create_temp_project("env-overrides");
+    create_file(&project_dir, "components/MyWidget.tsx", "export function MyWidget() {}");
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{
+            "rules": { "filename_style": { "severity": "warn" } },
+            "env_overrides": { "ci": { "rules": { "filename_style": { "severity": "error" } } } }
+        }"#,
+    );
+
+    // Local runs stay friendly
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .env_remove("CI")
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(0));
+
+    // CI=true selects the `ci` overrides
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("json")
+        .env("CI", "true")
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["metadata"]["env"], "ci");
+    assert_eq!(report["diagnostics"][0]["severity"], "error");
+
+    // So does --env, without CI
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--env")
+        .arg("ci")
+        .env_remove("CI")
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_message_template() {
+    let project_dir = create_temp_project("message-template");
+    create_file(&project_dir, "components/MyWidget.tsx", "export function MyWidget() {}");
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{
+            "rules": {
+                "filename_style_consistency": {
+                    "message_template": "{file}: rename '{filename}' to {expected_style}, see https://wiki.example.com/{rule}"
+                }
+            }
+        }"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(
+        report["diagnostics"][0]["message"],
+        "components/MyWidget.tsx: rename 'MyWidget' to KebabCase, see https://wiki.example.com/filename-style"
+    );
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_root_config_stops_inheritance() {
+    let project_dir = create_temp_project("config-root");
+
+    create_file(
+        &project_dir,
+        "packages/web/app/my-component.tsx",
+        "'use client'\nexport async function getServerSideProps() {}",
+    );
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{"rules":{"server_side_exports":{"severity":"error"}}}"#,
+    );
+    create_file(&project_dir, "packages/web/naechste.yaml", "fail_on: error\n");
+
+    // The package config inherits the error severity from the workspace config
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(project_dir.join("packages/web"))
+        .arg("--verbose")
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("inheriting from"));
+
+    // ...unless it marks itself as the root
+    create_file(&project_dir, "packages/web/naechste.yaml", "root: true\n");
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(project_dir.join("packages/web"))
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(0));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_multiple_paths() {
+    let project_dir = create_temp_project("multiple-paths");
+    let clean = project_dir.join("packages/clean");
+    let broken = project_dir.join("packages/broken");
+
+    create_file(&clean, "app/page.tsx", "export default function Page() {}");
+    create_file(
+        &broken,
+        "app/MyComponent.tsx",
+        "'use client'\nexport async function getServerSideProps() {}",
+    );
+    create_file(
+        &broken,
+        "naechste.json",
+        r#"{"rules":{"server_side_exports":{"severity":"error"}}}"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&clean)
+        .arg(&broken)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    assert!(!diagnostics.is_empty());
+    for diagnostic in diagnostics {
+        let file = diagnostic["file"].as_str().unwrap();
+        assert!(file.starts_with(broken.to_str().unwrap()), "{}", file);
+    }
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_workspaces() {
+    let workspace = create_temp_project("workspaces");
+    let bad_component = "'use client'\nexport async function getServerSideProps() {}";
+
+    create_file(&workspace, "pnpm-workspace.yaml", "packages:\n  - 'apps/*'\n");
+    create_file(
+        &workspace,
+        "naechste.json",
+        r#"{"rules":{"server_side_exports":{"severity":"error"}}}"#,
+    );
+    // Has its own config that turns the rule off
+    create_file(&workspace, "apps/web/package.json", r#"{"dependencies":{"next":"15.0.0"}}"#);
+    create_file(
+        &workspace,
+        "apps/web/naechste.json",
+        r#"{"rules":{"server_side_exports":{"severity":"off"}}}"#,
+    );
+    create_file(&workspace, "apps/web/app/widget.tsx", bad_component);
+    // Falls back to the workspace config
+    create_file(&workspace, "apps/docs/package.json", r#"{"dependencies":{"next":"15.0.0"}}"#);
+    create_file(&workspace, "apps/docs/app/widget.tsx", bad_component);
+    // Not a Next.js app
+    create_file(&workspace, "apps/api/package.json", r#"{"dependencies":{}}"#);
+    create_file(&workspace, "apps/api/app/widget.tsx", bad_component);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&workspace)
+        .arg("--workspaces")
+        .arg("--format")
+        .arg("json")
         .output()
         .expect("Failed to execute command");
 
     assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let report: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+
+    let docs = workspace.join("apps/docs");
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0]["project"], docs.to_str().unwrap());
+
+    let projects = report["metadata"]["projects"].as_array().unwrap();
+    assert_eq!(projects.len(), 2);
+    assert_eq!(projects[0]["path"], docs.to_str().unwrap());
+    assert_eq!(projects[0]["errors"], 1);
+    assert_eq!(projects[1]["errors"], 0);
+
+    fs::remove_dir_all(workspace).ok();
+}
+
+#[test]
+fn test_cli_json_output() {
+    let project_dir = create_temp_project("json");
+
+    create_file(
+        &project_dir,
+        "app/MyComponent.tsx",
+        "'use client'\nexport const getServerSideProps = () => {}",
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("\"diagnostics\""));
+    assert!(stdout.contains("\"severity\""));
+    assert!(stdout.contains("\"rule\""));
+    assert!(stdout.contains("\"message\""));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_human_output() {
+    let project_dir = create_temp_project("human");
+
+    create_file(
+        &project_dir,
+        "app/BadName.tsx",
+        "export function Component() {}",
+    );
+
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{"rules":{"filename_style":{"severity":"warn","options":{"filename_style":"kebab-case"}}}}"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("human")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("warn") || stdout.contains("error"));
+    assert!(stdout.contains("filename-style") || stdout.contains("BadName"));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_custom_config_file() {
+    let project_dir = create_temp_project("custom-config");
+
+    create_file(
+        &project_dir,
+        "app/Component.tsx",
+        "export function Component() {}",
+    );
+    create_file(
+        &project_dir,
+        "custom.json",
+        r#"{"rules":{"filename_style":{"severity":"error","options":{"filename_style":"kebab-case"}}}}"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--config")
+        .arg(project_dir.join("custom.json"))
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_warnings_pass_by_default() {
+    let project_dir = create_temp_project("fail-on-default");
+
+    create_file(
+        &project_dir,
+        "app/BadName.tsx",
+        "export function Component() {}",
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_fail_on_warning_config() {
+    let project_dir = create_temp_project("fail-on-warning");
+
+    create_file(
+        &project_dir,
+        "app/BadName.tsx",
+        "export function Component() {}",
+    );
+    create_file(&project_dir, "naechste.json", r#"{"fail_on":"warning"}"#);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_error_on_warning_flag() {
+    let project_dir = create_temp_project("error-on-warning-flag");
+
+    create_file(
+        &project_dir,
+        "app/BadName.tsx",
+        "export function Component() {}",
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--error-on-warning")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_fix_inserts_file_header() {
+    let project_dir = create_temp_project("fix-file-header");
+
+    create_file(&project_dir, "lib/db.ts", "export const db = {};\n");
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{"rules":{"file_header":{"severity":"error","options":{"header_template":"// Copyright Acme Corp"}}}}"#,
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("file-header"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--fix")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(
+        fs::read_to_string(project_dir.join("lib/db.ts")).unwrap(),
+        "// Copyright Acme Corp\nexport const db = {};\n"
+    );
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_structure_snapshot() {
+    let project_dir = create_temp_project("structure-snapshot");
+
+    create_file(&project_dir, "app/page.tsx", "export default function Page() {}");
+    create_file(&project_dir, "naechste.json", r#"{"snapshot":{"enforce":true}}"#);
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("snapshot")
+        .arg("write")
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    assert!(project_dir.join("naechste-structure.lock").is_file());
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+
+    // An unrecorded route fails the run
+    create_file(&project_dir, "app/about/page.tsx", "export default function About() {}");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("structure-snapshot"));
+    assert!(stdout.contains("app/about"));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_migrate_config() {
+    let project_dir = create_temp_project("migrate-config");
+    create_file(&project_dir, "app/page.tsx", "export default function Page() {}");
+    create_file(
+        &project_dir,
+        "naechste.json",
+        r#"{"rules":{"server_side_exports":{"options":{"max_nesting_depth":3}}}}"#,
+    );
+
+    // Old configs still load, with a hint to migrate
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("migrate-config"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("migrate-config")
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+    assert!(output.status.success());
+    assert!(String::from_utf8_lossy(&output.stdout).contains("from version 1 to version 2"));
+
+    let migrated: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(project_dir.join("naechste.json")).unwrap())
+            .unwrap();
+    assert_eq!(migrated["version"], 2);
+    assert!(migrated["rules"]["server_side_exports"].get("options").is_none());
+
+    // A config from a newer release is a hard error
+    create_file(&project_dir, "naechste.json", r#"{"version":99}"#);
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("upgrade naechste"));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_invalid_config_exits_two() {
+    let project_dir = create_temp_project("invalid-config");
+    create_file(&project_dir, "app/page.tsx", "export default function Page() {}");
+    create_file(
+        &project_dir,
+        "naechste.yaml",
+        "rules:\n  server_side_exports:\n    severity: error\n   options: {}\n",
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    // No silent fallback to the default configuration
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("line 4, column 4"), "{}", stderr);
+    assert!(stderr.contains("   4 |    options: {}"), "{}", stderr);
+    assert!(output.stdout.is_empty());
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_internal_errors_exit_two() {
+    let project_dir = create_temp_project("internal-errors");
+    create_file(&project_dir, "app/page.tsx", "export default function Page() {}");
+    create_file(&project_dir, "bad.json", r#"{"rules": {"server_side_exports": }"#);
+
+    // A malformed explicit config
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--config")
+        .arg(project_dir.join("bad.json"))
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("bad.json"));
+
+    // A missing explicit config
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--config")
+        .arg(project_dir.join("missing.json"))
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+
+    // A project directory that can't be read
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(project_dir.join("does-not-exist"))
+        .output()
+        .expect("Failed to execute command");
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Could not read project directory"));
+    assert!(output.stdout.is_empty());
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_init_writes_starter_config() {
+    let project_dir = create_temp_project("init");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("init")
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+    let contents = fs::read_to_string(project_dir.join("naechste.json")).unwrap();
+    assert!(contents.contains("\"server_side_exports\""));
+
+    // The starter config is picked up without warnings
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(0));
+    assert!(!String::from_utf8_lossy(&output.stderr).contains("Warning"));
+
+    // Re-running without --force refuses to overwrite
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("init")
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--force"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg("init")
+        .arg("--force")
+        .arg(&project_dir)
+        .output()
+        .expect("Failed to execute command");
+
+    assert!(output.status.success());
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_since_reports_changed_files_only() {
+    let project_dir = create_temp_project("since");
+
+    create_file(&project_dir, "components/FirstCard.tsx", "export function FirstCard() {}");
+    create_file(&project_dir, "components/SecondCard.tsx", "export function SecondCard() {}");
+
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .args(["-c", "user.name=naechste", "-c", "user.email=naechste@example.com"])
+            .args(args)
+            .current_dir(&project_dir)
+            .output()
+            .expect("Failed to run git")
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    git(&["init", "-q"]);
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+
+    create_file(
+        &project_dir,
+        "components/SecondCard.tsx",
+        "export function SecondCard() { return null; }",
+    );
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--since")
+        .arg("HEAD")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("SecondCard.tsx"));
+    assert!(!stdout.contains("FirstCard.tsx"));
+
+    // An unknown ref is an error
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--since")
+        .arg("does-not-exist")
+        .output()
+        .expect("Failed to execute command");
+
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("does-not-exist"));
+
+    fs::remove_dir_all(project_dir).ok();
+}
+
+#[test]
+fn test_cli_respects_gitignore() {
+    let project_dir = create_temp_project("gitignore");
+
+    create_file(&project_dir, ".gitignore", "generated/**\n");
+    create_file(&project_dir, "generated/ApiClient.ts", "export const client = {};");
+    create_file(&project_dir, "lib/api-client.ts", "export const client = {};");
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("ApiClient"));
+
+    let output = Command::new(env!("CARGO_BIN_EXE_naechste"))
+        .arg(&project_dir)
+        .arg("--no-gitignore")
+        .arg("--format")
+        .arg("json")
+        .output()
+        .expect("Failed to execute command");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("generated/ApiClient.ts"));
+    assert!(stdout.contains("filename-style"));
 
     fs::remove_dir_all(project_dir).ok();
 }